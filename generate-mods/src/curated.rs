@@ -0,0 +1,194 @@
+//! Data that is not part of mods.json and is instead manually curated from
+//! osu!lazer's mod implementations.
+
+use crate::{error::GenResult, model::Ruleset, writer::Writer};
+
+#[derive(Debug, Default)]
+pub struct CuratedSetting {
+    pub default: Option<DefaultValue>,
+    pub range: Option<Range>,
+}
+
+/// Bounds and precision of a numeric setting
+#[derive(Copy, Clone, Debug)]
+pub struct Range {
+    pub min: f64,
+    pub max: f64,
+    pub precision: f64,
+}
+
+impl Range {
+    const fn new(min: f64, max: f64, precision: f64) -> Self {
+        Self {
+            min,
+            max,
+            precision,
+        }
+    }
+
+    pub fn write(self, writer: &mut Writer) -> GenResult {
+        writer.write("SettingRange { min: ")?;
+        writer.write(&*format!("{:?}", self.min))?;
+        writer.write(", max: ")?;
+        writer.write(&*format!("{:?}", self.max))?;
+        writer.write(", precision: ")?;
+        writer.write(&*format!("{:?}", self.precision))?;
+
+        writer.write(" }")
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum DefaultValue {
+    Bool(bool),
+    Number(f64),
+    String(&'static str),
+}
+
+impl DefaultValue {
+    pub fn write(self, writer: &mut Writer) -> GenResult {
+        match self {
+            Self::Bool(value) => writer.write(if value { "true" } else { "false" }),
+            Self::Number(value) => writer.write(&*format!("{value:?}")),
+            Self::String(value) => {
+                writer.write(b'"')?;
+                writer.write(value)?;
+
+                writer.write(b'"')
+            }
+        }
+    }
+
+    pub fn write_setting_default(self, writer: &mut Writer) -> GenResult {
+        let variant = match self {
+            Self::Bool(_) => "Bool",
+            Self::Number(_) => "Number",
+            Self::String(_) => "String",
+        };
+
+        writer.write("SettingDefault::")?;
+        writer.write(variant)?;
+        writer.write(b'(')?;
+        self.write(writer)?;
+
+        writer.write(b')')
+    }
+}
+
+impl CuratedSetting {
+    /// Returns `None` if the setting has not been curated yet.
+    pub fn get(ruleset: Ruleset, acronym: &str, setting: &str) -> Option<Self> {
+        let default = Self::default_value(acronym, setting)?;
+        let range = Self::range(ruleset, acronym, setting);
+
+        Some(Self { default, range })
+    }
+
+    /// The outer `Option` is `None` if the setting has not been curated yet.
+    fn default_value(acronym: &str, setting: &str) -> Option<Option<DefaultValue>> {
+        use DefaultValue::{Bool, Number, String};
+
+        let default = match (acronym, setting) {
+            ("EZ", "retries") => Some(Number(2.0)),
+            ("HT" | "DC", "speed_change") => Some(Number(0.75)),
+            ("DT" | "NC", "speed_change") => Some(Number(1.5)),
+            ("HT" | "DT" | "WU" | "WD", "adjust_pitch") => Some(Bool(false)),
+            ("AS", "adjust_pitch") => Some(Bool(true)),
+            ("SD", "fail_on_slider_tail") => Some(Bool(false)),
+            ("SD" | "PF" | "AC", "restart") => Some(Bool(false)),
+            ("PF", "require_perfect_hits") => Some(Bool(false)),
+            ("HD", "only_fade_approach_circles") => Some(Bool(false)),
+            ("FL", "follow_delay") => Some(Number(120.0)),
+            ("FL", "size_multiplier") => Some(Number(1.0)),
+            ("FL", "combo_based_size") => Some(Bool(true)),
+            ("AC", "minimum_accuracy") => Some(Number(0.9)),
+            ("AC", "accuracy_judge_mode") => Some(String("Standard")),
+            ("TP" | "RD", "seed") => None,
+            ("TP", "metronome") => Some(Bool(true)),
+            ("DA", "circle_size" | "approach_rate" | "drain_rate" | "overall_difficulty") => None,
+            ("DA", "scroll_speed") => Some(Number(1.0)),
+            ("DA", "extended_limits" | "hard_rock_offsets") => Some(Bool(false)),
+            (
+                "CL",
+                "no_slider_head_accuracy"
+                | "classic_note_lock"
+                | "always_play_tail_sample"
+                | "fade_hit_circle_early"
+                | "classic_health",
+            ) => Some(Bool(true)),
+            ("RD", "angle_sharpness") => Some(Number(7.0)),
+            ("MR", "reflection") => Some(String("Horizontal")),
+            ("WG", "strength") => Some(Number(1.0)),
+            ("GR", "start_scale") => Some(Number(0.5)),
+            ("DF", "start_scale") => Some(Number(2.0)),
+            ("WU" | "WD" | "AS", "initial_rate") => Some(Number(1.0)),
+            ("WU", "final_rate") => Some(Number(1.5)),
+            ("WD", "final_rate") => Some(Number(0.75)),
+            ("BR", "spin_speed") => Some(Number(0.5)),
+            ("BR", "direction") => Some(String("Clockwise")),
+            ("AD", "scale") => Some(Number(4.0)),
+            ("AD", "style") => Some(String("Gravity")),
+            ("MU", "inverse_muting") => Some(Bool(false)),
+            ("MU", "enable_metronome" | "affects_hit_sounds") => Some(Bool(true)),
+            ("MU", "mute_combo_count") => Some(Number(100.0)),
+            ("NS", "hidden_combo_count") => Some(Number(10.0)),
+            ("MG", "attraction_strength") => Some(Number(0.5)),
+            ("RP", "repulsion_strength") => Some(Number(0.5)),
+            ("DP", "max_depth") => Some(Number(100.0)),
+            ("DP", "show_approach_circles") => Some(Bool(true)),
+            ("BM", "max_size_combo_count") => Some(Number(50.0)),
+            ("BM", "max_cursor_size") => Some(Number(10.0)),
+            ("SR", "one_third_conversion" | "one_eighth_conversion") => Some(Bool(false)),
+            ("SR", "one_sixth_conversion") => Some(Bool(true)),
+            ("CO", "coverage") => Some(Number(0.5)),
+            ("CO", "direction") => Some(String("AlongScroll")),
+            _ => return None,
+        };
+
+        Some(default)
+    }
+
+    /// Lazer's `MinValue`, `MaxValue`, and `Precision` of numeric settings.
+    ///
+    /// Limits that depend on other settings such as `extended_limits` of
+    /// `DifficultyAdjust` are handled manually.
+    fn range(ruleset: Ruleset, acronym: &str, setting: &str) -> Option<Range> {
+        let range = match (acronym, setting) {
+            ("EZ", "retries") => Range::new(0.0, 10.0, 1.0),
+            ("HT" | "DC", "speed_change") => Range::new(0.5, 0.99, 0.01),
+            ("DT" | "NC", "speed_change") => Range::new(1.01, 2.0, 0.01),
+            ("FL", "follow_delay") => Range::new(0.0, 5000.0, 1.0),
+            ("FL", "size_multiplier") => match ruleset {
+                Ruleset::Osu => Range::new(0.5, 2.0, 0.1),
+                Ruleset::Taiko | Ruleset::Catch => Range::new(0.5, 1.5, 0.1),
+                Ruleset::Mania => Range::new(0.5, 3.0, 0.1),
+            },
+            ("AC", "minimum_accuracy") => Range::new(0.6, 0.99, 0.01),
+            ("DA", "circle_size" | "approach_rate" | "drain_rate" | "overall_difficulty") => {
+                Range::new(0.0, 10.0, 0.1)
+            }
+            ("DA", "scroll_speed") => Range::new(0.25, 4.0, 0.05),
+            ("RD", "angle_sharpness") => Range::new(1.0, 10.0, 0.1),
+            ("WG", "strength") => Range::new(0.1, 2.0, 0.1),
+            ("GR", "start_scale") => Range::new(0.0, 0.99, 0.01),
+            ("DF", "start_scale") => Range::new(1.0, 25.0, 0.1),
+            ("WU", "initial_rate") | ("WD", "final_rate") => Range::new(0.5, 1.99, 0.01),
+            ("WU", "final_rate") | ("WD", "initial_rate") => Range::new(0.51, 2.0, 0.01),
+            ("AS", "initial_rate") => Range::new(0.5, 2.0, 0.01),
+            ("BR", "spin_speed") => Range::new(0.02, 12.0, 0.01),
+            ("AD", "scale") => Range::new(1.5, 10.0, 0.1),
+            ("MU", "mute_combo_count") => Range::new(0.0, 500.0, 1.0),
+            ("NS", "hidden_combo_count") => Range::new(0.0, 50.0, 1.0),
+            ("MG", "attraction_strength") | ("RP", "repulsion_strength") => {
+                Range::new(0.05, 1.0, 0.05)
+            }
+            ("DP", "max_depth") => Range::new(50.0, 200.0, 10.0),
+            ("BM", "max_size_combo_count") => Range::new(5.0, 100.0, 1.0),
+            ("BM", "max_cursor_size") => Range::new(5.0, 15.0, 0.5),
+            ("CO", "coverage") => Range::new(0.2, 0.8, 0.1),
+            _ => return None,
+        };
+
+        Some(range)
+    }
+}
@@ -10,6 +10,7 @@ pub use self::{
     writer::Writer,
 };
 
+mod curated;
 mod error;
 mod model;
 mod writer;
@@ -34,7 +35,7 @@ pub fn specify_preamble(writer: &mut Writer, url: &str) -> GenResult {
         };",
     )?;
 
-    writer.write("\n\nuse crate::{Acronym, GameMode, GameModSimple, SettingSimple};\n\n")?;
+    writer.write("\n\nuse crate::{Acronym, GameMode, GameModSimple, SettingDefault, SettingKind, SettingRange, SettingSchema, SettingSimple};\n\n")?;
 
     Ok(())
 }
@@ -145,8 +146,13 @@ pub fn define_gamemod_intermode(
                 let name = &gamemod.name[..gamemod.name.len() - suffix_len];
                 let bits = gamemod.bits();
                 let kind = gamemod.kind.as_ref();
+                let display_name = gamemod.display_name.as_ref();
+                let eligibility = gamemod.eligibility();
 
-                (name, (bits, gamemod.acronym, kind))
+                (
+                    name,
+                    (bits, gamemod.acronym, kind, display_name, eligibility),
+                )
             })
         })
         .collect::<HashMap<_, _>>()
@@ -186,12 +192,49 @@ pub fn define_gamemod_intermode(
 
     writer.write(
         "impl GameModIntermode {\
+            /// All known [`GameModIntermode`]s i.e. every variant except `Unknown`, sorted by name\n\
+            pub const ALL: &'static [Self] = &[",
+    )?;
+
+    for (name, _) in mods.iter() {
+        writer.write("Self::")?;
+        writer.write(*name)?;
+        writer.write(b',')?;
+    }
+
+    writer.write(
+        "];\
+            /// All [`GameModIntermode`]s that are available in the given mode,\n\
+            /// in the same order as osu!lazer lists them\n\
+            pub const fn all_for_mode(mode: GameMode) -> &'static [Self] {\
+                match mode {",
+    )?;
+
+    for ruleset in rulesets {
+        let ruleset_str = ruleset.name.as_capitalized_str();
+
+        writer.write("GameMode::")?;
+        writer.write(ruleset_str)?;
+        writer.write(" => &[")?;
+
+        for gamemod in ruleset.mods.iter() {
+            writer.write("Self::")?;
+            writer.write(&gamemod.name[..gamemod.name.len() - ruleset_str.len()])?;
+            writer.write(b',')?;
+        }
+
+        writer.write("],")?;
+    }
+
+    writer.write(
+        "}\
+            }\
             /// The [`Acronym`] of this [`GameModIntermode`]\n\
             pub const fn acronym(&self) -> Acronym {\
                 unsafe { match self {",
     )?;
 
-    for (name, (_, acronym, _)) in mods.iter() {
+    for (name, (_, acronym, ..)) in mods.iter() {
         writer.write("Self::")?;
         writer.write(*name)?;
         writer.write(" => ")?;
@@ -205,6 +248,24 @@ pub fn define_gamemod_intermode(
                     }\
                 }\
             }\
+            /// The name of this [`GameModIntermode`]\n\
+            pub const fn name(&self) -> &'static str {\
+                match self {",
+    )?;
+
+    for (name, (.., display_name, _)) in mods.iter() {
+        writer.write("Self::")?;
+        writer.write(*name)?;
+        writer.write(" => \"")?;
+        writer.write(*display_name)?;
+        writer.write("\",")?;
+    }
+
+    writer.write(
+        "\
+                    Self::Unknown(_) => UnknownMod::name(),\
+                }\
+            }\
             /// Bit value of the [`GameModIntermode`]\n\
             ///\n\
             /// See <https://github.com/ppy/osu-api/wiki#mods>\n\
@@ -239,7 +300,7 @@ pub fn define_gamemod_intermode(
                 match self {",
     )?;
 
-    for (name, (.., kind)) in mods.iter() {
+    for (name, (.., kind, _, _)) in mods.iter() {
         writer.write("Self::")?;
         writer.write(*name)?;
         writer.write(" => GameModKind::")?;
@@ -251,13 +312,43 @@ pub fn define_gamemod_intermode(
         "\
                     Self::Unknown(_) => GameModKind::System,\
                 }\
-            }\
+            }",
+    )?;
+
+    let eligibilities = mods.first().map_or([].as_slice(), |(_, (.., first))| first);
+
+    for (i, eligibility) in eligibilities.iter().enumerate() {
+        writer.write("/// Whether this [`GameModIntermode`] ")?;
+        writer.write(eligibility.doc)?;
+        writer.write("\npub const fn ")?;
+        writer.write(eligibility.fn_name)?;
+        writer.write("(&self) -> bool { match self {")?;
+
+        for (name, (.., eligibility)) in mods.iter() {
+            writer.write("Self::")?;
+            writer.write(*name)?;
+            writer.write(" => ")?;
+            writer.write(if eligibility[i].value {
+                "true"
+            } else {
+                "false"
+            })?;
+            writer.write(b',')?;
+        }
+
+        writer.write("Self::Unknown(_) => UnknownMod::")?;
+        writer.write(eligibility.fn_name)?;
+        writer.write("(), }}")?;
+    }
+
+    writer.write(
+        "\
             /// Parse an [`Acronym`] into a [`GameModIntermode`]\n\
             pub fn from_acronym(acronym: Acronym) -> Self {\
                 match acronym.as_str() {",
     )?;
 
-    for (name, (_, acronym, _)) in mods.iter() {
+    for (name, (_, acronym, ..)) in mods.iter() {
         writer.write(b'"')?;
         writer.write(acronym.as_str())?;
         writer.write("\" => Self::")?;
@@ -270,6 +361,34 @@ pub fn define_gamemod_intermode(
                     _ => Self::Unknown(UnknownMod { acronym }),\
                 }\
             }\
+            /// Parse the name of a mod into a [`GameModIntermode`]\n\
+            ///\n\
+            /// Whitespace and casing are ignored so both `\"DoubleTime\"` and\n\
+            /// `\"Double Time\"` are accepted.\n\
+            ///\n\
+            /// Returns `None` if the name does not belong to any known mod.\n\
+            pub fn from_name(name: &str) -> Option<Self> {\
+                let name: String = name\
+                    .chars()\
+                    .filter(|c| !c.is_whitespace())\
+                    .map(|c| c.to_ascii_lowercase())\
+                    .collect();\
+                match name.as_str() {",
+    )?;
+
+    for (name, _) in mods.iter() {
+        writer.write(b'"')?;
+        writer.write(&*name.to_ascii_lowercase())?;
+        writer.write("\" => Some(Self::")?;
+        writer.write(*name)?;
+        writer.write("),")?;
+    }
+
+    writer.write(
+        "\
+                    _ => None,\
+                }\
+            }\
             /// Try to convert bitvalues into a [`GameModIntermode`]\n\
             pub const fn try_from_bits(bits: u32) -> Option<Self> {\
                 match bits {",
@@ -296,6 +415,35 @@ pub fn define_gamemod_intermode(
                     settings: HashMap::new(),\
                 }\
             }\
+            /// The [`SettingSchema`] for each setting of this [`GameModIntermode`]\n\
+            /// in the given [`GameMode`].\n\
+            ///\n\
+            /// Returns `None` if the mod is not available in the mode.\n\
+            pub const fn settings_schema(&self, mode: GameMode) -> Option<&'static [SettingSchema]> {\
+                match (self, mode) {",
+    )?;
+
+    for ruleset in rulesets {
+        let ruleset_str = ruleset.name.as_capitalized_str();
+
+        for gamemod in ruleset.mods.iter() {
+            let intermode = &gamemod.name[..gamemod.name.len() - ruleset_str.len()];
+
+            writer.write("(Self::")?;
+            writer.write(intermode)?;
+            writer.write(", GameMode::")?;
+            writer.write(ruleset_str)?;
+            writer.write(") => Some(")?;
+            writer.write(&gamemod.name)?;
+            writer.write("::settings_schema()),")?;
+        }
+    }
+
+    writer.write(
+        "\
+                    _ => None,\
+                }\
+            }\
         }",
     )?;
 
@@ -370,13 +518,19 @@ pub fn define_gamemod_fns(rulesets: &[RulesetMods], writer: &mut Writer) -> GenR
 
     define_gamemod_fn_new(rulesets, writer)?;
     define_gamemod_fn_acronym(rulesets, writer)?;
+    define_gamemod_fn_name(rulesets, writer)?;
     define_gamemod_fn_incompatible_mods(rulesets, writer)?;
     define_gamemod_fn_description(rulesets, writer)?;
     define_gamemod_fn_kind(rulesets, writer)?;
     define_gamemod_fn_bits(rulesets, writer)?;
+    define_gamemod_fns_eligibility(rulesets, writer)?;
     define_gamemod_fn_mode(rulesets, writer)?;
     define_gamemod_fn_intermode(rulesets, writer)?;
     define_gamemod_fn_into_simple(rulesets, writer)?;
+    define_gamemod_fn_settings_schema(rulesets, writer)?;
+    define_gamemod_fn_with_defaults_filled(rulesets, writer)?;
+    define_gamemod_fn_setting(rulesets, writer)?;
+    define_gamemod_fn_set_setting(rulesets, writer)?;
 
     writer.write(b'}')
 }
@@ -418,6 +572,10 @@ fn define_unknown_mod_impl(writer: &mut Writer) -> GenResult {
             pub const fn incompatible_mods() -> std::iter::Empty<Acronym> {\
                 std::iter::empty()\
             }\
+            /// A custom name for any unknown mod\n\
+            pub const fn name() -> &'static str {\
+                \"Unknown\"\
+            }\
             /// A custom description for any unknown mod\n\
             pub const fn description() -> &'static str {\
                 \"Some unknown mod\"\
@@ -426,6 +584,30 @@ fn define_unknown_mod_impl(writer: &mut Writer) -> GenResult {
             pub const fn kind() -> GameModKind {\
                 GameModKind::System\
             }\
+            /// Returns `false`\n\
+            pub const fn is_user_playable() -> bool {\
+                false\
+            }\
+            /// Returns `false`\n\
+            pub const fn is_valid_for_multiplayer() -> bool {\
+                false\
+            }\
+            /// Returns `false`\n\
+            pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {\
+                false\
+            }\
+            /// Returns `false`\n\
+            pub const fn requires_configuration() -> bool {\
+                false\
+            }\
+            /// Returns `false`\n\
+            pub const fn is_always_valid_for_submission() -> bool {\
+                false\
+            }\
+            /// Returns an empty slice\n\
+            pub const fn settings_schema() -> &'static [SettingSchema] {\
+                &[]\
+            }\
         }\
         impl Default for UnknownMod {\
             fn default() -> Self {\
@@ -524,6 +706,28 @@ fn define_gamemod_fn_acronym(rulesets: &[RulesetMods], writer: &mut Writer) -> G
     )
 }
 
+fn define_gamemod_fn_name(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
+    writer.write(
+        "/// The name of this [`GameMod`]\n\
+        pub const fn name(&self) -> &'static str {\
+        match self {",
+    )?;
+
+    for ruleset in rulesets {
+        for gamemod in ruleset.mods.iter() {
+            writer.write("Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(_) => ")?;
+            writer.write(&gamemod.name)?;
+            writer.write("::name(),")?;
+        }
+    }
+
+    writer.write("_ => UnknownMod::name(),")?;
+
+    writer.write("}}")
+}
+
 fn define_gamemod_fn_incompatible_mods(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
     writer.write(
         "/// List of [`Acronym`] for mods that are incompatible with this [`GameMod`]\n\
@@ -594,6 +798,38 @@ fn define_gamemod_fn_kind(rulesets: &[RulesetMods], writer: &mut Writer) -> GenR
     )
 }
 
+fn define_gamemod_fns_eligibility(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
+    let Some(first) = rulesets.iter().find_map(|ruleset| ruleset.mods.first()) else {
+        return Ok(());
+    };
+
+    for eligibility in first.eligibility() {
+        writer.write("/// Whether this [`GameMod`] ")?;
+        writer.write(eligibility.doc)?;
+        writer.write("\npub const fn ")?;
+        writer.write(eligibility.fn_name)?;
+        writer.write("(&self) -> bool { match self {")?;
+
+        for ruleset in rulesets {
+            for gamemod in ruleset.mods.iter() {
+                writer.write("Self::")?;
+                writer.write(&gamemod.name)?;
+                writer.write("(_) => ")?;
+                writer.write(&gamemod.name)?;
+                writer.write("::")?;
+                writer.write(eligibility.fn_name)?;
+                writer.write("(),")?;
+            }
+        }
+
+        writer.write("_ => UnknownMod::")?;
+        writer.write(eligibility.fn_name)?;
+        writer.write("(), }}")?;
+    }
+
+    Ok(())
+}
+
 fn define_gamemod_fn_bits(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
     writer.write(
         "/// Optional bit value of this [`GameMod`]\n\
@@ -623,6 +859,110 @@ fn define_gamemod_fn_bits(rulesets: &[RulesetMods], writer: &mut Writer) -> GenR
     )
 }
 
+fn define_gamemod_fn_settings_schema(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
+    writer.write(
+        "/// The [`SettingSchema`] for each setting of this [`GameMod`]\n\
+        pub const fn settings_schema(&self) -> &'static [SettingSchema] {\
+            match self {",
+    )?;
+
+    for ruleset in rulesets {
+        for gamemod in ruleset.mods.iter() {
+            writer.write("Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(_) => ")?;
+            writer.write(&gamemod.name)?;
+            writer.write("::settings_schema(),")?;
+        }
+    }
+
+    writer.write("_ => UnknownMod::settings_schema(),")?;
+
+    writer.write("}}")
+}
+
+fn define_gamemod_fn_with_defaults_filled(
+    rulesets: &[RulesetMods],
+    writer: &mut Writer,
+) -> GenResult {
+    writer.write(
+        "/// Replace all unset settings of this [`GameMod`] with their default value\n\
+        ///\n\
+        /// Settings without a fixed default value remain unchanged.\n\
+        #[must_use]\
+        pub fn with_defaults_filled(self) -> Self {\
+            match self {",
+    )?;
+
+    for ruleset in rulesets {
+        for gamemod in ruleset.mods.iter() {
+            if gamemod.settings.is_empty() {
+                continue;
+            }
+
+            writer.write("Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(m) => Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(m.with_defaults_filled()),")?;
+        }
+    }
+
+    writer.write(
+        "\
+                other => other,\
+            }\
+        }",
+    )
+}
+
+fn define_gamemod_fn_setting(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
+    writer.write(
+        "/// The value of the setting with the given name if it is set\n\
+        pub fn setting(&self, name: &str) -> Option<SettingSimple> {\
+            match self {",
+    )?;
+
+    for ruleset in rulesets {
+        for gamemod in ruleset.mods.iter() {
+            if gamemod.settings.is_empty() {
+                continue;
+            }
+
+            writer.write("Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(m) => m.setting(name),")?;
+        }
+    }
+
+    writer.write("_ => None, }}")
+}
+
+fn define_gamemod_fn_set_setting(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
+    writer.write(
+        "/// Set or unset the setting with the given name\n\
+        ///\n\
+        /// Returns `false` if there is no such setting or if the value has the\n\
+        /// wrong type.\n\
+        pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {\
+            match self {",
+    )?;
+
+    for ruleset in rulesets {
+        for gamemod in ruleset.mods.iter() {
+            if gamemod.settings.is_empty() {
+                continue;
+            }
+
+            writer.write("Self::")?;
+            writer.write(&gamemod.name)?;
+            writer.write("(m) => m.set_setting(name, value),")?;
+        }
+    }
+
+    writer.write("_ => false, }}")
+}
+
 fn define_gamemod_fn_into_simple(rulesets: &[RulesetMods], writer: &mut Writer) -> GenResult {
     writer.write(
         "/// Convert a [`GameMod`] into a [`GameModSimple`]\n\
@@ -2,6 +2,7 @@ use std::{
     borrow::Cow,
     cmp::Ordering,
     fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
 };
 
 use itoa::Buffer;
@@ -10,7 +11,11 @@ use serde::{
     Deserialize, Deserializer,
 };
 
-use crate::{error::GenResult, writer::Writer};
+use crate::{
+    curated::{CuratedSetting, DefaultValue},
+    error::GenResult,
+    writer::Writer,
+};
 
 #[derive(Copy, Clone, Debug, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -51,6 +56,19 @@ impl RulesetMods {
                     .incompatible_mods
                     .retain(|incompatible| incompatible != &gamemod.acronym);
             }
+
+            for gamemod in ruleset.mods.iter_mut() {
+                for setting in gamemod.settings.iter_mut() {
+                    match CuratedSetting::get(ruleset.name, gamemod.acronym.as_str(), &setting.name)
+                    {
+                        Some(curated) => setting.curated = curated,
+                        None => println!(
+                            "Missing curated data for setting `{}` of {}",
+                            setting.name, gamemod.name
+                        ),
+                    }
+                }
+            }
         }
     }
 
@@ -74,7 +92,7 @@ impl RulesetMods {
 
             let mut name = res.into_owned();
             name.push_str(self.name.as_capitalized_str());
-            gamemod.name = name.into_boxed_str();
+            gamemod.display_name = mem::replace(&mut gamemod.name, name.into_boxed_str());
         }
     }
 }
@@ -147,11 +165,29 @@ impl PartialOrd for Acronym {
 pub struct GameMod {
     pub acronym: Acronym,
     pub name: Box<str>,
+    /// The original name as provided by mods.json i.e. including whitespace
+    /// and without mode suffix
+    #[serde(skip)]
+    pub display_name: Box<str>,
     pub description: Box<str>,
     #[serde(rename = "Type")]
     pub kind: Box<str>,
     pub settings: Box<[Setting]>,
     pub incompatible_mods: Vec<Acronym>,
+    user_playable: bool,
+    valid_for_multiplayer: bool,
+    valid_for_multiplayer_as_free_mod: bool,
+    requires_configuration: bool,
+    always_valid_for_submission: bool,
+}
+
+/// A boolean property of a mod that determines where it may be used.
+#[derive(Copy, Clone)]
+pub struct Eligibility {
+    pub fn_name: &'static str,
+    /// Continuation of "Whether \<mod\> ..."
+    pub doc: &'static str,
+    pub value: bool,
 }
 
 impl GameMod {
@@ -193,6 +229,36 @@ impl GameMod {
         }
     }
 
+    pub const fn eligibility(&self) -> [Eligibility; 5] {
+        [
+            Eligibility {
+                fn_name: "is_user_playable",
+                doc: "is playable by a user i.e. the user interacts with the game",
+                value: self.user_playable,
+            },
+            Eligibility {
+                fn_name: "is_valid_for_multiplayer",
+                doc: "can be selected in multiplayer",
+                value: self.valid_for_multiplayer,
+            },
+            Eligibility {
+                fn_name: "is_valid_for_multiplayer_as_free_mod",
+                doc: "can be selected as free mod in multiplayer",
+                value: self.valid_for_multiplayer_as_free_mod,
+            },
+            Eligibility {
+                fn_name: "requires_configuration",
+                doc: "needs to be configured before it can be used",
+                value: self.requires_configuration,
+            },
+            Eligibility {
+                fn_name: "is_always_valid_for_submission",
+                doc: "is valid for score submission regardless of other mods or its settings",
+                value: self.always_valid_for_submission,
+            },
+        ]
+    }
+
     pub fn discriminant(&self) -> Option<u8> {
         self.bits().map(|n| (n.ilog2() as u8) + 1)
     }
@@ -406,10 +472,17 @@ impl GameMod {
         writer.write(b'{')?;
 
         self.define_fn_acronym(writer)?;
+        self.define_fn_name(writer)?;
         self.define_fn_incompatible_mods(writer)?;
         self.define_fn_description(writer)?;
         self.define_fn_kind(writer)?;
         self.define_fn_bits(writer, itoa_buf)?;
+        self.define_fns_eligibility(writer)?;
+        self.define_fn_settings_schema(writer)?;
+        self.define_fns_effective_settings(writer)?;
+        self.define_fn_with_defaults_filled(writer)?;
+        self.define_fn_setting(writer)?;
+        self.define_fn_set_setting(writer)?;
 
         writer.write(b'}')
     }
@@ -432,6 +505,19 @@ impl GameMod {
         )
     }
 
+    fn define_fn_name(&self, writer: &mut Writer) -> GenResult {
+        writer.write("/// The name of [`")?;
+        writer.write(&self.name)?;
+        writer.write(
+            "`]\n\
+            pub const fn name() -> &'static str {\
+                \"",
+        )?;
+        writer.write(&self.display_name)?;
+
+        writer.write("\"}")
+    }
+
     fn define_fn_incompatible_mods(&self, writer: &mut Writer) -> GenResult {
         writer.write("/// Iterator of [`Acronym`] for mods that are incompatible with [`")?;
         writer.write(&self.name)?;
@@ -504,6 +590,207 @@ impl GameMod {
 
         writer.write(b'}')
     }
+
+    fn define_fns_eligibility(&self, writer: &mut Writer) -> GenResult {
+        for eligibility in self.eligibility() {
+            writer.write("/// Whether [`")?;
+            writer.write(&self.name)?;
+            writer.write("`] ")?;
+            writer.write(eligibility.doc)?;
+            writer.write("\npub const fn ")?;
+            writer.write(eligibility.fn_name)?;
+            writer.write("() -> bool {")?;
+            writer.write(if eligibility.value { "true" } else { "false" })?;
+            writer.write(b'}')?;
+        }
+
+        Ok(())
+    }
+
+    fn define_fn_settings_schema(&self, writer: &mut Writer) -> GenResult {
+        writer.write("/// The [`SettingSchema`] for each setting of [`")?;
+        writer.write(&self.name)?;
+        writer.write(
+            "`]\n\
+            pub const fn settings_schema() -> &'static [SettingSchema] {\
+                &[",
+        )?;
+
+        for setting in self.settings.iter() {
+            setting.write_schema(writer)?;
+        }
+
+        writer.write("]}")
+    }
+
+    fn define_fns_effective_settings(&self, writer: &mut Writer) -> GenResult {
+        for setting in self.settings.iter() {
+            let Some(default) = setting.curated.default else {
+                continue;
+            };
+
+            writer.write("/// The value of [`")?;
+            writer.write(&self.name)?;
+            writer.write("::")?;
+            writer.write(&setting.name)?;
+            writer.write("`] or its default `")?;
+            default.write(writer)?;
+            writer.write("` if unset\n")?;
+
+            if let DefaultValue::String(_) = default {
+                writer.write("pub fn effective_")?;
+                writer.write(&setting.name)?;
+                writer.write("(&self) -> &str {")?;
+                writer.write("match self.")?;
+                writer.write(&setting.name)?;
+                writer.write(" { Some(ref value) => value, None => ")?;
+            } else {
+                writer.write("pub const fn effective_")?;
+                writer.write(&setting.name)?;
+                writer.write("(&self) -> ")?;
+                setting.kind.write(writer)?;
+                writer.write(" {")?;
+                writer.write("match self.")?;
+                writer.write(&setting.name)?;
+                writer.write(" { Some(value) => value, None => ")?;
+            }
+
+            default.write(writer)?;
+            writer.write("}}")?;
+        }
+
+        Ok(())
+    }
+
+    fn define_fn_with_defaults_filled(&self, writer: &mut Writer) -> GenResult {
+        writer.write("/// Replace all unset settings of [`")?;
+        writer.write(&self.name)?;
+        writer.write(
+            "`] with their default value\n\
+            ///\n\
+            /// Settings without a fixed default value remain unchanged.\n\
+            #[must_use]",
+        )?;
+
+        if self
+            .settings
+            .iter()
+            .all(|setting| setting.curated.default.is_none())
+        {
+            return writer.write("pub const fn with_defaults_filled(self) -> Self { self }");
+        }
+
+        writer.write("pub fn with_defaults_filled(self) -> Self { Self {")?;
+
+        for setting in self.settings.iter() {
+            writer.write(&setting.name)?;
+            writer.write(b':')?;
+
+            match setting.curated.default {
+                Some(DefaultValue::String(default)) => {
+                    writer.write("Some(self.")?;
+                    writer.write(&setting.name)?;
+                    writer.write(".unwrap_or_else(|| String::from(\"")?;
+                    writer.write(default)?;
+                    writer.write("\"))),")?;
+                }
+                Some(default) => {
+                    writer.write("Some(self.")?;
+                    writer.write(&setting.name)?;
+                    writer.write(".unwrap_or(")?;
+                    default.write(writer)?;
+                    writer.write(")),")?;
+                }
+                None => {
+                    writer.write("self.")?;
+                    writer.write(&setting.name)?;
+                    writer.write(b',')?;
+                }
+            }
+        }
+
+        writer.write("}}")
+    }
+
+    fn define_fn_setting(&self, writer: &mut Writer) -> GenResult {
+        writer.write("/// The value of the setting with the given name if it is set\n")?;
+
+        if self.settings.is_empty() {
+            return writer.write(
+                "pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {\
+                    None\
+                }",
+            );
+        }
+
+        writer.write(
+            "pub fn setting(&self, name: &str) -> Option<SettingSimple> {\
+                match name {",
+        )?;
+
+        for setting in self.settings.iter() {
+            writer.write(b'"')?;
+            writer.write(&setting.name)?;
+            writer.write("\" => self.")?;
+            writer.write(&setting.name)?;
+
+            if let SettingType::String = setting.kind {
+                writer.write(".clone()")?;
+            }
+
+            writer.write(".map(SettingSimple::")?;
+            writer.write(setting.kind.as_variant_str())?;
+            writer.write("),")?;
+        }
+
+        writer.write("_ => None, }}")
+    }
+
+    fn define_fn_set_setting(&self, writer: &mut Writer) -> GenResult {
+        writer.write(
+            "/// Set or unset the setting with the given name\n\
+            ///\n\
+            /// Returns `false` if there is no such setting or if the value has the\n\
+            /// wrong type.\n",
+        )?;
+
+        if self.settings.is_empty() {
+            return writer.write(
+                "pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {\
+                    false\
+                }",
+            );
+        }
+
+        writer.write(
+            "pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {\
+                match (name, value) {",
+        )?;
+
+        for setting in self.settings.iter() {
+            writer.write("(\"")?;
+            writer.write(&setting.name)?;
+            writer.write("\", None) => self.")?;
+            writer.write(&setting.name)?;
+            writer.write(" = None,")?;
+
+            writer.write("(\"")?;
+            writer.write(&setting.name)?;
+            writer.write("\", Some(SettingSimple::")?;
+            writer.write(setting.kind.as_variant_str())?;
+            writer.write("(value))) => self.")?;
+            writer.write(&setting.name)?;
+            writer.write(" = Some(value),")?;
+        }
+
+        writer.write(
+            "\
+                    _ => return false,\
+                }\
+                true\
+            }",
+        )
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -514,6 +801,8 @@ pub struct Setting {
     pub kind: SettingType,
     #[serde(rename = "Description")]
     description: Box<str>,
+    #[serde(skip)]
+    pub curated: CuratedSetting,
 }
 
 impl Setting {
@@ -529,6 +818,38 @@ impl Setting {
 
         writer.write(">,")
     }
+
+    pub fn write_schema(&self, writer: &mut Writer) -> GenResult {
+        writer.write("SettingSchema { name: \"")?;
+        writer.write(&self.name)?;
+        writer.write("\", kind: SettingKind::")?;
+        writer.write(self.kind.as_variant_str())?;
+        writer.write(", description: \"")?;
+        writer.write(&self.description)?;
+        writer.write("\", default: ")?;
+
+        match self.curated.default {
+            Some(default) => {
+                writer.write("Some(")?;
+                default.write_setting_default(writer)?;
+                writer.write(b')')?;
+            }
+            None => writer.write("None")?,
+        }
+
+        writer.write(", range: ")?;
+
+        match self.curated.range {
+            Some(range) => {
+                writer.write("Some(")?;
+                range.write(writer)?;
+                writer.write(b')')?;
+            }
+            None => writer.write("None")?,
+        }
+
+        writer.write(" },")
+    }
 }
 
 #[derive(Copy, Clone, Debug, Deserialize)]
@@ -542,6 +863,14 @@ pub enum SettingType {
 }
 
 impl SettingType {
+    pub fn as_variant_str(self) -> &'static str {
+        match self {
+            Self::Bool => "Bool",
+            Self::Number => "Number",
+            Self::String => "String",
+        }
+    }
+
     pub fn write(self, writer: &mut Writer) -> GenResult {
         match self {
             Self::Bool => writer.write("bool"),
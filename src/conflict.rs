@@ -0,0 +1,106 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+use crate::{GameMod, GameModIntermode, GameModKind};
+
+/// A pair of mods that cannot be combined.
+///
+/// Returned by [`GameMods::conflicts`].
+///
+/// [`GameMods::conflicts`]: crate::GameMods::conflicts
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModConflict<'a> {
+    /// The mod whose incompatible mods contain [`excluded`].
+    ///
+    /// [`excluded`]: ModConflict::excluded
+    pub gamemod: &'a GameMod,
+    /// The mod that is excluded by [`gamemod`].
+    ///
+    /// [`gamemod`]: ModConflict::gamemod
+    pub excluded: &'a GameMod,
+    /// Whether [`excluded`] also lists [`gamemod`] as incompatible.
+    ///
+    /// [`gamemod`]: ModConflict::gamemod
+    /// [`excluded`]: ModConflict::excluded
+    pub mutual: bool,
+}
+
+impl Display for ModConflict<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} is incompatible with {}",
+            self.gamemod.acronym(),
+            self.excluded.acronym()
+        )
+    }
+}
+
+/// Decides which of two conflicting mods [`GameMods::sanitize_with`] keeps.
+///
+/// If the strategy considers both mods equal, the mod that comes first in
+/// [`GameMods`] is kept, just like [`GameMods::sanitize`] does.
+///
+/// [`GameMods`]: crate::GameMods
+/// [`GameMods::sanitize`]: crate::GameMods::sanitize
+/// [`GameMods::sanitize_with`]: crate::GameMods::sanitize_with
+#[derive(Copy, Clone)]
+pub enum SanitizeStrategy<'a> {
+    /// Keep the mod that comes first in the given order, e.g. the order in
+    /// which the mods were selected. Mods that are not in the order lose
+    /// against those that are.
+    KeepEarliest(&'a [GameModIntermode]),
+    /// Keep the mod that comes last in the given order, e.g. the order in
+    /// which the mods were selected. Mods that are not in the order lose
+    /// against those that are.
+    KeepLatest(&'a [GameModIntermode]),
+    /// Keep the mod whose [`GameModKind`] comes first in the given list.
+    /// Kinds that are not in the list lose against those that are.
+    PreferKind(&'a [GameModKind]),
+    /// Keep the first mod if the closure returns [`Ordering::Less`] and the
+    /// second mod if it returns [`Ordering::Greater`].
+    ///
+    /// The closure should be a total order. Otherwise, no panic occurs but
+    /// which mods are kept is unspecified.
+    Custom(&'a dyn Fn(&GameMod, &GameMod) -> Ordering),
+}
+
+impl SanitizeStrategy<'_> {
+    /// Compares two mods such that the preferred mod is [`Ordering::Less`].
+    pub(crate) fn compare(&self, a: &GameMod, b: &GameMod) -> Ordering {
+        fn position<T: PartialEq>(list: &[T], item: &T) -> usize {
+            list.iter().position(|x| x == item).unwrap_or(list.len())
+        }
+
+        match self {
+            Self::KeepEarliest(order) => {
+                position(order, &a.intermode()).cmp(&position(order, &b.intermode()))
+            }
+            Self::KeepLatest(order) => {
+                let rank = |gamemod: &GameMod| {
+                    order
+                        .iter()
+                        .rposition(|intermode| *intermode == gamemod.intermode())
+                        .map_or(order.len(), |idx| order.len() - 1 - idx)
+                };
+
+                rank(a).cmp(&rank(b))
+            }
+            Self::PreferKind(kinds) => position(kinds, &a.kind()).cmp(&position(kinds, &b.kind())),
+            Self::Custom(f) => f(a, b),
+        }
+    }
+}
+
+impl Debug for SanitizeStrategy<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::KeepEarliest(order) => f.debug_tuple("KeepEarliest").field(order).finish(),
+            Self::KeepLatest(order) => f.debug_tuple("KeepLatest").field(order).finish(),
+            Self::PreferKind(kinds) => f.debug_tuple("PreferKind").field(kinds).finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
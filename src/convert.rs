@@ -0,0 +1,222 @@
+use crate::{GameMod, GameModIntermode, GameMode, GameMods, GameModsLegacy, SettingSimple};
+
+/// The result of [`GameMods::convert_to_mode`].
+///
+/// Contains the converted mods as well as everything that could not be
+/// carried over to the target mode.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModeConversion {
+    /// The mods of the target mode.
+    pub mods: GameMods,
+    /// Mods that don't exist in the target mode.
+    pub dropped_mods: Vec<GameMod>,
+    /// Settings that don't exist on the target mode's mod.
+    pub dropped_settings: Vec<DroppedSetting>,
+}
+
+/// The result of [`GameMods::to_legacy_report`].
+///
+/// Contains the legacy mods as well as everything that could not be
+/// represented by them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LegacyConversion {
+    /// The legacy mods of all mods that have a bit value.
+    pub legacy: GameModsLegacy,
+    /// Mods without a bit value.
+    pub dropped_mods: Vec<GameMod>,
+    /// Settings that differ from their default value.
+    pub dropped_settings: Vec<DroppedSetting>,
+}
+
+/// A setting that was lost in a [`ModeConversion`] or [`LegacyConversion`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DroppedSetting {
+    /// The original mod that carried the setting.
+    pub gamemod: GameMod,
+    /// The name of the setting.
+    pub setting: &'static str,
+    /// The value of the setting.
+    pub value: SettingSimple,
+}
+
+impl ModeConversion {
+    pub(crate) fn new(mods: &GameMods, mode: GameMode) -> Self {
+        let mut conversion = Self::default();
+
+        for gamemod in mods.iter() {
+            if gamemod.mode() == mode {
+                conversion.mods.insert(gamemod.clone());
+
+                continue;
+            }
+
+            let mut converted = GameMod::new(gamemod.acronym().as_str(), mode);
+
+            if matches!(converted.intermode(), GameModIntermode::Unknown(_)) {
+                conversion.dropped_mods.push(gamemod.clone());
+
+                continue;
+            }
+
+            for schema in gamemod.settings_schema() {
+                let Some(value) = gamemod.setting(schema.name) else {
+                    continue;
+                };
+
+                if !converted.set_setting(schema.name, Some(value.clone())) {
+                    conversion.dropped_settings.push(DroppedSetting {
+                        gamemod: gamemod.clone(),
+                        setting: schema.name,
+                        value,
+                    });
+                }
+            }
+
+            conversion.mods.insert(converted);
+        }
+
+        conversion
+    }
+
+    /// Whether all mods and settings were carried over.
+    pub const fn is_lossless(&self) -> bool {
+        self.dropped_mods.is_empty() && self.dropped_settings.is_empty()
+    }
+}
+
+impl LegacyConversion {
+    pub(crate) fn new(mods: &GameMods) -> Self {
+        let mut conversion = Self::default();
+
+        for gamemod in mods.iter() {
+            let Some(bits) = gamemod.bits() else {
+                conversion.dropped_mods.push(gamemod.clone());
+
+                continue;
+            };
+
+            conversion.legacy |= GameModsLegacy::from_bits(bits);
+
+            let mut normalized = gamemod.clone();
+            normalized.normalize();
+
+            for schema in normalized.settings_schema() {
+                if let Some(value) = normalized.setting(schema.name) {
+                    conversion.dropped_settings.push(DroppedSetting {
+                        gamemod: gamemod.clone(),
+                        setting: schema.name,
+                        value,
+                    });
+                }
+            }
+        }
+
+        conversion
+    }
+
+    /// Whether the legacy mods represent all mods and settings.
+    pub const fn is_lossless(&self) -> bool {
+        self.dropped_mods.is_empty() && self.dropped_settings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generated_mods::{
+        DifficultyAdjustOsu, DifficultyAdjustTaiko, DoubleTimeMania, DoubleTimeOsu,
+    };
+
+    use super::*;
+
+    #[test]
+    fn shared_settings() {
+        let mods: GameMods = [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.3),
+            adjust_pitch: Some(true),
+        })]
+        .into_iter()
+        .collect();
+
+        let conversion = mods.convert_to_mode(GameMode::Mania);
+
+        assert!(conversion.is_lossless());
+        assert_eq!(
+            conversion.mods,
+            GameMods::from(GameMod::DoubleTimeMania(DoubleTimeMania {
+                speed_change: Some(1.3),
+                adjust_pitch: Some(true),
+            }))
+        );
+    }
+
+    #[test]
+    fn dropped() {
+        let da = GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(9.5),
+            drain_rate: Some(4.0),
+            ..Default::default()
+        });
+        let fi = GameMod::FadeInMania(Default::default());
+
+        let mods: GameMods = [da.clone(), fi.clone()].into_iter().collect();
+        let conversion = mods.convert_to_mode(GameMode::Taiko);
+
+        assert_eq!(
+            conversion.mods,
+            GameMods::from(GameMod::DifficultyAdjustTaiko(DifficultyAdjustTaiko {
+                drain_rate: Some(4.0),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(conversion.dropped_mods, [fi]);
+        assert_eq!(
+            conversion.dropped_settings,
+            [DroppedSetting {
+                gamemod: da,
+                setting: "approach_rate",
+                value: SettingSimple::Number(9.5),
+            }]
+        );
+    }
+
+    #[test]
+    fn legacy() {
+        let dt = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.3),
+            adjust_pitch: Some(false),
+        });
+        let da = GameMod::DifficultyAdjustOsu(Default::default());
+
+        let mods: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            dt.clone(),
+            da.clone(),
+        ]
+        .into_iter()
+        .collect();
+
+        let conversion = mods.to_legacy_report();
+
+        assert_eq!(
+            conversion.legacy,
+            GameModsLegacy::Hidden | GameModsLegacy::DoubleTime
+        );
+        assert_eq!(conversion.dropped_mods, [da]);
+        assert_eq!(
+            conversion.dropped_settings,
+            [DroppedSetting {
+                gamemod: dt,
+                setting: "speed_change",
+                value: SettingSimple::Number(1.3),
+            }]
+        );
+
+        let nc: GameMods = [GameMod::NightcoreOsu(Default::default())]
+            .into_iter()
+            .collect();
+        let conversion = nc.to_legacy_report();
+
+        assert!(conversion.is_lossless());
+        assert_eq!(conversion.legacy, GameModsLegacy::Nightcore);
+    }
+}
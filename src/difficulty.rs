@@ -0,0 +1,395 @@
+/// Difficulty attributes of a beatmap.
+///
+/// Mods can be applied through [`GameMods::apply_to_difficulty`].
+///
+/// [`GameMods::apply_to_difficulty`]: crate::GameMods::apply_to_difficulty
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeatmapDifficulty {
+    /// Circle size
+    pub cs: f64,
+    /// Approach rate
+    pub ar: f64,
+    /// Overall difficulty
+    pub od: f64,
+    /// Drain rate
+    pub hp: f64,
+}
+
+impl BeatmapDifficulty {
+    const HARD_ROCK_RATIO: f64 = 1.4;
+    const HARD_ROCK_CS_RATIO: f64 = 1.3;
+    const EASY_RATIO: f64 = 0.5;
+
+    /// Create a new [`BeatmapDifficulty`].
+    pub const fn new(cs: f64, ar: f64, od: f64, hp: f64) -> Self {
+        Self { cs, ar, od, hp }
+    }
+
+    /// The time in milliseconds that hit objects are visible before they need
+    /// to be hit, adjusted by the given clock rate.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_mods::BeatmapDifficulty;
+    ///
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 8.0, 5.0);
+    /// assert_eq!(difficulty.approach_rate_ms(1.0), 600.0);
+    /// assert_eq!(difficulty.approach_rate_ms(1.5), 400.0);
+    /// ```
+    pub fn approach_rate_ms(&self, clock_rate: f64) -> f64 {
+        difficulty_range(self.ar, 1800.0, 1200.0, 450.0) / clock_rate
+    }
+
+    /// The hit window in milliseconds for a 300 in osu!standard, adjusted by
+    /// the given clock rate.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_mods::BeatmapDifficulty;
+    ///
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 5.0, 5.0);
+    /// assert_eq!(difficulty.overall_difficulty_ms(1.0), 50.0);
+    /// assert_eq!(difficulty.overall_difficulty_ms(0.5), 100.0);
+    /// ```
+    pub fn overall_difficulty_ms(&self, clock_rate: f64) -> f64 {
+        difficulty_range(self.od, 80.0, 50.0, 20.0) / clock_rate
+    }
+
+    /// Adjust AR and OD so that they result in the same timings at clock rate
+    /// 1.0 as the current values do at the given clock rate.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_mods::BeatmapDifficulty;
+    ///
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 8.0, 5.0);
+    /// let adjusted = difficulty.with_clock_rate(1.5);
+    ///
+    /// assert!((adjusted.ar - 10.333).abs() < 0.001);
+    /// assert!((adjusted.od - 9.778).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn with_clock_rate(self, clock_rate: f64) -> Self {
+        let preempt = self.approach_rate_ms(clock_rate);
+        let great_hit_window = self.overall_difficulty_ms(clock_rate);
+
+        let ar = if preempt > 1200.0 {
+            5.0 - (preempt - 1200.0) / 120.0
+        } else {
+            5.0 + (1200.0 - preempt) / 150.0
+        };
+
+        Self {
+            ar,
+            od: (80.0 - great_hit_window) / 6.0,
+            ..self
+        }
+    }
+
+    pub(crate) fn apply_hard_rock(&mut self) {
+        self.cs = (self.cs * Self::HARD_ROCK_CS_RATIO).min(10.0);
+        self.ar = (self.ar * Self::HARD_ROCK_RATIO).min(10.0);
+        self.od = (self.od * Self::HARD_ROCK_RATIO).min(10.0);
+        self.hp = (self.hp * Self::HARD_ROCK_RATIO).min(10.0);
+    }
+
+    pub(crate) fn apply_easy(&mut self) {
+        self.cs *= Self::EASY_RATIO;
+        self.ar *= Self::EASY_RATIO;
+        self.od *= Self::EASY_RATIO;
+        self.hp *= Self::EASY_RATIO;
+    }
+
+    /// Apply the overrides of `DifficultyAdjust` while respecting the limits
+    /// of its settings.
+    pub(crate) const fn apply_overrides(&mut self, overrides: DifficultyOverrides) {
+        let max = if overrides.extended_limits {
+            11.0
+        } else {
+            10.0
+        };
+        let min_ar = if overrides.extended_limits {
+            -10.0
+        } else {
+            0.0
+        };
+
+        if let Some(cs) = overrides.cs {
+            self.cs = cs.clamp(0.0, max);
+        }
+
+        if let Some(ar) = overrides.ar {
+            self.ar = ar.clamp(min_ar, max);
+        }
+
+        if let Some(od) = overrides.od {
+            self.od = od.clamp(0.0, max);
+        }
+
+        if let Some(hp) = overrides.hp {
+            self.hp = hp.clamp(0.0, max);
+        }
+    }
+}
+
+/// Hit windows in milliseconds i.e. how far off a hit may be to still get
+/// the respective judgement.
+///
+/// Created through [`GameMods::hit_windows`].
+///
+/// [`GameMods::hit_windows`]: crate::GameMods::hit_windows
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HitWindows {
+    /// The window for a PERFECT in osu!mania. `None` for other modes.
+    pub perfect: Option<f64>,
+    /// The window for a 300 or GREAT.
+    pub great: f64,
+    /// The window for a GOOD in osu!mania. `None` for other modes.
+    pub good: Option<f64>,
+    /// The window for a 100 or OK.
+    pub ok: f64,
+    /// The window for a 50 or MEH. `None` for osu!taiko.
+    pub meh: Option<f64>,
+}
+
+impl HitWindows {
+    /// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Osu/Scoring/OsuHitWindows.cs>
+    pub(crate) fn osu(od: f64, clock_rate: f64) -> Self {
+        Self {
+            perfect: None,
+            great: difficulty_range(od, 80.0, 50.0, 20.0) / clock_rate,
+            good: None,
+            ok: difficulty_range(od, 140.0, 100.0, 60.0) / clock_rate,
+            meh: Some(difficulty_range(od, 200.0, 150.0, 100.0) / clock_rate),
+        }
+    }
+
+    /// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Taiko/Scoring/TaikoHitWindows.cs>
+    pub(crate) fn taiko(od: f64, clock_rate: f64) -> Self {
+        Self {
+            perfect: None,
+            great: difficulty_range(od, 50.0, 35.0, 20.0) / clock_rate,
+            good: None,
+            ok: difficulty_range(od, 120.0, 80.0, 50.0) / clock_rate,
+            meh: None,
+        }
+    }
+
+    /// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Mania/Scoring/ManiaHitWindows.cs>
+    pub(crate) fn mania(od: f64, multiplier: f64) -> Self {
+        let window = |min, mid, max| difficulty_range(od, min, mid, max) * multiplier;
+
+        Self {
+            perfect: Some(window(22.4, 19.4, 13.9)),
+            great: window(64.0, 49.0, 34.0),
+            good: Some(window(97.0, 82.0, 67.0)),
+            ok: window(127.0, 112.0, 97.0),
+            meh: Some(window(151.0, 136.0, 121.0)),
+        }
+    }
+
+    /// osu!stable's windows in osu!mania which differ for converts.
+    pub(crate) fn mania_classic(od: f64, multiplier: f64, is_convert: bool) -> Self {
+        let (great, good, offset) = if is_convert {
+            let is_hard = od.round_ties_even() > 4.0;
+
+            (
+                if is_hard { 34.0 } else { 47.0 },
+                if is_hard { 67.0 } else { 77.0 },
+                0.0,
+            )
+        } else {
+            let inverted_od = (10.0 - od).clamp(0.0, 10.0);
+
+            (34.0, 67.0, 3.0 * inverted_od)
+        };
+
+        Self {
+            perfect: Some(16.0 * multiplier),
+            great: (great + offset) * multiplier,
+            good: Some((good + offset) * multiplier),
+            ok: (97.0 + offset) * multiplier,
+            meh: Some((121.0 + offset) * multiplier),
+        }
+    }
+}
+
+/// The settings of `DifficultyAdjust` that affect [`BeatmapDifficulty`].
+#[derive(Copy, Clone, Default)]
+pub(crate) struct DifficultyOverrides {
+    pub cs: Option<f64>,
+    pub ar: Option<f64>,
+    pub od: Option<f64>,
+    pub hp: Option<f64>,
+    pub extended_limits: bool,
+}
+
+/// Maps a difficulty value in `[0, 10]` onto the given range with `mid`
+/// corresponding to a value of 5.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game/Beatmaps/IBeatmapDifficultyInfo.cs>
+pub(crate) fn difficulty_range(difficulty: f64, min: f64, mid: f64, max: f64) -> f64 {
+    if difficulty > 5.0 {
+        mid + (max - mid) * (difficulty - 5.0) / 5.0
+    } else if difficulty < 5.0 {
+        mid + (mid - min) * (difficulty - 5.0) / 5.0
+    } else {
+        mid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generated_mods::{DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu},
+        GameMod, GameMode, GameMods,
+    };
+
+    use super::*;
+
+    const DIFFICULTY: BeatmapDifficulty = BeatmapDifficulty::new(4.0, 9.0, 8.0, 5.0);
+
+    #[test]
+    fn hard_rock() {
+        let mods = GameMods::from(GameMod::HardRockOsu(Default::default()));
+        let adjusted = mods.apply_to_difficulty(DIFFICULTY);
+
+        assert!((adjusted.cs - 5.2).abs() < f64::EPSILON);
+        assert!((adjusted.ar - 10.0).abs() < f64::EPSILON);
+        assert!((adjusted.od - 10.0).abs() < f64::EPSILON);
+        assert!((adjusted.hp - 7.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn easy() {
+        let mods = GameMods::from(GameMod::EasyCatch(Default::default()));
+        let adjusted = mods.apply_to_difficulty(DIFFICULTY);
+
+        assert_eq!(adjusted, BeatmapDifficulty::new(2.0, 4.5, 4.0, 2.5));
+    }
+
+    #[test]
+    fn difficulty_adjust() {
+        let mods = GameMods::from(GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(10.5),
+            overall_difficulty: Some(3.0),
+            ..Default::default()
+        }));
+        let adjusted = mods.apply_to_difficulty(DIFFICULTY);
+
+        assert_eq!(adjusted, BeatmapDifficulty::new(4.0, 10.0, 3.0, 5.0));
+
+        let mods = GameMods::from(GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+            approach_rate: Some(10.5),
+            extended_limits: Some(true),
+            ..Default::default()
+        }));
+        let adjusted = mods.apply_to_difficulty(DIFFICULTY);
+
+        assert_eq!(adjusted, BeatmapDifficulty::new(4.0, 10.5, 8.0, 5.0));
+    }
+
+    #[test]
+    fn clock_rate() {
+        let adjusted = DIFFICULTY.with_clock_rate(0.75);
+
+        assert!((adjusted.approach_rate_ms(1.0) - DIFFICULTY.approach_rate_ms(0.75)).abs() < 1e-9);
+        assert!(
+            (adjusted.overall_difficulty_ms(1.0) - DIFFICULTY.overall_difficulty_ms(0.75)).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn hit_windows() {
+        let ht = GameMods::from(GameMod::HalfTimeTaiko(Default::default()));
+        let windows = ht.hit_windows(DIFFICULTY, GameMode::Taiko, false).unwrap();
+
+        assert!((windows.great - 26.0 / 0.75).abs() < 1e-9);
+        assert!((windows.ok - 62.0 / 0.75).abs() < 1e-9);
+        assert_eq!(windows.meh, None);
+
+        let mods: GameMods = [
+            GameMod::EasyMania(Default::default()),
+            GameMod::DoubleTimeMania(Default::default()),
+            GameMod::DifficultyAdjustMania(DifficultyAdjustMania {
+                overall_difficulty: Some(10.0),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+        let windows = mods
+            .hit_windows(DIFFICULTY, GameMode::Mania, false)
+            .unwrap();
+
+        assert_eq!(windows.perfect, Some(13.9 * 1.4));
+        assert!((windows.great - 34.0 * 1.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hard_rock_mania() {
+        let mods = GameMods::from(GameMod::HardRockMania(Default::default()));
+        let adjusted = mods.apply_to_difficulty(DIFFICULTY);
+
+        assert_eq!(adjusted, DIFFICULTY);
+
+        let windows = mods
+            .hit_windows(DIFFICULTY, GameMode::Mania, false)
+            .unwrap();
+        let nomod = GameMods::new()
+            .hit_windows(adjusted, GameMode::Mania, false)
+            .unwrap();
+
+        assert!((windows.great - nomod.great / 1.4).abs() < 1e-9);
+        assert!((windows.ok - nomod.ok / 1.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mismatching_mode() {
+        let hr = GameMods::from(GameMod::HardRockOsu(Default::default()));
+
+        assert_eq!(
+            hr.hit_windows(DIFFICULTY, GameMode::Mania, false),
+            GameMods::new().hit_windows(DIFFICULTY, GameMode::Mania, false)
+        );
+        assert_eq!(
+            hr.approach_preempt(DIFFICULTY, GameMode::Catch),
+            GameMods::new().approach_preempt(DIFFICULTY, GameMode::Catch)
+        );
+    }
+
+    #[test]
+    fn classic_mania() {
+        let mut mods = GameMods::from(GameMod::ClassicMania(Default::default()));
+        let windows = mods
+            .hit_windows(DIFFICULTY, GameMode::Mania, false)
+            .unwrap();
+
+        assert_eq!(
+            windows,
+            HitWindows {
+                perfect: Some(16.0),
+                great: 40.0,
+                good: Some(73.0),
+                ok: 103.0,
+                meh: Some(127.0),
+            }
+        );
+
+        let easy = BeatmapDifficulty {
+            od: 4.4,
+            ..DIFFICULTY
+        };
+        let windows = mods.hit_windows(easy, GameMode::Mania, true).unwrap();
+        assert!((windows.great - 47.0).abs() < f64::EPSILON);
+        assert_eq!(windows.good, Some(77.0));
+
+        mods.insert(GameMod::HardRockMania(Default::default()));
+        let windows = mods.hit_windows(DIFFICULTY, GameMode::Mania, true).unwrap();
+        assert!((windows.great - 34.0 / 1.4).abs() < 1e-9);
+        assert!((windows.ok - 97.0 / 1.4).abs() < 1e-9);
+    }
+}
@@ -3,6 +3,8 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
+use crate::{tournament::PoolSlot, Acronym, GameMod, GameMode, GameModsIntermode};
+
 /// Error while trying to parse an [`Acronym`].
 ///
 /// [`Acronym`]: crate::Acronym
@@ -31,6 +33,8 @@ impl Display for AcronymParseError {
 pub struct GameModsLegacyParseError {
     /// The string on which parsing failed.
     pub mods: Box<str>,
+    /// Where and why parsing failed.
+    pub cause: AcronymsParseError,
 }
 
 impl Error for GameModsLegacyParseError {}
@@ -39,8 +43,365 @@ impl Display for GameModsLegacyParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(
             f,
-            "Failed to parse string `{}` into GameModsLegacy",
-            self.mods
+            "Failed to parse string `{}` into GameModsLegacy: {}",
+            self.mods, self.cause
         )
     }
 }
+
+/// Error while trying to parse a combination of acronyms.
+///
+/// Returned by [`GameModsIntermode::parse_acronyms`] and contained in
+/// [`GameModsLegacyParseError`].
+///
+/// [`GameModsIntermode::parse_acronyms`]: crate::GameModsIntermode::parse_acronyms
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcronymsParseError {
+    /// The byte offset of [`substring`] within the parsed string.
+    ///
+    /// [`substring`]: AcronymsParseError::substring
+    pub offset: usize,
+    /// The part of the parsed string that caused the error.
+    pub substring: Box<str>,
+    /// What kind of error occurred.
+    pub kind: AcronymsParseErrorKind,
+    /// The known acronym that is closest to [`substring`], if any is close.
+    ///
+    /// [`substring`]: AcronymsParseError::substring
+    pub suggestion: Option<Acronym>,
+}
+
+impl Error for AcronymsParseError {}
+
+impl Display for AcronymsParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            AcronymsParseErrorKind::UnknownAcronym => f.write_str("Unknown acronym")?,
+            AcronymsParseErrorKind::AmbiguousSplit => f.write_str("Ambiguous acronyms")?,
+        }
+
+        write!(f, " `{}` at position {}", self.substring, self.offset)?;
+
+        if let Some(ref suggestion) = self.suggestion {
+            write!(f, ", did you mean `{suggestion}`?")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The kind of an [`AcronymsParseError`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AcronymsParseErrorKind {
+    /// The substring is not a known acronym.
+    UnknownAcronym,
+    /// The substring consists of known acronyms but whether it is split
+    /// after two or three characters, the remainder cannot be parsed e.g.
+    /// `SV2K` is neither `SV2` + `K` nor `SV` + `2K`.
+    AmbiguousSplit,
+}
+
+/// Error while trying to parse [`GameMods`] with inline settings through
+/// [`GameMods::parse_with_settings`].
+///
+/// [`GameMods`]: crate::GameMods
+/// [`GameMods::parse_with_settings`]: crate::GameMods::parse_with_settings
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameModsParseError {
+    /// The acronyms could not be parsed.
+    Acronyms(AcronymsParseError),
+    /// The acronym is known but there is no such mod for the mode.
+    InvalidForMode { acronym: Acronym, mode: GameMode },
+    /// Settings in parentheses were not closed.
+    UnclosedParenthesis,
+    /// A parenthesis did not follow a mod.
+    UnexpectedParenthesis,
+    /// The mod has no setting with the given name.
+    UnknownSetting {
+        /// The acronym of the mod.
+        acronym: Acronym,
+        /// The name of the setting as given in the string.
+        setting: Box<str>,
+    },
+    /// The value could not be parsed into the setting's type.
+    InvalidValue {
+        /// The acronym of the mod.
+        acronym: Acronym,
+        /// The name of the setting.
+        setting: &'static str,
+        /// The value as given in the string.
+        value: Box<str>,
+    },
+    /// A value without name was given for a mod without `speed_change` or
+    /// more than once for the same mod.
+    UnexpectedValue {
+        /// The acronym of the mod.
+        acronym: Acronym,
+        /// The value as given in the string.
+        value: Box<str>,
+    },
+}
+
+impl Error for GameModsParseError {}
+
+impl Display for GameModsParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Acronyms(err) => Display::fmt(err, f),
+            Self::InvalidForMode { acronym, mode } => {
+                write!(f, "There is no mod {acronym} for mode {mode:?}")
+            }
+            Self::UnclosedParenthesis => f.write_str("Missing closing parenthesis"),
+            Self::UnexpectedParenthesis => f.write_str("Parenthesis without preceding mod"),
+            Self::UnknownSetting { acronym, setting } => {
+                write!(f, "{acronym} has no setting `{setting}`")
+            }
+            Self::InvalidValue {
+                acronym,
+                setting,
+                value,
+            } => write!(f, "Invalid value `{value}` for {acronym} `{setting}`"),
+            Self::UnexpectedValue { acronym, value } => {
+                write!(
+                    f,
+                    "Unexpected value `{value}` without setting name for {acronym}"
+                )
+            }
+        }
+    }
+}
+
+/// Error returned by [`GameMod::validate_settings`] and
+/// [`GameMods::validate`] listing all invalid settings.
+///
+/// [`GameMod::validate_settings`]: crate::GameMod::validate_settings
+/// [`GameMods::validate`]: crate::GameMods::validate
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettingsValidationError {
+    /// All settings that failed validation.
+    pub invalid: Vec<InvalidSetting>,
+}
+
+impl Error for SettingsValidationError {}
+
+impl Display for SettingsValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Invalid mod settings")?;
+
+        for (i, invalid) in self.invalid.iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { "; " })?;
+            Display::fmt(invalid, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single setting that failed validation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidSetting {
+    /// The acronym of the mod that contains the setting.
+    pub acronym: Acronym,
+    /// The name of the setting.
+    pub setting: &'static str,
+    /// Why the setting is invalid.
+    pub reason: InvalidSettingReason,
+}
+
+impl Display for InvalidSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} `{}` ", self.acronym, self.setting)?;
+
+        match self.reason {
+            InvalidSettingReason::OutOfRange { value, min, max } => {
+                write!(f, "is {value} but must be between {min} and {max}")
+            }
+            InvalidSettingReason::OffStep { value, precision } => {
+                write!(f, "is {value} but must be a multiple of {precision}")
+            }
+            InvalidSettingReason::Contradiction { other } => {
+                write!(f, "contradicts `{other}`")
+            }
+        }
+    }
+}
+
+/// The reason why an [`InvalidSetting`] is invalid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InvalidSettingReason {
+    /// The value lies outside of the setting's bounds.
+    OutOfRange { value: f64, min: f64, max: f64 },
+    /// The value is not a multiple of the setting's precision.
+    OffStep { value: f64, precision: f64 },
+    /// The value contradicts another setting of the same mod e.g. the
+    /// `initial_rate` of `WindUp` is not smaller than its `final_rate`.
+    Contradiction {
+        /// The name of the other setting.
+        other: &'static str,
+    },
+}
+
+/// Error returned by the validation functions of the [`multiplayer`] module.
+///
+/// Each variant contains the mods that caused the error.
+///
+/// [`multiplayer`]: crate::multiplayer
+#[derive(Clone, Debug, PartialEq)]
+pub enum MultiplayerModsError {
+    /// The mods are not of the playlist item's mode.
+    WrongMode {
+        invalid: Vec<GameMod>,
+        mode: GameMode,
+    },
+    /// The mods exclude each other or appear multiple times.
+    IncompatibleMods { invalid: Vec<GameMod> },
+    /// The required mods contain mods that are not valid for multiplayer.
+    InvalidRequiredMods { invalid: Vec<GameMod> },
+    /// The allowed mods contain mods that are not valid as free mods.
+    InvalidFreeMods { invalid: Vec<GameMod> },
+    /// Allowed or chosen free mods are incompatible with the required mods.
+    IncompatibleWithRequiredMods { invalid: Vec<GameMod> },
+    /// The chosen free mods are not among the allowed mods.
+    NotAllowed { invalid: Vec<GameMod> },
+}
+
+impl MultiplayerModsError {
+    /// The mods that caused the error.
+    pub fn invalid(&self) -> &[GameMod] {
+        match self {
+            Self::WrongMode { invalid, .. }
+            | Self::IncompatibleMods { invalid }
+            | Self::InvalidRequiredMods { invalid }
+            | Self::InvalidFreeMods { invalid }
+            | Self::IncompatibleWithRequiredMods { invalid }
+            | Self::NotAllowed { invalid } => invalid,
+        }
+    }
+}
+
+impl Error for MultiplayerModsError {}
+
+impl Display for MultiplayerModsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::WrongMode { mode, .. } => write!(f, "Mods are not for mode {mode:?}")?,
+            Self::IncompatibleMods { .. } => f.write_str("Invalid combination of mods")?,
+            Self::InvalidRequiredMods { .. } => f.write_str("Invalid required mods")?,
+            Self::InvalidFreeMods { .. } => f.write_str("Invalid free mods")?,
+            Self::IncompatibleWithRequiredMods { .. } => {
+                f.write_str("Invalid combination of required and free mods")?;
+            }
+            Self::NotAllowed { .. } => f.write_str("Mods are not allowed")?,
+        }
+
+        for (i, gamemod) in self.invalid().iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { ", " })?;
+            Display::fmt(&gamemod.acronym(), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error when validating a play for a [`PoolSlot`] of a [`Mappool`].
+///
+/// [`Mappool`]: crate::Mappool
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlotValidationError {
+    /// The play contains mods that are not allowed on the slot.
+    NotAllowed {
+        slot: PoolSlot,
+        mods: GameModsIntermode,
+    },
+    /// The play lacks mods that are required on the slot.
+    MissingRequired {
+        slot: PoolSlot,
+        mods: GameModsIntermode,
+    },
+    /// The play contains none of the mods of which one is required.
+    MissingOneOf {
+        slot: PoolSlot,
+        mods: GameModsIntermode,
+    },
+    /// The play's mods exclude each other.
+    Incompatible { slot: PoolSlot },
+    /// The play's clock rate differs from the slot's clock rate.
+    ClockRate {
+        slot: PoolSlot,
+        expected: f64,
+        actual: Option<f64>,
+    },
+}
+
+impl Error for SlotValidationError {}
+
+impl Display for SlotValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotAllowed { slot, mods } => write!(f, "{mods} not allowed on {slot}"),
+            Self::MissingRequired { slot, mods } => write!(f, "{mods} required on {slot}"),
+            Self::MissingOneOf { slot, mods } => {
+                f.write_str("One of ")?;
+
+                for (i, gamemod) in mods.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("/")?;
+                    }
+
+                    f.write_str(gamemod.acronym().as_str())?;
+                }
+
+                write!(f, " required on {slot}")
+            }
+            Self::Incompatible { slot } => write!(f, "Invalid combination of mods on {slot}"),
+            Self::ClockRate {
+                slot,
+                expected,
+                actual: Some(actual),
+            } => write!(
+                f,
+                "Clock rate on {slot} must be {expected} but was {actual}"
+            ),
+            Self::ClockRate {
+                slot,
+                expected,
+                actual: None,
+            } => write!(f, "Clock rate on {slot} must be {expected}"),
+        }
+    }
+}
+
+/// Error when determining the mode of [`GameMods`] through [`GameMods::mode`].
+///
+/// [`GameMods`]: crate::GameMods
+/// [`GameMods::mode`]: crate::GameMods::mode
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameModsModeError {
+    /// There are no mods to determine a mode from.
+    Empty,
+    /// The mods belong to multiple modes.
+    Mixed { modes: Vec<GameMode> },
+}
+
+impl Error for GameModsModeError {}
+
+impl Display for GameModsModeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty => f.write_str("Cannot determine the mode of empty mods"),
+            Self::Mixed { modes } => {
+                f.write_str("Mods belong to multiple modes: ")?;
+
+                for (i, mode) in modes.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(mode.as_str())?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    setting::setting_alias, GameMod, GameModIntermode, GameMods, GameModsIntermode, GameModsLegacy,
+    SettingSimple,
+};
+
+/// Configurable [`Display`] of mods.
+///
+/// Created through [`GameMods::format`], [`GameModsIntermode::format`], or
+/// [`GameModsLegacy::format`].
+///
+/// # Example
+/// ```
+/// use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod, GameMods, FormatOrder};
+///
+/// let mut mods = GameMods::new();
+/// mods.insert(GameMod::HiddenOsu(Default::default()));
+/// mods.insert(GameMod::DoubleTimeOsu(DoubleTimeOsu {
+///     speed_change: Some(1.3),
+///     adjust_pitch: None,
+/// }));
+///
+/// assert_eq!(mods.format().prefix("+").to_string(), "+DTHD");
+/// assert_eq!(
+///     mods.format().separator(", ").settings(true).to_string(),
+///     "DT(1.3x), HD",
+/// );
+/// assert_eq!(
+///     mods.format().separator(", ").full_names(true).order(FormatOrder::Legacy).to_string(),
+///     "Hidden, Double Time",
+/// );
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct GameModsFormatter<'a> {
+    source: FormatSource<'a>,
+    separator: &'a str,
+    prefix: &'a str,
+    settings: bool,
+    full_names: bool,
+    order: FormatOrder,
+}
+
+/// The order in which a [`GameModsFormatter`] displays mods.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FormatOrder {
+    /// Keep the order of the formatted collection.
+    #[default]
+    Native,
+    /// osu!lazer's order i.e. sorted by [`GameModKind`] and then by acronym.
+    ///
+    /// [`GameModKind`]: crate::GameModKind
+    Lazer,
+    /// osu!stable's order i.e. sorted by the legacy bits. Mods without bits
+    /// come last, sorted by acronym.
+    Legacy,
+    /// Sorted alphabetically by acronym or by name if full names are shown.
+    Alphabetical,
+}
+
+#[derive(Clone, Debug)]
+enum FormatSource<'a> {
+    Mods(&'a GameMods),
+    Intermode(&'a GameModsIntermode),
+    Legacy(GameModsLegacy),
+}
+
+struct Entry<'a> {
+    intermode: GameModIntermode,
+    gamemod: Option<&'a GameMod>,
+}
+
+impl<'a> GameModsFormatter<'a> {
+    const fn new(source: FormatSource<'a>) -> Self {
+        Self {
+            source,
+            separator: "",
+            prefix: "",
+            settings: false,
+            full_names: false,
+            order: FormatOrder::Native,
+        }
+    }
+
+    pub(crate) const fn mods(mods: &'a GameMods) -> Self {
+        Self::new(FormatSource::Mods(mods))
+    }
+
+    pub(crate) const fn intermode(mods: &'a GameModsIntermode) -> Self {
+        Self::new(FormatSource::Intermode(mods))
+    }
+
+    pub(crate) const fn legacy(mods: GameModsLegacy) -> Self {
+        Self::new(FormatSource::Legacy(mods))
+    }
+
+    /// The string between two mods. Defaults to `""`.
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// The string in front of the first mod e.g. `"+"`. Defaults to `""`.
+    pub const fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+
+        self
+    }
+
+    /// Whether settings that differ from their default value should be shown
+    /// in parentheses after the mod e.g. `DT(1.3x)` or `DA(ar=9.5,od=8)`.
+    /// Defaults to `false`.
+    ///
+    /// Only [`GameMods`] carry settings so this has no effect on other types.
+    pub const fn settings(mut self, settings: bool) -> Self {
+        self.settings = settings;
+
+        self
+    }
+
+    /// Whether mods should be shown by their full name instead of their
+    /// acronym. Defaults to `false`.
+    pub const fn full_names(mut self, full_names: bool) -> Self {
+        self.full_names = full_names;
+
+        self
+    }
+
+    /// The order in which mods are shown. Defaults to [`FormatOrder::Native`].
+    pub const fn order(mut self, order: FormatOrder) -> Self {
+        self.order = order;
+
+        self
+    }
+
+    fn entries(&self) -> Vec<Entry<'a>> {
+        match self.source {
+            FormatSource::Mods(mods) => mods
+                .iter()
+                .map(|gamemod| Entry {
+                    intermode: gamemod.intermode(),
+                    gamemod: Some(gamemod),
+                })
+                .collect(),
+            FormatSource::Intermode(mods) => mods
+                .iter()
+                .map(|intermode| Entry {
+                    intermode,
+                    gamemod: None,
+                })
+                .collect(),
+            FormatSource::Legacy(mods) => mods
+                .iter()
+                .filter_map(|gamemod| GameModIntermode::try_from_bits(gamemod.bits()))
+                .map(|intermode| Entry {
+                    intermode,
+                    gamemod: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn write_label(&self, f: &mut Formatter<'_>, intermode: GameModIntermode) -> FmtResult {
+        if self.full_names {
+            f.write_str(intermode.name())
+        } else {
+            f.write_str(intermode.acronym().as_str())
+        }
+    }
+
+    fn sort(&self, entries: &mut [Entry<'_>]) {
+        match self.order {
+            FormatOrder::Native => {}
+            FormatOrder::Lazer => entries.sort_by_key(|entry| entry.intermode),
+            FormatOrder::Legacy => entries.sort_by(|a, b| {
+                let bits = |entry: &Entry<'_>| entry.intermode.bits().unwrap_or(u32::MAX);
+
+                bits(a).cmp(&bits(b)).then_with(|| {
+                    a.intermode
+                        .acronym()
+                        .as_str()
+                        .cmp(b.intermode.acronym().as_str())
+                })
+            }),
+            FormatOrder::Alphabetical if self.full_names => {
+                entries.sort_by_key(|entry| entry.intermode.name());
+            }
+            FormatOrder::Alphabetical => entries.sort_by(|a, b| {
+                a.intermode
+                    .acronym()
+                    .as_str()
+                    .cmp(b.intermode.acronym().as_str())
+            }),
+        }
+    }
+}
+
+impl Display for GameModsFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut entries = self.entries();
+        self.sort(&mut entries);
+
+        f.write_str(self.prefix)?;
+
+        if entries.is_empty() {
+            return f.write_str(if self.full_names { "No Mod" } else { "NM" });
+        }
+
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+
+            self.write_label(f, entry.intermode)?;
+
+            if let Some(gamemod) = entry.gamemod.filter(|_| self.settings) {
+                write_settings(f, gamemod)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes all non-default settings of the mod in parentheses.
+fn write_settings(f: &mut Formatter<'_>, gamemod: &GameMod) -> FmtResult {
+    let mut normalized = gamemod.clone();
+    normalized.normalize();
+
+    let mut first = true;
+
+    for schema in normalized.settings_schema() {
+        let Some(value) = normalized.setting(schema.name) else {
+            continue;
+        };
+
+        f.write_str(if first { "(" } else { "," })?;
+        first = false;
+
+        match (schema.name, value) {
+            ("speed_change", SettingSimple::Number(value)) => write!(f, "{value}x")?,
+            (name, SettingSimple::Bool(value)) => write!(f, "{}={value}", setting_alias(name))?,
+            (name, SettingSimple::Number(value)) => write!(f, "{}={value}", setting_alias(name))?,
+            (name, SettingSimple::String(value)) => write!(f, "{}={value}", setting_alias(name))?,
+        }
+    }
+
+    if first {
+        Ok(())
+    } else {
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generated_mods::{DifficultyAdjustOsu, DoubleTimeOsu, HiddenOsu, WindUpOsu};
+
+    use super::*;
+
+    fn mods() -> GameMods {
+        let mut mods = GameMods::new();
+        mods.insert(GameMod::HiddenOsu(HiddenOsu::default()));
+        mods.insert(GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.3),
+            adjust_pitch: Some(true),
+        }));
+        mods.insert(GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(9.5),
+            overall_difficulty: Some(8.0),
+            extended_limits: Some(false),
+            ..Default::default()
+        }));
+
+        mods
+    }
+
+    #[test]
+    fn default_matches_display() {
+        let mods = mods();
+        assert_eq!(mods.format().to_string(), mods.to_string());
+
+        let intermode: GameModsIntermode = mods.iter().map(GameMod::intermode).collect();
+        assert_eq!(intermode.format().to_string(), intermode.to_string());
+
+        let legacy = GameModsLegacy::Hidden | GameModsLegacy::Nightcore;
+        assert_eq!(legacy.format().to_string(), legacy.to_string());
+    }
+
+    #[test]
+    fn settings() {
+        let formatted = mods().format().settings(true).separator(" ").to_string();
+
+        assert_eq!(formatted, "DT(1.3x,adjust_pitch=true) HD DA(ar=9.5,od=8)");
+
+        let mut mods = GameMods::new();
+        mods.insert(GameMod::WindUpOsu(WindUpOsu {
+            initial_rate: Some(1.0),
+            final_rate: Some(1.75),
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            mods.format().settings(true).to_string(),
+            "WU(final_rate=1.75)"
+        );
+    }
+
+    #[test]
+    fn names_and_order() {
+        let formatted = mods()
+            .format()
+            .full_names(true)
+            .separator(", ")
+            .order(FormatOrder::Alphabetical)
+            .to_string();
+
+        assert_eq!(formatted, "Difficulty Adjust, Double Time, Hidden");
+
+        let legacy = GameModsLegacy::HardRock | GameModsLegacy::Hidden | GameModsLegacy::Easy;
+        let formatted = legacy
+            .format()
+            .order(FormatOrder::Lazer)
+            .prefix("+")
+            .to_string();
+        assert_eq!(formatted, "+EZHDHR");
+
+        let intermode: GameModsIntermode = mods().iter().map(GameMod::intermode).collect();
+        let formatted = intermode.format().order(FormatOrder::Legacy).to_string();
+        assert_eq!(formatted, "HDDTDA");
+    }
+
+    #[test]
+    fn no_mod() {
+        assert_eq!(GameMods::new().format().prefix("+").to_string(), "+NM");
+        assert_eq!(
+            GameModsLegacy::NoMod.format().full_names(true).to_string(),
+            "No Mod"
+        );
+    }
+}
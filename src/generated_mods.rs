@@ -12,7 +12,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
-use crate::{Acronym, GameModSimple, GameMode, SettingSimple};
+use crate::{
+    Acronym, GameModSimple, GameMode, SettingDefault, SettingKind, SettingRange, SettingSchema,
+    SettingSimple,
+};
 
 mod all_structs {
     /// Larger circles, more forgiving HP drain, less accuracy required, and extra lives!
@@ -1439,6 +1442,10 @@ impl EasyOsu {
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("EZ") }
     }
+    /// The name of [`EasyOsu`]
+    pub const fn name() -> &'static str {
+        "Easy"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`EasyOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1464,12 +1471,85 @@ impl EasyOsu {
     pub const fn bits() -> u32 {
         2
     }
+    /// Whether [`EasyOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`EasyOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`EasyOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`EasyOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`EasyOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`EasyOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "retries",
+            kind: SettingKind::Number,
+            description: "Number of extra lives",
+            default: Some(SettingDefault::Number(2.0)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 10.0,
+                precision: 1.0,
+            }),
+        }]
+    }
+    /// The value of [`EasyOsu::retries`] or its default `2.0` if unset
+    pub const fn effective_retries(&self) -> f64 {
+        match self.retries {
+            Some(value) => value,
+            None => 2.0,
+        }
+    }
+    /// Replace all unset settings of [`EasyOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            retries: Some(self.retries.unwrap_or(2.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "retries" => self.retries.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("retries", None) => self.retries = None,
+            ("retries", Some(SettingSimple::Number(value))) => self.retries = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoFailOsu {
     /// The acronym of [`NoFailOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NF") }
     }
+    /// The name of [`NoFailOsu`]
+    pub const fn name() -> &'static str {
+        "No Fail"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoFailOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1496,12 +1576,58 @@ impl NoFailOsu {
     pub const fn bits() -> u32 {
         1
     }
+    /// Whether [`NoFailOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoFailOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoFailOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoFailOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoFailOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoFailOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NoFailOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HalfTimeOsu {
     /// The acronym of [`HalfTimeOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HT") }
     }
+    /// The name of [`HalfTimeOsu`]
+    pub const fn name() -> &'static str {
+        "Half Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HalfTimeOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1530,12 +1656,105 @@ impl HalfTimeOsu {
     pub const fn bits() -> u32 {
         256
     }
+    /// Whether [`HalfTimeOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HalfTimeOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual decrease to apply",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`HalfTimeOsu::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`HalfTimeOsu::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`HalfTimeOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DaycoreOsu {
     /// The acronym of [`DaycoreOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DC") }
     }
+    /// The name of [`DaycoreOsu`]
+    pub const fn name() -> &'static str {
+        "Daycore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DaycoreOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1558,12 +1777,85 @@ impl DaycoreOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`DaycoreOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DaycoreOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DaycoreOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DaycoreOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DaycoreOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DaycoreOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual decrease to apply",
+            default: Some(SettingDefault::Number(0.75)),
+            range: Some(SettingRange {
+                min: 0.5,
+                max: 0.99,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`DaycoreOsu::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// Replace all unset settings of [`DaycoreOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl HardRockOsu {
     /// The acronym of [`HardRockOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HR") }
     }
+    /// The name of [`HardRockOsu`]
+    pub const fn name() -> &'static str {
+        "Hard Rock"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HardRockOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1589,12 +1881,58 @@ impl HardRockOsu {
     pub const fn bits() -> u32 {
         16
     }
+    /// Whether [`HardRockOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HardRockOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HardRockOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HardRockOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HardRockOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HardRockOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HardRockOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SuddenDeathOsu {
     /// The acronym of [`SuddenDeathOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SD") }
     }
+    /// The name of [`SuddenDeathOsu`]
+    pub const fn name() -> &'static str {
+        "Sudden Death"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SuddenDeathOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1621,12 +1959,103 @@ impl SuddenDeathOsu {
     pub const fn bits() -> u32 {
         32
     }
+    /// Whether [`SuddenDeathOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SuddenDeathOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SuddenDeathOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "fail_on_slider_tail",
+                kind: SettingKind::Bool,
+                description: "",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`SuddenDeathOsu::fail_on_slider_tail`] or its default `false` if unset
+    pub const fn effective_fail_on_slider_tail(&self) -> bool {
+        match self.fail_on_slider_tail {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`SuddenDeathOsu::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`SuddenDeathOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            fail_on_slider_tail: Some(self.fail_on_slider_tail.unwrap_or(false)),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "fail_on_slider_tail" => self.fail_on_slider_tail.map(SettingSimple::Bool),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("fail_on_slider_tail", None) => self.fail_on_slider_tail = None,
+            ("fail_on_slider_tail", Some(SettingSimple::Bool(value))) => {
+                self.fail_on_slider_tail = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl PerfectOsu {
     /// The acronym of [`PerfectOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("PF") }
     }
+    /// The name of [`PerfectOsu`]
+    pub const fn name() -> &'static str {
+        "Perfect"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`PerfectOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1653,12 +2082,81 @@ impl PerfectOsu {
     pub const fn bits() -> u32 {
         16416
     }
+    /// Whether [`PerfectOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`PerfectOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`PerfectOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`PerfectOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`PerfectOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`PerfectOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`PerfectOsu::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`PerfectOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DoubleTimeOsu {
     /// The acronym of [`DoubleTimeOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DT") }
     }
+    /// The name of [`DoubleTimeOsu`]
+    pub const fn name() -> &'static str {
+        "Double Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DoubleTimeOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1687,12 +2185,105 @@ impl DoubleTimeOsu {
     pub const fn bits() -> u32 {
         64
     }
+    /// Whether [`DoubleTimeOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DoubleTimeOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual increase to apply",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 1.01,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DoubleTimeOsu::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`DoubleTimeOsu::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DoubleTimeOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NightcoreOsu {
     /// The acronym of [`NightcoreOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NC") }
     }
+    /// The name of [`NightcoreOsu`]
+    pub const fn name() -> &'static str {
+        "Nightcore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NightcoreOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1721,12 +2312,85 @@ impl NightcoreOsu {
     pub const fn bits() -> u32 {
         576
     }
+    /// Whether [`NightcoreOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NightcoreOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NightcoreOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`NightcoreOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NightcoreOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NightcoreOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual increase to apply",
+            default: Some(SettingDefault::Number(1.5)),
+            range: Some(SettingRange {
+                min: 1.01,
+                max: 2.0,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`NightcoreOsu::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// Replace all unset settings of [`NightcoreOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl HiddenOsu {
     /// The acronym of [`HiddenOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HD") }
     }
+    /// The name of [`HiddenOsu`]
+    pub const fn name() -> &'static str {
+        "Hidden"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HiddenOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1754,12 +2418,85 @@ impl HiddenOsu {
     pub const fn bits() -> u32 {
         8
     }
+    /// Whether [`HiddenOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HiddenOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HiddenOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HiddenOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HiddenOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HiddenOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "only_fade_approach_circles",
+            kind: SettingKind::Bool,
+            description: "The main object body will not fade when enabled.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`HiddenOsu::only_fade_approach_circles`] or its default `false` if unset
+    pub const fn effective_only_fade_approach_circles(&self) -> bool {
+        match self.only_fade_approach_circles {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`HiddenOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            only_fade_approach_circles: Some(self.only_fade_approach_circles.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "only_fade_approach_circles" => {
+                self.only_fade_approach_circles.map(SettingSimple::Bool)
+            }
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("only_fade_approach_circles", None) => self.only_fade_approach_circles = None,
+            ("only_fade_approach_circles", Some(SettingSimple::Bool(value))) => {
+                self.only_fade_approach_circles = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl TraceableOsu {
     /// The acronym of [`TraceableOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("TC") }
     }
+    /// The name of [`TraceableOsu`]
+    pub const fn name() -> &'static str {
+        "Traceable"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TraceableOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1782,12 +2519,58 @@ impl TraceableOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`TraceableOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TraceableOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`TraceableOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`TraceableOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TraceableOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`TraceableOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`TraceableOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl FlashlightOsu {
     /// The acronym of [`FlashlightOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FL") }
     }
+    /// The name of [`FlashlightOsu`]
+    pub const fn name() -> &'static str {
+        "Flashlight"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FlashlightOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1812,12 +2595,131 @@ impl FlashlightOsu {
     pub const fn bits() -> u32 {
         1024
     }
+    /// Whether [`FlashlightOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FlashlightOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FlashlightOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FlashlightOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FlashlightOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FlashlightOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "follow_delay",
+                kind: SettingKind::Number,
+                description: "Milliseconds until the flashlight reaches the cursor",
+                default: Some(SettingDefault::Number(120.0)),
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 5000.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "size_multiplier",
+                kind: SettingKind::Number,
+                description: "Multiplier applied to the default flashlight size.",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 2.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "combo_based_size",
+                kind: SettingKind::Bool,
+                description: "Decrease the flashlight size as combo increases.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`FlashlightOsu::follow_delay`] or its default `120.0` if unset
+    pub const fn effective_follow_delay(&self) -> f64 {
+        match self.follow_delay {
+            Some(value) => value,
+            None => 120.0,
+        }
+    }
+    /// The value of [`FlashlightOsu::size_multiplier`] or its default `1.0` if unset
+    pub const fn effective_size_multiplier(&self) -> f64 {
+        match self.size_multiplier {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`FlashlightOsu::combo_based_size`] or its default `true` if unset
+    pub const fn effective_combo_based_size(&self) -> bool {
+        match self.combo_based_size {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`FlashlightOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            follow_delay: Some(self.follow_delay.unwrap_or(120.0)),
+            size_multiplier: Some(self.size_multiplier.unwrap_or(1.0)),
+            combo_based_size: Some(self.combo_based_size.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "follow_delay" => self.follow_delay.map(SettingSimple::Number),
+            "size_multiplier" => self.size_multiplier.map(SettingSimple::Number),
+            "combo_based_size" => self.combo_based_size.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("follow_delay", None) => self.follow_delay = None,
+            ("follow_delay", Some(SettingSimple::Number(value))) => self.follow_delay = Some(value),
+            ("size_multiplier", None) => self.size_multiplier = None,
+            ("size_multiplier", Some(SettingSimple::Number(value))) => {
+                self.size_multiplier = Some(value)
+            }
+            ("combo_based_size", None) => self.combo_based_size = None,
+            ("combo_based_size", Some(SettingSimple::Bool(value))) => {
+                self.combo_based_size = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl BlindsOsu {
     /// The acronym of [`BlindsOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("BL") }
     }
+    /// The name of [`BlindsOsu`]
+    pub const fn name() -> &'static str {
+        "Blinds"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`BlindsOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("FL")] }.into_iter()
@@ -1830,12 +2732,58 @@ impl BlindsOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`BlindsOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`BlindsOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`BlindsOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`BlindsOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`BlindsOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`BlindsOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`BlindsOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl StrictTrackingOsu {
     /// The acronym of [`StrictTrackingOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("ST") }
     }
+    /// The name of [`StrictTrackingOsu`]
+    pub const fn name() -> &'static str {
+        "Strict Tracking"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`StrictTrackingOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1854,12 +2802,58 @@ impl StrictTrackingOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`StrictTrackingOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`StrictTrackingOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`StrictTrackingOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`StrictTrackingOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`StrictTrackingOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`StrictTrackingOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`StrictTrackingOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AccuracyChallengeOsu {
     /// The acronym of [`AccuracyChallengeOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AC") }
     }
+    /// The name of [`AccuracyChallengeOsu`]
+    pub const fn name() -> &'static str {
+        "Accuracy Challenge"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AccuracyChallengeOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1880,12 +2874,130 @@ impl AccuracyChallengeOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`AccuracyChallengeOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AccuracyChallengeOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "minimum_accuracy",
+                kind: SettingKind::Number,
+                description: "Trigger a failure if your accuracy goes below this value.",
+                default: Some(SettingDefault::Number(0.9)),
+                range: Some(SettingRange {
+                    min: 0.6,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "accuracy_judge_mode",
+                kind: SettingKind::String,
+                description: "The mode of accuracy that will trigger failure.",
+                default: Some(SettingDefault::String("Standard")),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AccuracyChallengeOsu::minimum_accuracy`] or its default `0.9` if unset
+    pub const fn effective_minimum_accuracy(&self) -> f64 {
+        match self.minimum_accuracy {
+            Some(value) => value,
+            None => 0.9,
+        }
+    }
+    /// The value of [`AccuracyChallengeOsu::accuracy_judge_mode`] or its default `"Standard"` if unset
+    pub fn effective_accuracy_judge_mode(&self) -> &str {
+        match self.accuracy_judge_mode {
+            Some(ref value) => value,
+            None => "Standard",
+        }
+    }
+    /// The value of [`AccuracyChallengeOsu::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`AccuracyChallengeOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            minimum_accuracy: Some(self.minimum_accuracy.unwrap_or(0.9)),
+            accuracy_judge_mode: Some(
+                self.accuracy_judge_mode
+                    .unwrap_or_else(|| String::from("Standard")),
+            ),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "minimum_accuracy" => self.minimum_accuracy.map(SettingSimple::Number),
+            "accuracy_judge_mode" => self.accuracy_judge_mode.clone().map(SettingSimple::String),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("minimum_accuracy", None) => self.minimum_accuracy = None,
+            ("minimum_accuracy", Some(SettingSimple::Number(value))) => {
+                self.minimum_accuracy = Some(value)
+            }
+            ("accuracy_judge_mode", None) => self.accuracy_judge_mode = None,
+            ("accuracy_judge_mode", Some(SettingSimple::String(value))) => {
+                self.accuracy_judge_mode = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl TargetPracticeOsu {
     /// The acronym of [`TargetPracticeOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("TP") }
     }
+    /// The name of [`TargetPracticeOsu`]
+    pub const fn name() -> &'static str {
+        "Target Practice"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TargetPracticeOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1916,12 +3028,94 @@ impl TargetPracticeOsu {
     pub const fn bits() -> u32 {
         8388608
     }
+    /// Whether [`TargetPracticeOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TargetPracticeOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`TargetPracticeOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`TargetPracticeOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TargetPracticeOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`TargetPracticeOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "seed",
+                kind: SettingKind::Number,
+                description: "Use a custom seed instead of a random one",
+                default: None,
+                range: None,
+            },
+            SettingSchema {
+                name: "metronome",
+                kind: SettingKind::Bool,
+                description: "Whether a metronome beat should play in the background",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`TargetPracticeOsu::metronome`] or its default `true` if unset
+    pub const fn effective_metronome(&self) -> bool {
+        match self.metronome {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`TargetPracticeOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            seed: self.seed,
+            metronome: Some(self.metronome.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "seed" => self.seed.map(SettingSimple::Number),
+            "metronome" => self.metronome.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("seed", None) => self.seed = None,
+            ("seed", Some(SettingSimple::Number(value))) => self.seed = Some(value),
+            ("metronome", None) => self.metronome = None,
+            ("metronome", Some(SettingSimple::Bool(value))) => self.metronome = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DifficultyAdjustOsu {
     /// The acronym of [`DifficultyAdjustOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DA") }
     }
+    /// The name of [`DifficultyAdjustOsu`]
+    pub const fn name() -> &'static str {
+        "Difficulty Adjust"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DifficultyAdjustOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -1941,12 +3135,149 @@ impl DifficultyAdjustOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`DifficultyAdjustOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DifficultyAdjustOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "circle_size",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set CS.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "approach_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set AR.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "drain_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set HP.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "overall_difficulty",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set OD.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "extended_limits",
+                kind: SettingKind::Bool,
+                description: "Adjust difficulty beyond sane limits.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DifficultyAdjustOsu::extended_limits`] or its default `false` if unset
+    pub const fn effective_extended_limits(&self) -> bool {
+        match self.extended_limits {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DifficultyAdjustOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            circle_size: self.circle_size,
+            approach_rate: self.approach_rate,
+            drain_rate: self.drain_rate,
+            overall_difficulty: self.overall_difficulty,
+            extended_limits: Some(self.extended_limits.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "circle_size" => self.circle_size.map(SettingSimple::Number),
+            "approach_rate" => self.approach_rate.map(SettingSimple::Number),
+            "drain_rate" => self.drain_rate.map(SettingSimple::Number),
+            "overall_difficulty" => self.overall_difficulty.map(SettingSimple::Number),
+            "extended_limits" => self.extended_limits.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("circle_size", None) => self.circle_size = None,
+            ("circle_size", Some(SettingSimple::Number(value))) => self.circle_size = Some(value),
+            ("approach_rate", None) => self.approach_rate = None,
+            ("approach_rate", Some(SettingSimple::Number(value))) => {
+                self.approach_rate = Some(value)
+            }
+            ("drain_rate", None) => self.drain_rate = None,
+            ("drain_rate", Some(SettingSimple::Number(value))) => self.drain_rate = Some(value),
+            ("overall_difficulty", None) => self.overall_difficulty = None,
+            ("overall_difficulty", Some(SettingSimple::Number(value))) => {
+                self.overall_difficulty = Some(value)
+            }
+            ("extended_limits", None) => self.extended_limits = None,
+            ("extended_limits", Some(SettingSimple::Bool(value))) => {
+                self.extended_limits = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl ClassicOsu {
     /// The acronym of [`ClassicOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CL") }
     }
+    /// The name of [`ClassicOsu`]
+    pub const fn name() -> &'static str {
+        "Classic"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ClassicOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("ST")] }.into_iter()
@@ -1959,12 +3290,166 @@ impl ClassicOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ClassicOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ClassicOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ClassicOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ClassicOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ClassicOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ClassicOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "no_slider_head_accuracy",
+                kind: SettingKind::Bool,
+                description: "Scores sliders proportionally to the number of ticks hit.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "classic_note_lock",
+                kind: SettingKind::Bool,
+                description: "Applies note lock to the full hit window.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "always_play_tail_sample",
+                kind: SettingKind::Bool,
+                description:
+                    "Always plays a slider's tail sample regardless of whether it was hit or not.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "fade_hit_circle_early",
+                kind: SettingKind::Bool,
+                description: "Make hit circles fade out into a miss, rather than after it.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "classic_health",
+                kind: SettingKind::Bool,
+                description: "More closely resembles the original HP drain mechanics.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`ClassicOsu::no_slider_head_accuracy`] or its default `true` if unset
+    pub const fn effective_no_slider_head_accuracy(&self) -> bool {
+        match self.no_slider_head_accuracy {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`ClassicOsu::classic_note_lock`] or its default `true` if unset
+    pub const fn effective_classic_note_lock(&self) -> bool {
+        match self.classic_note_lock {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`ClassicOsu::always_play_tail_sample`] or its default `true` if unset
+    pub const fn effective_always_play_tail_sample(&self) -> bool {
+        match self.always_play_tail_sample {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`ClassicOsu::fade_hit_circle_early`] or its default `true` if unset
+    pub const fn effective_fade_hit_circle_early(&self) -> bool {
+        match self.fade_hit_circle_early {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`ClassicOsu::classic_health`] or its default `true` if unset
+    pub const fn effective_classic_health(&self) -> bool {
+        match self.classic_health {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`ClassicOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            no_slider_head_accuracy: Some(self.no_slider_head_accuracy.unwrap_or(true)),
+            classic_note_lock: Some(self.classic_note_lock.unwrap_or(true)),
+            always_play_tail_sample: Some(self.always_play_tail_sample.unwrap_or(true)),
+            fade_hit_circle_early: Some(self.fade_hit_circle_early.unwrap_or(true)),
+            classic_health: Some(self.classic_health.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "no_slider_head_accuracy" => self.no_slider_head_accuracy.map(SettingSimple::Bool),
+            "classic_note_lock" => self.classic_note_lock.map(SettingSimple::Bool),
+            "always_play_tail_sample" => self.always_play_tail_sample.map(SettingSimple::Bool),
+            "fade_hit_circle_early" => self.fade_hit_circle_early.map(SettingSimple::Bool),
+            "classic_health" => self.classic_health.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("no_slider_head_accuracy", None) => self.no_slider_head_accuracy = None,
+            ("no_slider_head_accuracy", Some(SettingSimple::Bool(value))) => {
+                self.no_slider_head_accuracy = Some(value)
+            }
+            ("classic_note_lock", None) => self.classic_note_lock = None,
+            ("classic_note_lock", Some(SettingSimple::Bool(value))) => {
+                self.classic_note_lock = Some(value)
+            }
+            ("always_play_tail_sample", None) => self.always_play_tail_sample = None,
+            ("always_play_tail_sample", Some(SettingSimple::Bool(value))) => {
+                self.always_play_tail_sample = Some(value)
+            }
+            ("fade_hit_circle_early", None) => self.fade_hit_circle_early = None,
+            ("fade_hit_circle_early", Some(SettingSimple::Bool(value))) => {
+                self.fade_hit_circle_early = Some(value)
+            }
+            ("classic_health", None) => self.classic_health = None,
+            ("classic_health", Some(SettingSimple::Bool(value))) => {
+                self.classic_health = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl RandomOsu {
     /// The acronym of [`RandomOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RD") }
     }
+    /// The name of [`RandomOsu`]
+    pub const fn name() -> &'static str {
+        "Random"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RandomOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("TP")] }.into_iter()
@@ -1983,12 +3468,100 @@ impl RandomOsu {
     pub const fn bits() -> u32 {
         2097152
     }
+    /// Whether [`RandomOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RandomOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RandomOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RandomOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RandomOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RandomOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "angle_sharpness",
+                kind: SettingKind::Number,
+                description: "How sharp angles should be",
+                default: Some(SettingDefault::Number(7.0)),
+                range: Some(SettingRange {
+                    min: 1.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "seed",
+                kind: SettingKind::Number,
+                description: "Use a custom seed instead of a random one",
+                default: None,
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`RandomOsu::angle_sharpness`] or its default `7.0` if unset
+    pub const fn effective_angle_sharpness(&self) -> f64 {
+        match self.angle_sharpness {
+            Some(value) => value,
+            None => 7.0,
+        }
+    }
+    /// Replace all unset settings of [`RandomOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            angle_sharpness: Some(self.angle_sharpness.unwrap_or(7.0)),
+            seed: self.seed,
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "angle_sharpness" => self.angle_sharpness.map(SettingSimple::Number),
+            "seed" => self.seed.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("angle_sharpness", None) => self.angle_sharpness = None,
+            ("angle_sharpness", Some(SettingSimple::Number(value))) => {
+                self.angle_sharpness = Some(value)
+            }
+            ("seed", None) => self.seed = None,
+            ("seed", Some(SettingSimple::Number(value))) => self.seed = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl MirrorOsu {
     /// The acronym of [`MirrorOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MR") }
     }
+    /// The name of [`MirrorOsu`]
+    pub const fn name() -> &'static str {
+        "Mirror"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MirrorOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("HR")] }.into_iter()
@@ -2007,12 +3580,84 @@ impl MirrorOsu {
     pub const fn bits() -> u32 {
         1073741824
     }
+    /// Whether [`MirrorOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MirrorOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MirrorOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MirrorOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MirrorOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MirrorOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "reflection",
+            kind: SettingKind::String,
+            description: "",
+            default: Some(SettingDefault::String("Horizontal")),
+            range: None,
+        }]
+    }
+    /// The value of [`MirrorOsu::reflection`] or its default `"Horizontal"` if unset
+    pub fn effective_reflection(&self) -> &str {
+        match self.reflection {
+            Some(ref value) => value,
+            None => "Horizontal",
+        }
+    }
+    /// Replace all unset settings of [`MirrorOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            reflection: Some(
+                self.reflection
+                    .unwrap_or_else(|| String::from("Horizontal")),
+            ),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "reflection" => self.reflection.clone().map(SettingSimple::String),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("reflection", None) => self.reflection = None,
+            ("reflection", Some(SettingSimple::String(value))) => self.reflection = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl AlternateOsu {
     /// The acronym of [`AlternateOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AL") }
     }
+    /// The name of [`AlternateOsu`]
+    pub const fn name() -> &'static str {
+        "Alternate"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AlternateOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2033,12 +3678,58 @@ impl AlternateOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
-}
-impl SingleTapOsu {
+    /// Whether [`AlternateOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AlternateOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AlternateOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`AlternateOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AlternateOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AlternateOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AlternateOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
+}
+impl SingleTapOsu {
     /// The acronym of [`SingleTapOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SG") }
     }
+    /// The name of [`SingleTapOsu`]
+    pub const fn name() -> &'static str {
+        "Single Tap"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SingleTapOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2059,12 +3750,58 @@ impl SingleTapOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`SingleTapOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SingleTapOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SingleTapOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SingleTapOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SingleTapOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SingleTapOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SingleTapOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AutoplayOsu {
     /// The acronym of [`AutoplayOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AT") }
     }
+    /// The name of [`AutoplayOsu`]
+    pub const fn name() -> &'static str {
+        "Autoplay"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AutoplayOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2097,12 +3834,58 @@ impl AutoplayOsu {
     pub const fn bits() -> u32 {
         2048
     }
+    /// Whether [`AutoplayOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`AutoplayOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AutoplayOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AutoplayOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AutoplayOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AutoplayOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AutoplayOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl CinemaOsu {
     /// The acronym of [`CinemaOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CN") }
     }
+    /// The name of [`CinemaOsu`]
+    pub const fn name() -> &'static str {
+        "Cinema"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`CinemaOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2139,12 +3922,58 @@ impl CinemaOsu {
     pub const fn bits() -> u32 {
         4194304
     }
+    /// Whether [`CinemaOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`CinemaOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`CinemaOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`CinemaOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`CinemaOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`CinemaOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`CinemaOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl RelaxOsu {
     /// The acronym of [`RelaxOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RX") }
     }
+    /// The name of [`RelaxOsu`]
+    pub const fn name() -> &'static str {
+        "Relax"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RelaxOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2173,12 +4002,58 @@ impl RelaxOsu {
     pub const fn bits() -> u32 {
         128
     }
+    /// Whether [`RelaxOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RelaxOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RelaxOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RelaxOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RelaxOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RelaxOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`RelaxOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AutopilotOsu {
     /// The acronym of [`AutopilotOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AP") }
     }
+    /// The name of [`AutopilotOsu`]
+    pub const fn name() -> &'static str {
+        "Autopilot"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AutopilotOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2208,12 +4083,58 @@ impl AutopilotOsu {
     pub const fn bits() -> u32 {
         8192
     }
+    /// Whether [`AutopilotOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AutopilotOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AutopilotOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`AutopilotOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AutopilotOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AutopilotOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AutopilotOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SpunOutOsu {
     /// The acronym of [`SpunOutOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SO") }
     }
+    /// The name of [`SpunOutOsu`]
+    pub const fn name() -> &'static str {
+        "Spun Out"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SpunOutOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2240,12 +4161,58 @@ impl SpunOutOsu {
     pub const fn bits() -> u32 {
         4096
     }
+    /// Whether [`SpunOutOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SpunOutOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SpunOutOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SpunOutOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SpunOutOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SpunOutOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SpunOutOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl TransformOsu {
     /// The acronym of [`TransformOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("TR") }
     }
+    /// The name of [`TransformOsu`]
+    pub const fn name() -> &'static str {
+        "Transform"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TransformOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2267,12 +4234,58 @@ impl TransformOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`TransformOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TransformOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`TransformOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`TransformOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TransformOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`TransformOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`TransformOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl WiggleOsu {
     /// The acronym of [`WiggleOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WG") }
     }
+    /// The name of [`WiggleOsu`]
+    pub const fn name() -> &'static str {
+        "Wiggle"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WiggleOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2293,12 +4306,85 @@ impl WiggleOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WiggleOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WiggleOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WiggleOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`WiggleOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WiggleOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WiggleOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "strength",
+            kind: SettingKind::Number,
+            description: "Multiplier applied to the wiggling strength.",
+            default: Some(SettingDefault::Number(1.0)),
+            range: Some(SettingRange {
+                min: 0.1,
+                max: 2.0,
+                precision: 0.1,
+            }),
+        }]
+    }
+    /// The value of [`WiggleOsu::strength`] or its default `1.0` if unset
+    pub const fn effective_strength(&self) -> f64 {
+        match self.strength {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// Replace all unset settings of [`WiggleOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            strength: Some(self.strength.unwrap_or(1.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "strength" => self.strength.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("strength", None) => self.strength = None,
+            ("strength", Some(SettingSimple::Number(value))) => self.strength = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl SpinInOsu {
     /// The acronym of [`SpinInOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SI") }
     }
+    /// The name of [`SpinInOsu`]
+    pub const fn name() -> &'static str {
+        "Spin In"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SpinInOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2321,12 +4407,58 @@ impl SpinInOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`SpinInOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SpinInOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SpinInOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SpinInOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SpinInOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SpinInOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SpinInOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl GrowOsu {
     /// The acronym of [`GrowOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("GR") }
     }
+    /// The name of [`GrowOsu`]
+    pub const fn name() -> &'static str {
+        "Grow"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`GrowOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2348,12 +4480,85 @@ impl GrowOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`GrowOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`GrowOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`GrowOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`GrowOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`GrowOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`GrowOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "start_scale",
+            kind: SettingKind::Number,
+            description: "The initial size multiplier applied to all objects.",
+            default: Some(SettingDefault::Number(0.5)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 0.99,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`GrowOsu::start_scale`] or its default `0.5` if unset
+    pub const fn effective_start_scale(&self) -> f64 {
+        match self.start_scale {
+            Some(value) => value,
+            None => 0.5,
+        }
+    }
+    /// Replace all unset settings of [`GrowOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            start_scale: Some(self.start_scale.unwrap_or(0.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "start_scale" => self.start_scale.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("start_scale", None) => self.start_scale = None,
+            ("start_scale", Some(SettingSimple::Number(value))) => self.start_scale = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DeflateOsu {
     /// The acronym of [`DeflateOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DF") }
     }
+    /// The name of [`DeflateOsu`]
+    pub const fn name() -> &'static str {
+        "Deflate"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DeflateOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2375,12 +4580,85 @@ impl DeflateOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`DeflateOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DeflateOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DeflateOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DeflateOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DeflateOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DeflateOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "start_scale",
+            kind: SettingKind::Number,
+            description: "The initial size multiplier applied to all objects.",
+            default: Some(SettingDefault::Number(2.0)),
+            range: Some(SettingRange {
+                min: 1.0,
+                max: 25.0,
+                precision: 0.1,
+            }),
+        }]
+    }
+    /// The value of [`DeflateOsu::start_scale`] or its default `2.0` if unset
+    pub const fn effective_start_scale(&self) -> f64 {
+        match self.start_scale {
+            Some(value) => value,
+            None => 2.0,
+        }
+    }
+    /// Replace all unset settings of [`DeflateOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            start_scale: Some(self.start_scale.unwrap_or(2.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "start_scale" => self.start_scale.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("start_scale", None) => self.start_scale = None,
+            ("start_scale", Some(SettingSimple::Number(value))) => self.start_scale = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl WindUpOsu {
     /// The acronym of [`WindUpOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WU") }
     }
+    /// The name of [`WindUpOsu`]
+    pub const fn name() -> &'static str {
+        "Wind Up"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindUpOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2403,12 +4681,127 @@ impl WindUpOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindUpOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindUpOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindUpOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindUpOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindUpOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindUpOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindUpOsu::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindUpOsu::final_rate`] or its default `1.5` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`WindUpOsu::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindUpOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl WindDownOsu {
     /// The acronym of [`WindDownOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WD") }
     }
+    /// The name of [`WindDownOsu`]
+    pub const fn name() -> &'static str {
+        "Wind Down"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindDownOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2431,12 +4824,127 @@ impl WindDownOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindDownOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindDownOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindDownOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindDownOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindDownOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindDownOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindDownOsu::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindDownOsu::final_rate`] or its default `0.75` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`WindDownOsu::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindDownOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl BarrelRollOsu {
     /// The acronym of [`BarrelRollOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("BR") }
     }
+    /// The name of [`BarrelRollOsu`]
+    pub const fn name() -> &'static str {
+        "Barrel Roll"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`BarrelRollOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("BU")] }.into_iter()
@@ -2449,12 +4957,105 @@ impl BarrelRollOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`BarrelRollOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`BarrelRollOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`BarrelRollOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`BarrelRollOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`BarrelRollOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`BarrelRollOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "spin_speed",
+                kind: SettingKind::Number,
+                description: "Rotations per minute",
+                default: Some(SettingDefault::Number(0.5)),
+                range: Some(SettingRange {
+                    min: 0.02,
+                    max: 12.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "direction",
+                kind: SettingKind::String,
+                description: "The direction of rotation",
+                default: Some(SettingDefault::String("Clockwise")),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`BarrelRollOsu::spin_speed`] or its default `0.5` if unset
+    pub const fn effective_spin_speed(&self) -> f64 {
+        match self.spin_speed {
+            Some(value) => value,
+            None => 0.5,
+        }
+    }
+    /// The value of [`BarrelRollOsu::direction`] or its default `"Clockwise"` if unset
+    pub fn effective_direction(&self) -> &str {
+        match self.direction {
+            Some(ref value) => value,
+            None => "Clockwise",
+        }
+    }
+    /// Replace all unset settings of [`BarrelRollOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            spin_speed: Some(self.spin_speed.unwrap_or(0.5)),
+            direction: Some(self.direction.unwrap_or_else(|| String::from("Clockwise"))),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "spin_speed" => self.spin_speed.map(SettingSimple::Number),
+            "direction" => self.direction.clone().map(SettingSimple::String),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("spin_speed", None) => self.spin_speed = None,
+            ("spin_speed", Some(SettingSimple::Number(value))) => self.spin_speed = Some(value),
+            ("direction", None) => self.direction = None,
+            ("direction", Some(SettingSimple::String(value))) => self.direction = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl ApproachDifferentOsu {
     /// The acronym of [`ApproachDifferentOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AD") }
     }
+    /// The name of [`ApproachDifferentOsu`]
+    pub const fn name() -> &'static str {
+        "Approach Different"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ApproachDifferentOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2477,12 +5078,106 @@ impl ApproachDifferentOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`ApproachDifferentOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ApproachDifferentOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ApproachDifferentOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ApproachDifferentOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ApproachDifferentOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ApproachDifferentOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "scale",
+                kind: SettingKind::Number,
+                description:
+                    "Change the initial size of the approach circle, relative to hit circles.",
+                default: Some(SettingDefault::Number(4.0)),
+                range: Some(SettingRange {
+                    min: 1.5,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "style",
+                kind: SettingKind::String,
+                description: "Change the animation style of the approach circles.",
+                default: Some(SettingDefault::String("Gravity")),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`ApproachDifferentOsu::scale`] or its default `4.0` if unset
+    pub const fn effective_scale(&self) -> f64 {
+        match self.scale {
+            Some(value) => value,
+            None => 4.0,
+        }
+    }
+    /// The value of [`ApproachDifferentOsu::style`] or its default `"Gravity"` if unset
+    pub fn effective_style(&self) -> &str {
+        match self.style {
+            Some(ref value) => value,
+            None => "Gravity",
+        }
+    }
+    /// Replace all unset settings of [`ApproachDifferentOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            scale: Some(self.scale.unwrap_or(4.0)),
+            style: Some(self.style.unwrap_or_else(|| String::from("Gravity"))),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "scale" => self.scale.map(SettingSimple::Number),
+            "style" => self.style.clone().map(SettingSimple::String),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("scale", None) => self.scale = None,
+            ("scale", Some(SettingSimple::Number(value))) => self.scale = Some(value),
+            ("style", None) => self.style = None,
+            ("style", Some(SettingSimple::String(value))) => self.style = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl MutedOsu {
     /// The acronym of [`MutedOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MU") }
     }
+    /// The name of [`MutedOsu`]
+    pub const fn name() -> &'static str {
+        "Muted"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MutedOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -2495,12 +5190,149 @@ impl MutedOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MutedOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MutedOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MutedOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MutedOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MutedOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MutedOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "inverse_muting",
+                kind: SettingKind::Bool,
+                description: "Increase volume as combo builds.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "enable_metronome",
+                kind: SettingKind::Bool,
+                description: "Add a metronome beat to help you keep track of the rhythm.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "mute_combo_count",
+                kind: SettingKind::Number,
+                description: "The combo count at which point the track reaches its final volume.",
+                default: Some(SettingDefault::Number(100.0)),
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 500.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "affects_hit_sounds",
+                kind: SettingKind::Bool,
+                description: "Hit sounds are also muted alongside the track.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`MutedOsu::inverse_muting`] or its default `false` if unset
+    pub const fn effective_inverse_muting(&self) -> bool {
+        match self.inverse_muting {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`MutedOsu::enable_metronome`] or its default `true` if unset
+    pub const fn effective_enable_metronome(&self) -> bool {
+        match self.enable_metronome {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`MutedOsu::mute_combo_count`] or its default `100.0` if unset
+    pub const fn effective_mute_combo_count(&self) -> f64 {
+        match self.mute_combo_count {
+            Some(value) => value,
+            None => 100.0,
+        }
+    }
+    /// The value of [`MutedOsu::affects_hit_sounds`] or its default `true` if unset
+    pub const fn effective_affects_hit_sounds(&self) -> bool {
+        match self.affects_hit_sounds {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`MutedOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            inverse_muting: Some(self.inverse_muting.unwrap_or(false)),
+            enable_metronome: Some(self.enable_metronome.unwrap_or(true)),
+            mute_combo_count: Some(self.mute_combo_count.unwrap_or(100.0)),
+            affects_hit_sounds: Some(self.affects_hit_sounds.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "inverse_muting" => self.inverse_muting.map(SettingSimple::Bool),
+            "enable_metronome" => self.enable_metronome.map(SettingSimple::Bool),
+            "mute_combo_count" => self.mute_combo_count.map(SettingSimple::Number),
+            "affects_hit_sounds" => self.affects_hit_sounds.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("inverse_muting", None) => self.inverse_muting = None,
+            ("inverse_muting", Some(SettingSimple::Bool(value))) => {
+                self.inverse_muting = Some(value)
+            }
+            ("enable_metronome", None) => self.enable_metronome = None,
+            ("enable_metronome", Some(SettingSimple::Bool(value))) => {
+                self.enable_metronome = Some(value)
+            }
+            ("mute_combo_count", None) => self.mute_combo_count = None,
+            ("mute_combo_count", Some(SettingSimple::Number(value))) => {
+                self.mute_combo_count = Some(value)
+            }
+            ("affects_hit_sounds", None) => self.affects_hit_sounds = None,
+            ("affects_hit_sounds", Some(SettingSimple::Bool(value))) => {
+                self.affects_hit_sounds = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoScopeOsu {
     /// The acronym of [`NoScopeOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NS") }
     }
+    /// The name of [`NoScopeOsu`]
+    pub const fn name() -> &'static str {
+        "No Scope"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoScopeOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("BM")] }.into_iter()
@@ -2513,12 +5345,87 @@ impl NoScopeOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`NoScopeOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoScopeOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoScopeOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoScopeOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoScopeOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoScopeOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "hidden_combo_count",
+            kind: SettingKind::Number,
+            description: "The combo count at which the cursor becomes completely hidden",
+            default: Some(SettingDefault::Number(10.0)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 50.0,
+                precision: 1.0,
+            }),
+        }]
+    }
+    /// The value of [`NoScopeOsu::hidden_combo_count`] or its default `10.0` if unset
+    pub const fn effective_hidden_combo_count(&self) -> f64 {
+        match self.hidden_combo_count {
+            Some(value) => value,
+            None => 10.0,
+        }
+    }
+    /// Replace all unset settings of [`NoScopeOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            hidden_combo_count: Some(self.hidden_combo_count.unwrap_or(10.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "hidden_combo_count" => self.hidden_combo_count.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("hidden_combo_count", None) => self.hidden_combo_count = None,
+            ("hidden_combo_count", Some(SettingSimple::Number(value))) => {
+                self.hidden_combo_count = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl MagnetisedOsu {
     /// The acronym of [`MagnetisedOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MG") }
     }
+    /// The name of [`MagnetisedOsu`]
+    pub const fn name() -> &'static str {
+        "Magnetised"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MagnetisedOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2544,12 +5451,87 @@ impl MagnetisedOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MagnetisedOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MagnetisedOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MagnetisedOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MagnetisedOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MagnetisedOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MagnetisedOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "attraction_strength",
+            kind: SettingKind::Number,
+            description: "How strong the pull is.",
+            default: Some(SettingDefault::Number(0.5)),
+            range: Some(SettingRange {
+                min: 0.05,
+                max: 1.0,
+                precision: 0.05,
+            }),
+        }]
+    }
+    /// The value of [`MagnetisedOsu::attraction_strength`] or its default `0.5` if unset
+    pub const fn effective_attraction_strength(&self) -> f64 {
+        match self.attraction_strength {
+            Some(value) => value,
+            None => 0.5,
+        }
+    }
+    /// Replace all unset settings of [`MagnetisedOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            attraction_strength: Some(self.attraction_strength.unwrap_or(0.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "attraction_strength" => self.attraction_strength.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("attraction_strength", None) => self.attraction_strength = None,
+            ("attraction_strength", Some(SettingSimple::Number(value))) => {
+                self.attraction_strength = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl RepelOsu {
     /// The acronym of [`RepelOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RP") }
     }
+    /// The name of [`RepelOsu`]
+    pub const fn name() -> &'static str {
+        "Repel"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RepelOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2574,12 +5556,87 @@ impl RepelOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`RepelOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RepelOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RepelOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RepelOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RepelOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RepelOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "repulsion_strength",
+            kind: SettingKind::Number,
+            description: "How strong the repulsion is.",
+            default: Some(SettingDefault::Number(0.5)),
+            range: Some(SettingRange {
+                min: 0.05,
+                max: 1.0,
+                precision: 0.05,
+            }),
+        }]
+    }
+    /// The value of [`RepelOsu::repulsion_strength`] or its default `0.5` if unset
+    pub const fn effective_repulsion_strength(&self) -> f64 {
+        match self.repulsion_strength {
+            Some(value) => value,
+            None => 0.5,
+        }
+    }
+    /// Replace all unset settings of [`RepelOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            repulsion_strength: Some(self.repulsion_strength.unwrap_or(0.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "repulsion_strength" => self.repulsion_strength.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("repulsion_strength", None) => self.repulsion_strength = None,
+            ("repulsion_strength", Some(SettingSimple::Number(value))) => {
+                self.repulsion_strength = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AdaptiveSpeedOsu {
     /// The acronym of [`AdaptiveSpeedOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AS") }
     }
+    /// The name of [`AdaptiveSpeedOsu`]
+    pub const fn name() -> &'static str {
+        "Adaptive Speed"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AdaptiveSpeedOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2604,12 +5661,105 @@ impl AdaptiveSpeedOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`AdaptiveSpeedOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AdaptiveSpeedOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AdaptiveSpeedOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AdaptiveSpeedOsu::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`AdaptiveSpeedOsu::adjust_pitch`] or its default `true` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`AdaptiveSpeedOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl FreezeFrameOsu {
     /// The acronym of [`FreezeFrameOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FR") }
     }
+    /// The name of [`FreezeFrameOsu`]
+    pub const fn name() -> &'static str {
+        "Freeze Frame"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FreezeFrameOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2630,12 +5780,58 @@ impl FreezeFrameOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`FreezeFrameOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FreezeFrameOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FreezeFrameOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FreezeFrameOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FreezeFrameOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FreezeFrameOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`FreezeFrameOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl BubblesOsu {
     /// The acronym of [`BubblesOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("BU") }
     }
+    /// The name of [`BubblesOsu`]
+    pub const fn name() -> &'static str {
+        "Bubbles"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`BubblesOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2655,12 +5851,58 @@ impl BubblesOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
-}
-impl SynesthesiaOsu {
+    /// Whether [`BubblesOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`BubblesOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`BubblesOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`BubblesOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`BubblesOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`BubblesOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`BubblesOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
+}
+impl SynesthesiaOsu {
     /// The acronym of [`SynesthesiaOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SY") }
     }
+    /// The name of [`SynesthesiaOsu`]
+    pub const fn name() -> &'static str {
+        "Synesthesia"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SynesthesiaOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -2673,12 +5915,58 @@ impl SynesthesiaOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`SynesthesiaOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SynesthesiaOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SynesthesiaOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SynesthesiaOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SynesthesiaOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SynesthesiaOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SynesthesiaOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl DepthOsu {
     /// The acronym of [`DepthOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DP") }
     }
+    /// The name of [`DepthOsu`]
+    pub const fn name() -> &'static str {
+        "Depth"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DepthOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2706,12 +5994,107 @@ impl DepthOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`DepthOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DepthOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DepthOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DepthOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DepthOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DepthOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "max_depth",
+                kind: SettingKind::Number,
+                description: "How far away objects appear.",
+                default: Some(SettingDefault::Number(100.0)),
+                range: Some(SettingRange {
+                    min: 50.0,
+                    max: 200.0,
+                    precision: 10.0,
+                }),
+            },
+            SettingSchema {
+                name: "show_approach_circles",
+                kind: SettingKind::Bool,
+                description: "Whether approach circles should be visible.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DepthOsu::max_depth`] or its default `100.0` if unset
+    pub const fn effective_max_depth(&self) -> f64 {
+        match self.max_depth {
+            Some(value) => value,
+            None => 100.0,
+        }
+    }
+    /// The value of [`DepthOsu::show_approach_circles`] or its default `true` if unset
+    pub const fn effective_show_approach_circles(&self) -> bool {
+        match self.show_approach_circles {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`DepthOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            max_depth: Some(self.max_depth.unwrap_or(100.0)),
+            show_approach_circles: Some(self.show_approach_circles.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "max_depth" => self.max_depth.map(SettingSimple::Number),
+            "show_approach_circles" => self.show_approach_circles.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("max_depth", None) => self.max_depth = None,
+            ("max_depth", Some(SettingSimple::Number(value))) => self.max_depth = Some(value),
+            ("show_approach_circles", None) => self.show_approach_circles = None,
+            ("show_approach_circles", Some(SettingSimple::Bool(value))) => {
+                self.show_approach_circles = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl BloomOsu {
     /// The acronym of [`BloomOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("BM") }
     }
+    /// The name of [`BloomOsu`]
+    pub const fn name() -> &'static str {
+        "Bloom"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`BloomOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2731,12 +6114,113 @@ impl BloomOsu {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`BloomOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`BloomOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`BloomOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`BloomOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`BloomOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`BloomOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "max_size_combo_count",
+                kind: SettingKind::Number,
+                description: "The combo count at which the cursor reaches its maximum size",
+                default: Some(SettingDefault::Number(50.0)),
+                range: Some(SettingRange {
+                    min: 5.0,
+                    max: 100.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "max_cursor_size",
+                kind: SettingKind::Number,
+                description: "The multiplier applied to cursor size when combo reaches maximum",
+                default: Some(SettingDefault::Number(10.0)),
+                range: Some(SettingRange {
+                    min: 5.0,
+                    max: 15.0,
+                    precision: 0.5,
+                }),
+            },
+        ]
+    }
+    /// The value of [`BloomOsu::max_size_combo_count`] or its default `50.0` if unset
+    pub const fn effective_max_size_combo_count(&self) -> f64 {
+        match self.max_size_combo_count {
+            Some(value) => value,
+            None => 50.0,
+        }
+    }
+    /// The value of [`BloomOsu::max_cursor_size`] or its default `10.0` if unset
+    pub const fn effective_max_cursor_size(&self) -> f64 {
+        match self.max_cursor_size {
+            Some(value) => value,
+            None => 10.0,
+        }
+    }
+    /// Replace all unset settings of [`BloomOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            max_size_combo_count: Some(self.max_size_combo_count.unwrap_or(50.0)),
+            max_cursor_size: Some(self.max_cursor_size.unwrap_or(10.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "max_size_combo_count" => self.max_size_combo_count.map(SettingSimple::Number),
+            "max_cursor_size" => self.max_cursor_size.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("max_size_combo_count", None) => self.max_size_combo_count = None,
+            ("max_size_combo_count", Some(SettingSimple::Number(value))) => {
+                self.max_size_combo_count = Some(value)
+            }
+            ("max_cursor_size", None) => self.max_cursor_size = None,
+            ("max_cursor_size", Some(SettingSimple::Number(value))) => {
+                self.max_cursor_size = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl TouchDeviceOsu {
     /// The acronym of [`TouchDeviceOsu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("TD") }
     }
+    /// The name of [`TouchDeviceOsu`]
+    pub const fn name() -> &'static str {
+        "Touch Device"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TouchDeviceOsu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2763,12 +6247,58 @@ impl TouchDeviceOsu {
     pub const fn bits() -> u32 {
         4
     }
+    /// Whether [`TouchDeviceOsu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TouchDeviceOsu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`TouchDeviceOsu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`TouchDeviceOsu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TouchDeviceOsu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        true
+    }
+    /// The [`SettingSchema`] for each setting of [`TouchDeviceOsu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`TouchDeviceOsu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl ScoreV2Osu {
     /// The acronym of [`ScoreV2Osu`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SV2") }
     }
+    /// The name of [`ScoreV2Osu`]
+    pub const fn name() -> &'static str {
+        "Score V2"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ScoreV2Osu`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -2787,12 +6317,58 @@ impl ScoreV2Osu {
     pub const fn bits() -> u32 {
         536870912
     }
+    /// Whether [`ScoreV2Osu`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Osu`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ScoreV2Osu`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Osu`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Osu`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ScoreV2Osu`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ScoreV2Osu`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl EasyTaiko {
     /// The acronym of [`EasyTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("EZ") }
     }
+    /// The name of [`EasyTaiko`]
+    pub const fn name() -> &'static str {
+        "Easy"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`EasyTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2817,12 +6393,58 @@ impl EasyTaiko {
     pub const fn bits() -> u32 {
         2
     }
+    /// Whether [`EasyTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`EasyTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`EasyTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`EasyTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`EasyTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`EasyTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`EasyTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl NoFailTaiko {
     /// The acronym of [`NoFailTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NF") }
     }
+    /// The name of [`NoFailTaiko`]
+    pub const fn name() -> &'static str {
+        "No Fail"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoFailTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2849,12 +6471,58 @@ impl NoFailTaiko {
     pub const fn bits() -> u32 {
         1
     }
+    /// Whether [`NoFailTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoFailTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoFailTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoFailTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoFailTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoFailTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NoFailTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HalfTimeTaiko {
     /// The acronym of [`HalfTimeTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HT") }
     }
+    /// The name of [`HalfTimeTaiko`]
+    pub const fn name() -> &'static str {
+        "Half Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HalfTimeTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2883,12 +6551,105 @@ impl HalfTimeTaiko {
     pub const fn bits() -> u32 {
         256
     }
+    /// Whether [`HalfTimeTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HalfTimeTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual decrease to apply",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`HalfTimeTaiko::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`HalfTimeTaiko::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`HalfTimeTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DaycoreTaiko {
     /// The acronym of [`DaycoreTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DC") }
     }
+    /// The name of [`DaycoreTaiko`]
+    pub const fn name() -> &'static str {
+        "Daycore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DaycoreTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2911,12 +6672,85 @@ impl DaycoreTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`DaycoreTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DaycoreTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DaycoreTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DaycoreTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DaycoreTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DaycoreTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual decrease to apply",
+            default: Some(SettingDefault::Number(0.75)),
+            range: Some(SettingRange {
+                min: 0.5,
+                max: 0.99,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`DaycoreTaiko::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// Replace all unset settings of [`DaycoreTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl SimplifiedRhythmTaiko {
     /// The acronym of [`SimplifiedRhythmTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SR") }
     }
+    /// The name of [`SimplifiedRhythmTaiko`]
+    pub const fn name() -> &'static str {
+        "Simplified Rhythm"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SimplifiedRhythmTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -2929,12 +6763,125 @@ impl SimplifiedRhythmTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`SimplifiedRhythmTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SimplifiedRhythmTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SimplifiedRhythmTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SimplifiedRhythmTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SimplifiedRhythmTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SimplifiedRhythmTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "one_third_conversion",
+                kind: SettingKind::Bool,
+                description: "Converts 1/3 patterns to 1/2 rhythm.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "one_sixth_conversion",
+                kind: SettingKind::Bool,
+                description: "Converts 1/6 patterns to 1/4 rhythm.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "one_eighth_conversion",
+                kind: SettingKind::Bool,
+                description: "Converts 1/8 patterns to 1/4 rhythm.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`SimplifiedRhythmTaiko::one_third_conversion`] or its default `false` if unset
+    pub const fn effective_one_third_conversion(&self) -> bool {
+        match self.one_third_conversion {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`SimplifiedRhythmTaiko::one_sixth_conversion`] or its default `true` if unset
+    pub const fn effective_one_sixth_conversion(&self) -> bool {
+        match self.one_sixth_conversion {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`SimplifiedRhythmTaiko::one_eighth_conversion`] or its default `false` if unset
+    pub const fn effective_one_eighth_conversion(&self) -> bool {
+        match self.one_eighth_conversion {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`SimplifiedRhythmTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            one_third_conversion: Some(self.one_third_conversion.unwrap_or(false)),
+            one_sixth_conversion: Some(self.one_sixth_conversion.unwrap_or(true)),
+            one_eighth_conversion: Some(self.one_eighth_conversion.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "one_third_conversion" => self.one_third_conversion.map(SettingSimple::Bool),
+            "one_sixth_conversion" => self.one_sixth_conversion.map(SettingSimple::Bool),
+            "one_eighth_conversion" => self.one_eighth_conversion.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("one_third_conversion", None) => self.one_third_conversion = None,
+            ("one_third_conversion", Some(SettingSimple::Bool(value))) => {
+                self.one_third_conversion = Some(value)
+            }
+            ("one_sixth_conversion", None) => self.one_sixth_conversion = None,
+            ("one_sixth_conversion", Some(SettingSimple::Bool(value))) => {
+                self.one_sixth_conversion = Some(value)
+            }
+            ("one_eighth_conversion", None) => self.one_eighth_conversion = None,
+            ("one_eighth_conversion", Some(SettingSimple::Bool(value))) => {
+                self.one_eighth_conversion = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl HardRockTaiko {
     /// The acronym of [`HardRockTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HR") }
     }
+    /// The name of [`HardRockTaiko`]
+    pub const fn name() -> &'static str {
+        "Hard Rock"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HardRockTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2959,12 +6906,58 @@ impl HardRockTaiko {
     pub const fn bits() -> u32 {
         16
     }
+    /// Whether [`HardRockTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HardRockTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HardRockTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HardRockTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HardRockTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HardRockTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HardRockTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SuddenDeathTaiko {
     /// The acronym of [`SuddenDeathTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SD") }
     }
+    /// The name of [`SuddenDeathTaiko`]
+    pub const fn name() -> &'static str {
+        "Sudden Death"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SuddenDeathTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -2990,12 +6983,81 @@ impl SuddenDeathTaiko {
     pub const fn bits() -> u32 {
         32
     }
+    /// Whether [`SuddenDeathTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SuddenDeathTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SuddenDeathTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`SuddenDeathTaiko::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`SuddenDeathTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl PerfectTaiko {
     /// The acronym of [`PerfectTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("PF") }
     }
+    /// The name of [`PerfectTaiko`]
+    pub const fn name() -> &'static str {
+        "Perfect"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`PerfectTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3022,12 +7084,81 @@ impl PerfectTaiko {
     pub const fn bits() -> u32 {
         16416
     }
+    /// Whether [`PerfectTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`PerfectTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`PerfectTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`PerfectTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`PerfectTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`PerfectTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`PerfectTaiko::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`PerfectTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DoubleTimeTaiko {
     /// The acronym of [`DoubleTimeTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DT") }
     }
+    /// The name of [`DoubleTimeTaiko`]
+    pub const fn name() -> &'static str {
+        "Double Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DoubleTimeTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3056,12 +7187,105 @@ impl DoubleTimeTaiko {
     pub const fn bits() -> u32 {
         64
     }
+    /// Whether [`DoubleTimeTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DoubleTimeTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual increase to apply",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 1.01,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DoubleTimeTaiko::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`DoubleTimeTaiko::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DoubleTimeTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NightcoreTaiko {
     /// The acronym of [`NightcoreTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NC") }
     }
+    /// The name of [`NightcoreTaiko`]
+    pub const fn name() -> &'static str {
+        "Nightcore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NightcoreTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3090,12 +7314,85 @@ impl NightcoreTaiko {
     pub const fn bits() -> u32 {
         576
     }
+    /// Whether [`NightcoreTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NightcoreTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NightcoreTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`NightcoreTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NightcoreTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NightcoreTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual increase to apply",
+            default: Some(SettingDefault::Number(1.5)),
+            range: Some(SettingRange {
+                min: 1.01,
+                max: 2.0,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`NightcoreTaiko::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// Replace all unset settings of [`NightcoreTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl HiddenTaiko {
     /// The acronym of [`HiddenTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HD") }
     }
+    /// The name of [`HiddenTaiko`]
+    pub const fn name() -> &'static str {
+        "Hidden"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HiddenTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3114,12 +7411,58 @@ impl HiddenTaiko {
     pub const fn bits() -> u32 {
         8
     }
+    /// Whether [`HiddenTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HiddenTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HiddenTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HiddenTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HiddenTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HiddenTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HiddenTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl FlashlightTaiko {
     /// The acronym of [`FlashlightTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FL") }
     }
+    /// The name of [`FlashlightTaiko`]
+    pub const fn name() -> &'static str {
+        "Flashlight"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FlashlightTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3138,12 +7481,109 @@ impl FlashlightTaiko {
     pub const fn bits() -> u32 {
         1024
     }
+    /// Whether [`FlashlightTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FlashlightTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FlashlightTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FlashlightTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FlashlightTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FlashlightTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "size_multiplier",
+                kind: SettingKind::Number,
+                description: "Multiplier applied to the default flashlight size.",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.5,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "combo_based_size",
+                kind: SettingKind::Bool,
+                description: "Decrease the flashlight size as combo increases.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`FlashlightTaiko::size_multiplier`] or its default `1.0` if unset
+    pub const fn effective_size_multiplier(&self) -> f64 {
+        match self.size_multiplier {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`FlashlightTaiko::combo_based_size`] or its default `true` if unset
+    pub const fn effective_combo_based_size(&self) -> bool {
+        match self.combo_based_size {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`FlashlightTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            size_multiplier: Some(self.size_multiplier.unwrap_or(1.0)),
+            combo_based_size: Some(self.combo_based_size.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "size_multiplier" => self.size_multiplier.map(SettingSimple::Number),
+            "combo_based_size" => self.combo_based_size.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("size_multiplier", None) => self.size_multiplier = None,
+            ("size_multiplier", Some(SettingSimple::Number(value))) => {
+                self.size_multiplier = Some(value)
+            }
+            ("combo_based_size", None) => self.combo_based_size = None,
+            ("combo_based_size", Some(SettingSimple::Bool(value))) => {
+                self.combo_based_size = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AccuracyChallengeTaiko {
     /// The acronym of [`AccuracyChallengeTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AC") }
     }
+    /// The name of [`AccuracyChallengeTaiko`]
+    pub const fn name() -> &'static str {
+        "Accuracy Challenge"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AccuracyChallengeTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3163,12 +7603,130 @@ impl AccuracyChallengeTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`AccuracyChallengeTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AccuracyChallengeTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "minimum_accuracy",
+                kind: SettingKind::Number,
+                description: "Trigger a failure if your accuracy goes below this value.",
+                default: Some(SettingDefault::Number(0.9)),
+                range: Some(SettingRange {
+                    min: 0.6,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "accuracy_judge_mode",
+                kind: SettingKind::String,
+                description: "The mode of accuracy that will trigger failure.",
+                default: Some(SettingDefault::String("Standard")),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AccuracyChallengeTaiko::minimum_accuracy`] or its default `0.9` if unset
+    pub const fn effective_minimum_accuracy(&self) -> f64 {
+        match self.minimum_accuracy {
+            Some(value) => value,
+            None => 0.9,
+        }
+    }
+    /// The value of [`AccuracyChallengeTaiko::accuracy_judge_mode`] or its default `"Standard"` if unset
+    pub fn effective_accuracy_judge_mode(&self) -> &str {
+        match self.accuracy_judge_mode {
+            Some(ref value) => value,
+            None => "Standard",
+        }
+    }
+    /// The value of [`AccuracyChallengeTaiko::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`AccuracyChallengeTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            minimum_accuracy: Some(self.minimum_accuracy.unwrap_or(0.9)),
+            accuracy_judge_mode: Some(
+                self.accuracy_judge_mode
+                    .unwrap_or_else(|| String::from("Standard")),
+            ),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "minimum_accuracy" => self.minimum_accuracy.map(SettingSimple::Number),
+            "accuracy_judge_mode" => self.accuracy_judge_mode.clone().map(SettingSimple::String),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("minimum_accuracy", None) => self.minimum_accuracy = None,
+            ("minimum_accuracy", Some(SettingSimple::Number(value))) => {
+                self.minimum_accuracy = Some(value)
+            }
+            ("accuracy_judge_mode", None) => self.accuracy_judge_mode = None,
+            ("accuracy_judge_mode", Some(SettingSimple::String(value))) => {
+                self.accuracy_judge_mode = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl RandomTaiko {
     /// The acronym of [`RandomTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RD") }
     }
+    /// The name of [`RandomTaiko`]
+    pub const fn name() -> &'static str {
+        "Random"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RandomTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("SW")] }.into_iter()
@@ -3187,12 +7745,72 @@ impl RandomTaiko {
     pub const fn bits() -> u32 {
         2097152
     }
+    /// Whether [`RandomTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RandomTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RandomTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RandomTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RandomTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RandomTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "seed",
+            kind: SettingKind::Number,
+            description: "Use a custom seed instead of a random one",
+            default: None,
+            range: None,
+        }]
+    }
+    /// Replace all unset settings of [`RandomTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "seed" => self.seed.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("seed", None) => self.seed = None,
+            ("seed", Some(SettingSimple::Number(value))) => self.seed = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DifficultyAdjustTaiko {
     /// The acronym of [`DifficultyAdjustTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DA") }
     }
+    /// The name of [`DifficultyAdjustTaiko`]
+    pub const fn name() -> &'static str {
+        "Difficulty Adjust"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DifficultyAdjustTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3211,12 +7829,139 @@ impl DifficultyAdjustTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`DifficultyAdjustTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DifficultyAdjustTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "scroll_speed",
+                kind: SettingKind::Number,
+                description: "Adjust a beatmap's set scroll speed",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.25,
+                    max: 4.0,
+                    precision: 0.05,
+                }),
+            },
+            SettingSchema {
+                name: "drain_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set HP.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "overall_difficulty",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set OD.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "extended_limits",
+                kind: SettingKind::Bool,
+                description: "Adjust difficulty beyond sane limits.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DifficultyAdjustTaiko::scroll_speed`] or its default `1.0` if unset
+    pub const fn effective_scroll_speed(&self) -> f64 {
+        match self.scroll_speed {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`DifficultyAdjustTaiko::extended_limits`] or its default `false` if unset
+    pub const fn effective_extended_limits(&self) -> bool {
+        match self.extended_limits {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DifficultyAdjustTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            scroll_speed: Some(self.scroll_speed.unwrap_or(1.0)),
+            drain_rate: self.drain_rate,
+            overall_difficulty: self.overall_difficulty,
+            extended_limits: Some(self.extended_limits.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "scroll_speed" => self.scroll_speed.map(SettingSimple::Number),
+            "drain_rate" => self.drain_rate.map(SettingSimple::Number),
+            "overall_difficulty" => self.overall_difficulty.map(SettingSimple::Number),
+            "extended_limits" => self.extended_limits.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("scroll_speed", None) => self.scroll_speed = None,
+            ("scroll_speed", Some(SettingSimple::Number(value))) => self.scroll_speed = Some(value),
+            ("drain_rate", None) => self.drain_rate = None,
+            ("drain_rate", Some(SettingSimple::Number(value))) => self.drain_rate = Some(value),
+            ("overall_difficulty", None) => self.overall_difficulty = None,
+            ("overall_difficulty", Some(SettingSimple::Number(value))) => {
+                self.overall_difficulty = Some(value)
+            }
+            ("extended_limits", None) => self.extended_limits = None,
+            ("extended_limits", Some(SettingSimple::Bool(value))) => {
+                self.extended_limits = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl ClassicTaiko {
     /// The acronym of [`ClassicTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CL") }
     }
+    /// The name of [`ClassicTaiko`]
+    pub const fn name() -> &'static str {
+        "Classic"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ClassicTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3229,12 +7974,58 @@ impl ClassicTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ClassicTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ClassicTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ClassicTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ClassicTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ClassicTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ClassicTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ClassicTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SwapTaiko {
     /// The acronym of [`SwapTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SW") }
     }
+    /// The name of [`SwapTaiko`]
+    pub const fn name() -> &'static str {
+        "Swap"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SwapTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("RD")] }.into_iter()
@@ -3247,12 +8038,58 @@ impl SwapTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`SwapTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SwapTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SwapTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SwapTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SwapTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SwapTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SwapTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SingleTapTaiko {
     /// The acronym of [`SingleTapTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SG") }
     }
+    /// The name of [`SingleTapTaiko`]
+    pub const fn name() -> &'static str {
+        "Single Tap"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SingleTapTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3272,12 +8109,58 @@ impl SingleTapTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`SingleTapTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SingleTapTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SingleTapTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SingleTapTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SingleTapTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SingleTapTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SingleTapTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl ConstantSpeedTaiko {
     /// The acronym of [`ConstantSpeedTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CS") }
     }
+    /// The name of [`ConstantSpeedTaiko`]
+    pub const fn name() -> &'static str {
+        "Constant Speed"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ConstantSpeedTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3290,12 +8173,58 @@ impl ConstantSpeedTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ConstantSpeedTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ConstantSpeedTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ConstantSpeedTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ConstantSpeedTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AutoplayTaiko {
     /// The acronym of [`AutoplayTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AT") }
     }
+    /// The name of [`AutoplayTaiko`]
+    pub const fn name() -> &'static str {
+        "Autoplay"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AutoplayTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3322,12 +8251,58 @@ impl AutoplayTaiko {
     pub const fn bits() -> u32 {
         2048
     }
+    /// Whether [`AutoplayTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`AutoplayTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AutoplayTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AutoplayTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AutoplayTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AutoplayTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AutoplayTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl CinemaTaiko {
     /// The acronym of [`CinemaTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CN") }
     }
+    /// The name of [`CinemaTaiko`]
+    pub const fn name() -> &'static str {
+        "Cinema"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`CinemaTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3358,12 +8333,58 @@ impl CinemaTaiko {
     pub const fn bits() -> u32 {
         4194304
     }
+    /// Whether [`CinemaTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`CinemaTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`CinemaTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`CinemaTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`CinemaTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`CinemaTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`CinemaTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl RelaxTaiko {
     /// The acronym of [`RelaxTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RX") }
     }
+    /// The name of [`RelaxTaiko`]
+    pub const fn name() -> &'static str {
+        "Relax"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RelaxTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3389,12 +8410,58 @@ impl RelaxTaiko {
     pub const fn bits() -> u32 {
         128
     }
+    /// Whether [`RelaxTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RelaxTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RelaxTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RelaxTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RelaxTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RelaxTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`RelaxTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl WindUpTaiko {
     /// The acronym of [`WindUpTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WU") }
     }
+    /// The name of [`WindUpTaiko`]
+    pub const fn name() -> &'static str {
+        "Wind Up"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindUpTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3417,12 +8484,127 @@ impl WindUpTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindUpTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindUpTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindUpTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindUpTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindUpTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindUpTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindUpTaiko::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindUpTaiko::final_rate`] or its default `1.5` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`WindUpTaiko::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindUpTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl WindDownTaiko {
     /// The acronym of [`WindDownTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WD") }
     }
+    /// The name of [`WindDownTaiko`]
+    pub const fn name() -> &'static str {
+        "Wind Down"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindDownTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3445,12 +8627,127 @@ impl WindDownTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindDownTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindDownTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindDownTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindDownTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindDownTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindDownTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindDownTaiko::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindDownTaiko::final_rate`] or its default `0.75` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`WindDownTaiko::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindDownTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl MutedTaiko {
     /// The acronym of [`MutedTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MU") }
     }
+    /// The name of [`MutedTaiko`]
+    pub const fn name() -> &'static str {
+        "Muted"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MutedTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3463,12 +8760,149 @@ impl MutedTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MutedTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MutedTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MutedTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MutedTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MutedTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MutedTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "inverse_muting",
+                kind: SettingKind::Bool,
+                description: "Increase volume as combo builds.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "enable_metronome",
+                kind: SettingKind::Bool,
+                description: "Add a metronome beat to help you keep track of the rhythm.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "mute_combo_count",
+                kind: SettingKind::Number,
+                description: "The combo count at which point the track reaches its final volume.",
+                default: Some(SettingDefault::Number(100.0)),
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 500.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "affects_hit_sounds",
+                kind: SettingKind::Bool,
+                description: "Hit sounds are also muted alongside the track.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`MutedTaiko::inverse_muting`] or its default `false` if unset
+    pub const fn effective_inverse_muting(&self) -> bool {
+        match self.inverse_muting {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`MutedTaiko::enable_metronome`] or its default `true` if unset
+    pub const fn effective_enable_metronome(&self) -> bool {
+        match self.enable_metronome {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`MutedTaiko::mute_combo_count`] or its default `100.0` if unset
+    pub const fn effective_mute_combo_count(&self) -> f64 {
+        match self.mute_combo_count {
+            Some(value) => value,
+            None => 100.0,
+        }
+    }
+    /// The value of [`MutedTaiko::affects_hit_sounds`] or its default `true` if unset
+    pub const fn effective_affects_hit_sounds(&self) -> bool {
+        match self.affects_hit_sounds {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`MutedTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            inverse_muting: Some(self.inverse_muting.unwrap_or(false)),
+            enable_metronome: Some(self.enable_metronome.unwrap_or(true)),
+            mute_combo_count: Some(self.mute_combo_count.unwrap_or(100.0)),
+            affects_hit_sounds: Some(self.affects_hit_sounds.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "inverse_muting" => self.inverse_muting.map(SettingSimple::Bool),
+            "enable_metronome" => self.enable_metronome.map(SettingSimple::Bool),
+            "mute_combo_count" => self.mute_combo_count.map(SettingSimple::Number),
+            "affects_hit_sounds" => self.affects_hit_sounds.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("inverse_muting", None) => self.inverse_muting = None,
+            ("inverse_muting", Some(SettingSimple::Bool(value))) => {
+                self.inverse_muting = Some(value)
+            }
+            ("enable_metronome", None) => self.enable_metronome = None,
+            ("enable_metronome", Some(SettingSimple::Bool(value))) => {
+                self.enable_metronome = Some(value)
+            }
+            ("mute_combo_count", None) => self.mute_combo_count = None,
+            ("mute_combo_count", Some(SettingSimple::Number(value))) => {
+                self.mute_combo_count = Some(value)
+            }
+            ("affects_hit_sounds", None) => self.affects_hit_sounds = None,
+            ("affects_hit_sounds", Some(SettingSimple::Bool(value))) => {
+                self.affects_hit_sounds = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AdaptiveSpeedTaiko {
     /// The acronym of [`AdaptiveSpeedTaiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AS") }
     }
+    /// The name of [`AdaptiveSpeedTaiko`]
+    pub const fn name() -> &'static str {
+        "Adaptive Speed"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AdaptiveSpeedTaiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3493,12 +8927,105 @@ impl AdaptiveSpeedTaiko {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`AdaptiveSpeedTaiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AdaptiveSpeedTaiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedTaiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedTaiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedTaiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AdaptiveSpeedTaiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AdaptiveSpeedTaiko::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`AdaptiveSpeedTaiko::adjust_pitch`] or its default `true` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`AdaptiveSpeedTaiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl ScoreV2Taiko {
     /// The acronym of [`ScoreV2Taiko`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SV2") }
     }
+    /// The name of [`ScoreV2Taiko`]
+    pub const fn name() -> &'static str {
+        "Score V2"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ScoreV2Taiko`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3517,12 +9044,58 @@ impl ScoreV2Taiko {
     pub const fn bits() -> u32 {
         536870912
     }
+    /// Whether [`ScoreV2Taiko`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Taiko`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ScoreV2Taiko`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Taiko`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Taiko`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ScoreV2Taiko`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ScoreV2Taiko`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl EasyCatch {
     /// The acronym of [`EasyCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("EZ") }
     }
+    /// The name of [`EasyCatch`]
+    pub const fn name() -> &'static str {
+        "Easy"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`EasyCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3548,12 +9121,85 @@ impl EasyCatch {
     pub const fn bits() -> u32 {
         2
     }
+    /// Whether [`EasyCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`EasyCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`EasyCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`EasyCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`EasyCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`EasyCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "retries",
+            kind: SettingKind::Number,
+            description: "Number of extra lives",
+            default: Some(SettingDefault::Number(2.0)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 10.0,
+                precision: 1.0,
+            }),
+        }]
+    }
+    /// The value of [`EasyCatch::retries`] or its default `2.0` if unset
+    pub const fn effective_retries(&self) -> f64 {
+        match self.retries {
+            Some(value) => value,
+            None => 2.0,
+        }
+    }
+    /// Replace all unset settings of [`EasyCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            retries: Some(self.retries.unwrap_or(2.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "retries" => self.retries.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("retries", None) => self.retries = None,
+            ("retries", Some(SettingSimple::Number(value))) => self.retries = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoFailCatch {
     /// The acronym of [`NoFailCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NF") }
     }
+    /// The name of [`NoFailCatch`]
+    pub const fn name() -> &'static str {
+        "No Fail"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoFailCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3580,12 +9226,58 @@ impl NoFailCatch {
     pub const fn bits() -> u32 {
         1
     }
+    /// Whether [`NoFailCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoFailCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoFailCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoFailCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoFailCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoFailCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NoFailCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HalfTimeCatch {
     /// The acronym of [`HalfTimeCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HT") }
     }
+    /// The name of [`HalfTimeCatch`]
+    pub const fn name() -> &'static str {
+        "Half Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HalfTimeCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3613,12 +9305,105 @@ impl HalfTimeCatch {
     pub const fn bits() -> u32 {
         256
     }
+    /// Whether [`HalfTimeCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HalfTimeCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual decrease to apply",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`HalfTimeCatch::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`HalfTimeCatch::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`HalfTimeCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DaycoreCatch {
     /// The acronym of [`DaycoreCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DC") }
     }
+    /// The name of [`DaycoreCatch`]
+    pub const fn name() -> &'static str {
+        "Daycore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DaycoreCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3640,12 +9425,85 @@ impl DaycoreCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`DaycoreCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DaycoreCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DaycoreCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DaycoreCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DaycoreCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DaycoreCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual decrease to apply",
+            default: Some(SettingDefault::Number(0.75)),
+            range: Some(SettingRange {
+                min: 0.5,
+                max: 0.99,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`DaycoreCatch::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// Replace all unset settings of [`DaycoreCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl HardRockCatch {
     /// The acronym of [`HardRockCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HR") }
     }
+    /// The name of [`HardRockCatch`]
+    pub const fn name() -> &'static str {
+        "Hard Rock"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HardRockCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3670,12 +9528,58 @@ impl HardRockCatch {
     pub const fn bits() -> u32 {
         16
     }
+    /// Whether [`HardRockCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HardRockCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HardRockCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HardRockCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HardRockCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HardRockCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HardRockCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SuddenDeathCatch {
     /// The acronym of [`SuddenDeathCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SD") }
     }
+    /// The name of [`SuddenDeathCatch`]
+    pub const fn name() -> &'static str {
+        "Sudden Death"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SuddenDeathCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3701,12 +9605,81 @@ impl SuddenDeathCatch {
     pub const fn bits() -> u32 {
         32
     }
+    /// Whether [`SuddenDeathCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SuddenDeathCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SuddenDeathCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`SuddenDeathCatch::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`SuddenDeathCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl PerfectCatch {
     /// The acronym of [`PerfectCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("PF") }
     }
+    /// The name of [`PerfectCatch`]
+    pub const fn name() -> &'static str {
+        "Perfect"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`PerfectCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3733,12 +9706,81 @@ impl PerfectCatch {
     pub const fn bits() -> u32 {
         16416
     }
+    /// Whether [`PerfectCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`PerfectCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`PerfectCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`PerfectCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`PerfectCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`PerfectCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`PerfectCatch::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`PerfectCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DoubleTimeCatch {
     /// The acronym of [`DoubleTimeCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DT") }
     }
+    /// The name of [`DoubleTimeCatch`]
+    pub const fn name() -> &'static str {
+        "Double Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DoubleTimeCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3766,12 +9808,105 @@ impl DoubleTimeCatch {
     pub const fn bits() -> u32 {
         64
     }
+    /// Whether [`DoubleTimeCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DoubleTimeCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual increase to apply",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 1.01,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DoubleTimeCatch::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`DoubleTimeCatch::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DoubleTimeCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NightcoreCatch {
     /// The acronym of [`NightcoreCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NC") }
     }
+    /// The name of [`NightcoreCatch`]
+    pub const fn name() -> &'static str {
+        "Nightcore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NightcoreCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3799,12 +9934,85 @@ impl NightcoreCatch {
     pub const fn bits() -> u32 {
         576
     }
+    /// Whether [`NightcoreCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NightcoreCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NightcoreCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`NightcoreCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NightcoreCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NightcoreCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual increase to apply",
+            default: Some(SettingDefault::Number(1.5)),
+            range: Some(SettingRange {
+                min: 1.01,
+                max: 2.0,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`NightcoreCatch::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// Replace all unset settings of [`NightcoreCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl HiddenCatch {
     /// The acronym of [`HiddenCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HD") }
     }
+    /// The name of [`HiddenCatch`]
+    pub const fn name() -> &'static str {
+        "Hidden"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HiddenCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3823,12 +10031,58 @@ impl HiddenCatch {
     pub const fn bits() -> u32 {
         8
     }
-}
+    /// Whether [`HiddenCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HiddenCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HiddenCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HiddenCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HiddenCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HiddenCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HiddenCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
+}
 impl FlashlightCatch {
     /// The acronym of [`FlashlightCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FL") }
     }
+    /// The name of [`FlashlightCatch`]
+    pub const fn name() -> &'static str {
+        "Flashlight"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FlashlightCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3847,12 +10101,109 @@ impl FlashlightCatch {
     pub const fn bits() -> u32 {
         1024
     }
+    /// Whether [`FlashlightCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FlashlightCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FlashlightCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FlashlightCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FlashlightCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FlashlightCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "size_multiplier",
+                kind: SettingKind::Number,
+                description: "Multiplier applied to the default flashlight size.",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.5,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "combo_based_size",
+                kind: SettingKind::Bool,
+                description: "Decrease the flashlight size as combo increases.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`FlashlightCatch::size_multiplier`] or its default `1.0` if unset
+    pub const fn effective_size_multiplier(&self) -> f64 {
+        match self.size_multiplier {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`FlashlightCatch::combo_based_size`] or its default `true` if unset
+    pub const fn effective_combo_based_size(&self) -> bool {
+        match self.combo_based_size {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`FlashlightCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            size_multiplier: Some(self.size_multiplier.unwrap_or(1.0)),
+            combo_based_size: Some(self.combo_based_size.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "size_multiplier" => self.size_multiplier.map(SettingSimple::Number),
+            "combo_based_size" => self.combo_based_size.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("size_multiplier", None) => self.size_multiplier = None,
+            ("size_multiplier", Some(SettingSimple::Number(value))) => {
+                self.size_multiplier = Some(value)
+            }
+            ("combo_based_size", None) => self.combo_based_size = None,
+            ("combo_based_size", Some(SettingSimple::Bool(value))) => {
+                self.combo_based_size = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AccuracyChallengeCatch {
     /// The acronym of [`AccuracyChallengeCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AC") }
     }
+    /// The name of [`AccuracyChallengeCatch`]
+    pub const fn name() -> &'static str {
+        "Accuracy Challenge"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AccuracyChallengeCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3873,12 +10224,130 @@ impl AccuracyChallengeCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`AccuracyChallengeCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AccuracyChallengeCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "minimum_accuracy",
+                kind: SettingKind::Number,
+                description: "Trigger a failure if your accuracy goes below this value.",
+                default: Some(SettingDefault::Number(0.9)),
+                range: Some(SettingRange {
+                    min: 0.6,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "accuracy_judge_mode",
+                kind: SettingKind::String,
+                description: "The mode of accuracy that will trigger failure.",
+                default: Some(SettingDefault::String("Standard")),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AccuracyChallengeCatch::minimum_accuracy`] or its default `0.9` if unset
+    pub const fn effective_minimum_accuracy(&self) -> f64 {
+        match self.minimum_accuracy {
+            Some(value) => value,
+            None => 0.9,
+        }
+    }
+    /// The value of [`AccuracyChallengeCatch::accuracy_judge_mode`] or its default `"Standard"` if unset
+    pub fn effective_accuracy_judge_mode(&self) -> &str {
+        match self.accuracy_judge_mode {
+            Some(ref value) => value,
+            None => "Standard",
+        }
+    }
+    /// The value of [`AccuracyChallengeCatch::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`AccuracyChallengeCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            minimum_accuracy: Some(self.minimum_accuracy.unwrap_or(0.9)),
+            accuracy_judge_mode: Some(
+                self.accuracy_judge_mode
+                    .unwrap_or_else(|| String::from("Standard")),
+            ),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "minimum_accuracy" => self.minimum_accuracy.map(SettingSimple::Number),
+            "accuracy_judge_mode" => self.accuracy_judge_mode.clone().map(SettingSimple::String),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("minimum_accuracy", None) => self.minimum_accuracy = None,
+            ("minimum_accuracy", Some(SettingSimple::Number(value))) => {
+                self.minimum_accuracy = Some(value)
+            }
+            ("accuracy_judge_mode", None) => self.accuracy_judge_mode = None,
+            ("accuracy_judge_mode", Some(SettingSimple::String(value))) => {
+                self.accuracy_judge_mode = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DifficultyAdjustCatch {
     /// The acronym of [`DifficultyAdjustCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DA") }
     }
+    /// The name of [`DifficultyAdjustCatch`]
+    pub const fn name() -> &'static str {
+        "Difficulty Adjust"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DifficultyAdjustCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3897,12 +10366,169 @@ impl DifficultyAdjustCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`DifficultyAdjustCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DifficultyAdjustCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "circle_size",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set CS.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "approach_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set AR.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "hard_rock_offsets",
+                kind: SettingKind::Bool,
+                description: "Adjust the patterns as if Hard Rock is enabled.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "drain_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set HP.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "overall_difficulty",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set OD.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "extended_limits",
+                kind: SettingKind::Bool,
+                description: "Adjust difficulty beyond sane limits.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DifficultyAdjustCatch::hard_rock_offsets`] or its default `false` if unset
+    pub const fn effective_hard_rock_offsets(&self) -> bool {
+        match self.hard_rock_offsets {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`DifficultyAdjustCatch::extended_limits`] or its default `false` if unset
+    pub const fn effective_extended_limits(&self) -> bool {
+        match self.extended_limits {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DifficultyAdjustCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            circle_size: self.circle_size,
+            approach_rate: self.approach_rate,
+            hard_rock_offsets: Some(self.hard_rock_offsets.unwrap_or(false)),
+            drain_rate: self.drain_rate,
+            overall_difficulty: self.overall_difficulty,
+            extended_limits: Some(self.extended_limits.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "circle_size" => self.circle_size.map(SettingSimple::Number),
+            "approach_rate" => self.approach_rate.map(SettingSimple::Number),
+            "hard_rock_offsets" => self.hard_rock_offsets.map(SettingSimple::Bool),
+            "drain_rate" => self.drain_rate.map(SettingSimple::Number),
+            "overall_difficulty" => self.overall_difficulty.map(SettingSimple::Number),
+            "extended_limits" => self.extended_limits.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("circle_size", None) => self.circle_size = None,
+            ("circle_size", Some(SettingSimple::Number(value))) => self.circle_size = Some(value),
+            ("approach_rate", None) => self.approach_rate = None,
+            ("approach_rate", Some(SettingSimple::Number(value))) => {
+                self.approach_rate = Some(value)
+            }
+            ("hard_rock_offsets", None) => self.hard_rock_offsets = None,
+            ("hard_rock_offsets", Some(SettingSimple::Bool(value))) => {
+                self.hard_rock_offsets = Some(value)
+            }
+            ("drain_rate", None) => self.drain_rate = None,
+            ("drain_rate", Some(SettingSimple::Number(value))) => self.drain_rate = Some(value),
+            ("overall_difficulty", None) => self.overall_difficulty = None,
+            ("overall_difficulty", Some(SettingSimple::Number(value))) => {
+                self.overall_difficulty = Some(value)
+            }
+            ("extended_limits", None) => self.extended_limits = None,
+            ("extended_limits", Some(SettingSimple::Bool(value))) => {
+                self.extended_limits = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl ClassicCatch {
     /// The acronym of [`ClassicCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CL") }
     }
+    /// The name of [`ClassicCatch`]
+    pub const fn name() -> &'static str {
+        "Classic"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ClassicCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3915,12 +10541,58 @@ impl ClassicCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ClassicCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ClassicCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ClassicCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ClassicCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ClassicCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ClassicCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ClassicCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl MirrorCatch {
     /// The acronym of [`MirrorCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MR") }
     }
+    /// The name of [`MirrorCatch`]
+    pub const fn name() -> &'static str {
+        "Mirror"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MirrorCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -3939,12 +10611,58 @@ impl MirrorCatch {
     pub const fn bits() -> u32 {
         1073741824
     }
+    /// Whether [`MirrorCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MirrorCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MirrorCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MirrorCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MirrorCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MirrorCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`MirrorCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AutoplayCatch {
     /// The acronym of [`AutoplayCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AT") }
     }
+    /// The name of [`AutoplayCatch`]
+    pub const fn name() -> &'static str {
+        "Autoplay"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AutoplayCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -3970,12 +10688,58 @@ impl AutoplayCatch {
     pub const fn bits() -> u32 {
         2048
     }
+    /// Whether [`AutoplayCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`AutoplayCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AutoplayCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AutoplayCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AutoplayCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AutoplayCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AutoplayCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl CinemaCatch {
     /// The acronym of [`CinemaCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CN") }
     }
+    /// The name of [`CinemaCatch`]
+    pub const fn name() -> &'static str {
+        "Cinema"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`CinemaCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4005,12 +10769,58 @@ impl CinemaCatch {
     pub const fn bits() -> u32 {
         4194304
     }
+    /// Whether [`CinemaCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`CinemaCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`CinemaCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`CinemaCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`CinemaCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`CinemaCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`CinemaCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl RelaxCatch {
     /// The acronym of [`RelaxCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RX") }
     }
+    /// The name of [`RelaxCatch`]
+    pub const fn name() -> &'static str {
+        "Relax"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RelaxCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4036,12 +10846,58 @@ impl RelaxCatch {
     pub const fn bits() -> u32 {
         128
     }
+    /// Whether [`RelaxCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RelaxCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RelaxCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RelaxCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RelaxCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RelaxCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`RelaxCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl WindUpCatch {
     /// The acronym of [`WindUpCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WU") }
     }
+    /// The name of [`WindUpCatch`]
+    pub const fn name() -> &'static str {
+        "Wind Up"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindUpCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4063,12 +10919,127 @@ impl WindUpCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindUpCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindUpCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindUpCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindUpCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindUpCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindUpCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindUpCatch::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindUpCatch::final_rate`] or its default `1.5` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`WindUpCatch::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindUpCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl WindDownCatch {
     /// The acronym of [`WindDownCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WD") }
     }
+    /// The name of [`WindDownCatch`]
+    pub const fn name() -> &'static str {
+        "Wind Down"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindDownCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4090,12 +11061,127 @@ impl WindDownCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindDownCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindDownCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindDownCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindDownCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindDownCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindDownCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindDownCatch::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindDownCatch::final_rate`] or its default `0.75` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`WindDownCatch::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindDownCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl FloatingFruitsCatch {
     /// The acronym of [`FloatingFruitsCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FF") }
     }
+    /// The name of [`FloatingFruitsCatch`]
+    pub const fn name() -> &'static str {
+        "Floating Fruits"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FloatingFruitsCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4108,12 +11194,58 @@ impl FloatingFruitsCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`FloatingFruitsCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FloatingFruitsCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FloatingFruitsCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FloatingFruitsCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FloatingFruitsCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FloatingFruitsCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`FloatingFruitsCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl MutedCatch {
     /// The acronym of [`MutedCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MU") }
     }
+    /// The name of [`MutedCatch`]
+    pub const fn name() -> &'static str {
+        "Muted"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MutedCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4126,12 +11258,149 @@ impl MutedCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MutedCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MutedCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MutedCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MutedCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MutedCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MutedCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "inverse_muting",
+                kind: SettingKind::Bool,
+                description: "Increase volume as combo builds.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "enable_metronome",
+                kind: SettingKind::Bool,
+                description: "Add a metronome beat to help you keep track of the rhythm.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "mute_combo_count",
+                kind: SettingKind::Number,
+                description: "The combo count at which point the track reaches its final volume.",
+                default: Some(SettingDefault::Number(100.0)),
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 500.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "affects_hit_sounds",
+                kind: SettingKind::Bool,
+                description: "Hit sounds are also muted alongside the track.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`MutedCatch::inverse_muting`] or its default `false` if unset
+    pub const fn effective_inverse_muting(&self) -> bool {
+        match self.inverse_muting {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`MutedCatch::enable_metronome`] or its default `true` if unset
+    pub const fn effective_enable_metronome(&self) -> bool {
+        match self.enable_metronome {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`MutedCatch::mute_combo_count`] or its default `100.0` if unset
+    pub const fn effective_mute_combo_count(&self) -> f64 {
+        match self.mute_combo_count {
+            Some(value) => value,
+            None => 100.0,
+        }
+    }
+    /// The value of [`MutedCatch::affects_hit_sounds`] or its default `true` if unset
+    pub const fn effective_affects_hit_sounds(&self) -> bool {
+        match self.affects_hit_sounds {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`MutedCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            inverse_muting: Some(self.inverse_muting.unwrap_or(false)),
+            enable_metronome: Some(self.enable_metronome.unwrap_or(true)),
+            mute_combo_count: Some(self.mute_combo_count.unwrap_or(100.0)),
+            affects_hit_sounds: Some(self.affects_hit_sounds.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "inverse_muting" => self.inverse_muting.map(SettingSimple::Bool),
+            "enable_metronome" => self.enable_metronome.map(SettingSimple::Bool),
+            "mute_combo_count" => self.mute_combo_count.map(SettingSimple::Number),
+            "affects_hit_sounds" => self.affects_hit_sounds.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("inverse_muting", None) => self.inverse_muting = None,
+            ("inverse_muting", Some(SettingSimple::Bool(value))) => {
+                self.inverse_muting = Some(value)
+            }
+            ("enable_metronome", None) => self.enable_metronome = None,
+            ("enable_metronome", Some(SettingSimple::Bool(value))) => {
+                self.enable_metronome = Some(value)
+            }
+            ("mute_combo_count", None) => self.mute_combo_count = None,
+            ("mute_combo_count", Some(SettingSimple::Number(value))) => {
+                self.mute_combo_count = Some(value)
+            }
+            ("affects_hit_sounds", None) => self.affects_hit_sounds = None,
+            ("affects_hit_sounds", Some(SettingSimple::Bool(value))) => {
+                self.affects_hit_sounds = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoScopeCatch {
     /// The acronym of [`NoScopeCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NS") }
     }
+    /// The name of [`NoScopeCatch`]
+    pub const fn name() -> &'static str {
+        "No Scope"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoScopeCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4144,12 +11413,87 @@ impl NoScopeCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`NoScopeCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoScopeCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoScopeCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoScopeCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoScopeCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoScopeCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "hidden_combo_count",
+            kind: SettingKind::Number,
+            description: "The combo count at which the cursor becomes completely hidden",
+            default: Some(SettingDefault::Number(10.0)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 50.0,
+                precision: 1.0,
+            }),
+        }]
+    }
+    /// The value of [`NoScopeCatch::hidden_combo_count`] or its default `10.0` if unset
+    pub const fn effective_hidden_combo_count(&self) -> f64 {
+        match self.hidden_combo_count {
+            Some(value) => value,
+            None => 10.0,
+        }
+    }
+    /// Replace all unset settings of [`NoScopeCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            hidden_combo_count: Some(self.hidden_combo_count.unwrap_or(10.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "hidden_combo_count" => self.hidden_combo_count.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("hidden_combo_count", None) => self.hidden_combo_count = None,
+            ("hidden_combo_count", Some(SettingSimple::Number(value))) => {
+                self.hidden_combo_count = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl MovingFastCatch {
     /// The acronym of [`MovingFastCatch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MF") }
     }
+    /// The name of [`MovingFastCatch`]
+    pub const fn name() -> &'static str {
+        "Moving Fast"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MovingFastCatch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4169,12 +11513,58 @@ impl MovingFastCatch {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MovingFastCatch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MovingFastCatch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MovingFastCatch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MovingFastCatch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MovingFastCatch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MovingFastCatch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`MovingFastCatch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl ScoreV2Catch {
     /// The acronym of [`ScoreV2Catch`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SV2") }
     }
+    /// The name of [`ScoreV2Catch`]
+    pub const fn name() -> &'static str {
+        "Score V2"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ScoreV2Catch`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4193,12 +11583,58 @@ impl ScoreV2Catch {
     pub const fn bits() -> u32 {
         536870912
     }
+    /// Whether [`ScoreV2Catch`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Catch`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ScoreV2Catch`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Catch`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Catch`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ScoreV2Catch`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ScoreV2Catch`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl EasyMania {
     /// The acronym of [`EasyMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("EZ") }
     }
+    /// The name of [`EasyMania`]
+    pub const fn name() -> &'static str {
+        "Easy"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`EasyMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4224,12 +11660,85 @@ impl EasyMania {
     pub const fn bits() -> u32 {
         2
     }
+    /// Whether [`EasyMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`EasyMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`EasyMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`EasyMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`EasyMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`EasyMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "retries",
+            kind: SettingKind::Number,
+            description: "Number of extra lives",
+            default: Some(SettingDefault::Number(2.0)),
+            range: Some(SettingRange {
+                min: 0.0,
+                max: 10.0,
+                precision: 1.0,
+            }),
+        }]
+    }
+    /// The value of [`EasyMania::retries`] or its default `2.0` if unset
+    pub const fn effective_retries(&self) -> f64 {
+        match self.retries {
+            Some(value) => value,
+            None => 2.0,
+        }
+    }
+    /// Replace all unset settings of [`EasyMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            retries: Some(self.retries.unwrap_or(2.0)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "retries" => self.retries.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("retries", None) => self.retries = None,
+            ("retries", Some(SettingSimple::Number(value))) => self.retries = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoFailMania {
     /// The acronym of [`NoFailMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NF") }
     }
+    /// The name of [`NoFailMania`]
+    pub const fn name() -> &'static str {
+        "No Fail"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoFailMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4256,12 +11765,58 @@ impl NoFailMania {
     pub const fn bits() -> u32 {
         1
     }
+    /// Whether [`NoFailMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoFailMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoFailMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoFailMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoFailMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoFailMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NoFailMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HalfTimeMania {
     /// The acronym of [`HalfTimeMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HT") }
     }
+    /// The name of [`HalfTimeMania`]
+    pub const fn name() -> &'static str {
+        "Half Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HalfTimeMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4290,12 +11845,105 @@ impl HalfTimeMania {
     pub const fn bits() -> u32 {
         256
     }
+    /// Whether [`HalfTimeMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HalfTimeMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HalfTimeMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HalfTimeMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual decrease to apply",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`HalfTimeMania::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`HalfTimeMania::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`HalfTimeMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DaycoreMania {
     /// The acronym of [`DaycoreMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DC") }
     }
+    /// The name of [`DaycoreMania`]
+    pub const fn name() -> &'static str {
+        "Daycore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DaycoreMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4318,12 +11966,85 @@ impl DaycoreMania {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`DaycoreMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DaycoreMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DaycoreMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DaycoreMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DaycoreMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DaycoreMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual decrease to apply",
+            default: Some(SettingDefault::Number(0.75)),
+            range: Some(SettingRange {
+                min: 0.5,
+                max: 0.99,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`DaycoreMania::speed_change`] or its default `0.75` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// Replace all unset settings of [`DaycoreMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(0.75)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NoReleaseMania {
     /// The acronym of [`NoReleaseMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NR") }
     }
+    /// The name of [`NoReleaseMania`]
+    pub const fn name() -> &'static str {
+        "No Release"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NoReleaseMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("HO")] }.into_iter()
@@ -4336,12 +12057,58 @@ impl NoReleaseMania {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyReduction
     }
+    /// Whether [`NoReleaseMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NoReleaseMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NoReleaseMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`NoReleaseMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NoReleaseMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NoReleaseMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NoReleaseMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HardRockMania {
     /// The acronym of [`HardRockMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HR") }
     }
+    /// The name of [`HardRockMania`]
+    pub const fn name() -> &'static str {
+        "Hard Rock"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HardRockMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4366,12 +12133,58 @@ impl HardRockMania {
     pub const fn bits() -> u32 {
         16
     }
+    /// Whether [`HardRockMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HardRockMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HardRockMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HardRockMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HardRockMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HardRockMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HardRockMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SuddenDeathMania {
     /// The acronym of [`SuddenDeathMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SD") }
     }
+    /// The name of [`SuddenDeathMania`]
+    pub const fn name() -> &'static str {
+        "Sudden Death"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SuddenDeathMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4397,12 +12210,81 @@ impl SuddenDeathMania {
     pub const fn bits() -> u32 {
         32
     }
+    /// Whether [`SuddenDeathMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`SuddenDeathMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SuddenDeathMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SuddenDeathMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "restart",
+            kind: SettingKind::Bool,
+            description: "Automatically restarts when failed.",
+            default: Some(SettingDefault::Bool(false)),
+            range: None,
+        }]
+    }
+    /// The value of [`SuddenDeathMania::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`SuddenDeathMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl PerfectMania {
     /// The acronym of [`PerfectMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("PF") }
     }
+    /// The name of [`PerfectMania`]
+    pub const fn name() -> &'static str {
+        "Perfect"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`PerfectMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4429,12 +12311,103 @@ impl PerfectMania {
     pub const fn bits() -> u32 {
         16416
     }
+    /// Whether [`PerfectMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`PerfectMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`PerfectMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`PerfectMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`PerfectMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`PerfectMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "require_perfect_hits",
+                kind: SettingKind::Bool,
+                description: "",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`PerfectMania::require_perfect_hits`] or its default `false` if unset
+    pub const fn effective_require_perfect_hits(&self) -> bool {
+        match self.require_perfect_hits {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`PerfectMania::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`PerfectMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            require_perfect_hits: Some(self.require_perfect_hits.unwrap_or(false)),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "require_perfect_hits" => self.require_perfect_hits.map(SettingSimple::Bool),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("require_perfect_hits", None) => self.require_perfect_hits = None,
+            ("require_perfect_hits", Some(SettingSimple::Bool(value))) => {
+                self.require_perfect_hits = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DoubleTimeMania {
     /// The acronym of [`DoubleTimeMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DT") }
     }
+    /// The name of [`DoubleTimeMania`]
+    pub const fn name() -> &'static str {
+        "Double Time"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DoubleTimeMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4463,12 +12436,105 @@ impl DoubleTimeMania {
     pub const fn bits() -> u32 {
         64
     }
+    /// Whether [`DoubleTimeMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DoubleTimeMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DoubleTimeMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DoubleTimeMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "speed_change",
+                kind: SettingKind::Number,
+                description: "The actual increase to apply",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 1.01,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DoubleTimeMania::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`DoubleTimeMania::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DoubleTimeMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl NightcoreMania {
     /// The acronym of [`NightcoreMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("NC") }
     }
+    /// The name of [`NightcoreMania`]
+    pub const fn name() -> &'static str {
+        "Nightcore"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NightcoreMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4497,12 +12563,85 @@ impl NightcoreMania {
     pub const fn bits() -> u32 {
         576
     }
+    /// Whether [`NightcoreMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NightcoreMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NightcoreMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`NightcoreMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NightcoreMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NightcoreMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "speed_change",
+            kind: SettingKind::Number,
+            description: "The actual increase to apply",
+            default: Some(SettingDefault::Number(1.5)),
+            range: Some(SettingRange {
+                min: 1.01,
+                max: 2.0,
+                precision: 0.01,
+            }),
+        }]
+    }
+    /// The value of [`NightcoreMania::speed_change`] or its default `1.5` if unset
+    pub const fn effective_speed_change(&self) -> f64 {
+        match self.speed_change {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// Replace all unset settings of [`NightcoreMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            speed_change: Some(self.speed_change.unwrap_or(1.5)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "speed_change" => self.speed_change.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("speed_change", None) => self.speed_change = None,
+            ("speed_change", Some(SettingSimple::Number(value))) => self.speed_change = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl FadeInMania {
     /// The acronym of [`FadeInMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FI") }
     }
+    /// The name of [`FadeInMania`]
+    pub const fn name() -> &'static str {
+        "Fade In"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FadeInMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4528,12 +12667,58 @@ impl FadeInMania {
     pub const fn bits() -> u32 {
         1048576
     }
+    /// Whether [`FadeInMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FadeInMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FadeInMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FadeInMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FadeInMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FadeInMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`FadeInMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HiddenMania {
     /// The acronym of [`HiddenMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HD") }
     }
+    /// The name of [`HiddenMania`]
+    pub const fn name() -> &'static str {
+        "Hidden"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HiddenMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4559,12 +12744,58 @@ impl HiddenMania {
     pub const fn bits() -> u32 {
         8
     }
+    /// Whether [`HiddenMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HiddenMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HiddenMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HiddenMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HiddenMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HiddenMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HiddenMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl CoverMania {
     /// The acronym of [`CoverMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CO") }
     }
+    /// The name of [`CoverMania`]
+    pub const fn name() -> &'static str {
+        "Cover"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`CoverMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4584,12 +12815,108 @@ impl CoverMania {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`CoverMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`CoverMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`CoverMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`CoverMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`CoverMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`CoverMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "coverage",
+                kind: SettingKind::Number,
+                description: "The proportion of playfield height that notes will be hidden for.",
+                default: Some(SettingDefault::Number(0.5)),
+                range: Some(SettingRange {
+                    min: 0.2,
+                    max: 0.8,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "direction",
+                kind: SettingKind::String,
+                description: "The direction on which the cover is applied",
+                default: Some(SettingDefault::String("AlongScroll")),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`CoverMania::coverage`] or its default `0.5` if unset
+    pub const fn effective_coverage(&self) -> f64 {
+        match self.coverage {
+            Some(value) => value,
+            None => 0.5,
+        }
+    }
+    /// The value of [`CoverMania::direction`] or its default `"AlongScroll"` if unset
+    pub fn effective_direction(&self) -> &str {
+        match self.direction {
+            Some(ref value) => value,
+            None => "AlongScroll",
+        }
+    }
+    /// Replace all unset settings of [`CoverMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            coverage: Some(self.coverage.unwrap_or(0.5)),
+            direction: Some(
+                self.direction
+                    .unwrap_or_else(|| String::from("AlongScroll")),
+            ),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "coverage" => self.coverage.map(SettingSimple::Number),
+            "direction" => self.direction.clone().map(SettingSimple::String),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("coverage", None) => self.coverage = None,
+            ("coverage", Some(SettingSimple::Number(value))) => self.coverage = Some(value),
+            ("direction", None) => self.direction = None,
+            ("direction", Some(SettingSimple::String(value))) => self.direction = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl FlashlightMania {
     /// The acronym of [`FlashlightMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("FL") }
     }
+    /// The name of [`FlashlightMania`]
+    pub const fn name() -> &'static str {
+        "Flashlight"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FlashlightMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4615,12 +12942,109 @@ impl FlashlightMania {
     pub const fn bits() -> u32 {
         1024
     }
+    /// Whether [`FlashlightMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FlashlightMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FlashlightMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`FlashlightMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FlashlightMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FlashlightMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "size_multiplier",
+                kind: SettingKind::Number,
+                description: "Multiplier applied to the default flashlight size.",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 3.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "combo_based_size",
+                kind: SettingKind::Bool,
+                description: "Decrease the flashlight size as combo increases.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`FlashlightMania::size_multiplier`] or its default `1.0` if unset
+    pub const fn effective_size_multiplier(&self) -> f64 {
+        match self.size_multiplier {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`FlashlightMania::combo_based_size`] or its default `true` if unset
+    pub const fn effective_combo_based_size(&self) -> bool {
+        match self.combo_based_size {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`FlashlightMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            size_multiplier: Some(self.size_multiplier.unwrap_or(1.0)),
+            combo_based_size: Some(self.combo_based_size.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "size_multiplier" => self.size_multiplier.map(SettingSimple::Number),
+            "combo_based_size" => self.combo_based_size.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("size_multiplier", None) => self.size_multiplier = None,
+            ("size_multiplier", Some(SettingSimple::Number(value))) => {
+                self.size_multiplier = Some(value)
+            }
+            ("combo_based_size", None) => self.combo_based_size = None,
+            ("combo_based_size", Some(SettingSimple::Bool(value))) => {
+                self.combo_based_size = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AccuracyChallengeMania {
     /// The acronym of [`AccuracyChallengeMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AC") }
     }
+    /// The name of [`AccuracyChallengeMania`]
+    pub const fn name() -> &'static str {
+        "Accuracy Challenge"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AccuracyChallengeMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4641,12 +13065,130 @@ impl AccuracyChallengeMania {
     pub const fn kind() -> GameModKind {
         GameModKind::DifficultyIncrease
     }
+    /// Whether [`AccuracyChallengeMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`AccuracyChallengeMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AccuracyChallengeMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AccuracyChallengeMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "minimum_accuracy",
+                kind: SettingKind::Number,
+                description: "Trigger a failure if your accuracy goes below this value.",
+                default: Some(SettingDefault::Number(0.9)),
+                range: Some(SettingRange {
+                    min: 0.6,
+                    max: 0.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "accuracy_judge_mode",
+                kind: SettingKind::String,
+                description: "The mode of accuracy that will trigger failure.",
+                default: Some(SettingDefault::String("Standard")),
+                range: None,
+            },
+            SettingSchema {
+                name: "restart",
+                kind: SettingKind::Bool,
+                description: "Automatically restarts when failed.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AccuracyChallengeMania::minimum_accuracy`] or its default `0.9` if unset
+    pub const fn effective_minimum_accuracy(&self) -> f64 {
+        match self.minimum_accuracy {
+            Some(value) => value,
+            None => 0.9,
+        }
+    }
+    /// The value of [`AccuracyChallengeMania::accuracy_judge_mode`] or its default `"Standard"` if unset
+    pub fn effective_accuracy_judge_mode(&self) -> &str {
+        match self.accuracy_judge_mode {
+            Some(ref value) => value,
+            None => "Standard",
+        }
+    }
+    /// The value of [`AccuracyChallengeMania::restart`] or its default `false` if unset
+    pub const fn effective_restart(&self) -> bool {
+        match self.restart {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`AccuracyChallengeMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            minimum_accuracy: Some(self.minimum_accuracy.unwrap_or(0.9)),
+            accuracy_judge_mode: Some(
+                self.accuracy_judge_mode
+                    .unwrap_or_else(|| String::from("Standard")),
+            ),
+            restart: Some(self.restart.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "minimum_accuracy" => self.minimum_accuracy.map(SettingSimple::Number),
+            "accuracy_judge_mode" => self.accuracy_judge_mode.clone().map(SettingSimple::String),
+            "restart" => self.restart.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("minimum_accuracy", None) => self.minimum_accuracy = None,
+            ("minimum_accuracy", Some(SettingSimple::Number(value))) => {
+                self.minimum_accuracy = Some(value)
+            }
+            ("accuracy_judge_mode", None) => self.accuracy_judge_mode = None,
+            ("accuracy_judge_mode", Some(SettingSimple::String(value))) => {
+                self.accuracy_judge_mode = Some(value)
+            }
+            ("restart", None) => self.restart = None,
+            ("restart", Some(SettingSimple::Bool(value))) => self.restart = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl RandomMania {
     /// The acronym of [`RandomMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("RD") }
     }
+    /// The name of [`RandomMania`]
+    pub const fn name() -> &'static str {
+        "Random"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`RandomMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4665,12 +13207,72 @@ impl RandomMania {
     pub const fn bits() -> u32 {
         2097152
     }
+    /// Whether [`RandomMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`RandomMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`RandomMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`RandomMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`RandomMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`RandomMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[SettingSchema {
+            name: "seed",
+            kind: SettingKind::Number,
+            description: "Use a custom seed instead of a random one",
+            default: None,
+            range: None,
+        }]
+    }
+    /// Replace all unset settings of [`RandomMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "seed" => self.seed.map(SettingSimple::Number),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("seed", None) => self.seed = None,
+            ("seed", Some(SettingSimple::Number(value))) => self.seed = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl DualStagesMania {
     /// The acronym of [`DualStagesMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DS") }
     }
+    /// The name of [`DualStagesMania`]
+    pub const fn name() -> &'static str {
+        "Dual Stages"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DualStagesMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4689,12 +13291,58 @@ impl DualStagesMania {
     pub const fn bits() -> u32 {
         33554432
     }
+    /// Whether [`DualStagesMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DualStagesMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DualStagesMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DualStagesMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`DualStagesMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DualStagesMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`DualStagesMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl MirrorMania {
     /// The acronym of [`MirrorMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MR") }
     }
+    /// The name of [`MirrorMania`]
+    pub const fn name() -> &'static str {
+        "Mirror"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MirrorMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4713,12 +13361,58 @@ impl MirrorMania {
     pub const fn bits() -> u32 {
         1073741824
     }
+    /// Whether [`MirrorMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MirrorMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MirrorMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MirrorMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MirrorMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MirrorMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`MirrorMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl DifficultyAdjustMania {
     /// The acronym of [`DifficultyAdjustMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("DA") }
     }
+    /// The name of [`DifficultyAdjustMania`]
+    pub const fn name() -> &'static str {
+        "Difficulty Adjust"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`DifficultyAdjustMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4737,12 +13431,117 @@ impl DifficultyAdjustMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`DifficultyAdjustMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        true
+    }
+    /// Whether [`DifficultyAdjustMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`DifficultyAdjustMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "overall_difficulty",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set OD.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "drain_rate",
+                kind: SettingKind::Number,
+                description: "Override a beatmap's set HP.",
+                default: None,
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 10.0,
+                    precision: 0.1,
+                }),
+            },
+            SettingSchema {
+                name: "extended_limits",
+                kind: SettingKind::Bool,
+                description: "Adjust difficulty beyond sane limits.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`DifficultyAdjustMania::extended_limits`] or its default `false` if unset
+    pub const fn effective_extended_limits(&self) -> bool {
+        match self.extended_limits {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`DifficultyAdjustMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            overall_difficulty: self.overall_difficulty,
+            drain_rate: self.drain_rate,
+            extended_limits: Some(self.extended_limits.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "overall_difficulty" => self.overall_difficulty.map(SettingSimple::Number),
+            "drain_rate" => self.drain_rate.map(SettingSimple::Number),
+            "extended_limits" => self.extended_limits.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("overall_difficulty", None) => self.overall_difficulty = None,
+            ("overall_difficulty", Some(SettingSimple::Number(value))) => {
+                self.overall_difficulty = Some(value)
+            }
+            ("drain_rate", None) => self.drain_rate = None,
+            ("drain_rate", Some(SettingSimple::Number(value))) => self.drain_rate = Some(value),
+            ("extended_limits", None) => self.extended_limits = None,
+            ("extended_limits", Some(SettingSimple::Bool(value))) => {
+                self.extended_limits = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl ClassicMania {
     /// The acronym of [`ClassicMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CL") }
     }
+    /// The name of [`ClassicMania`]
+    pub const fn name() -> &'static str {
+        "Classic"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ClassicMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4755,12 +13554,58 @@ impl ClassicMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ClassicMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ClassicMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ClassicMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ClassicMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ClassicMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ClassicMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ClassicMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl InvertMania {
     /// The acronym of [`InvertMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("IN") }
     }
+    /// The name of [`InvertMania`]
+    pub const fn name() -> &'static str {
+        "Invert"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`InvertMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe { [Acronym::from_str_unchecked("HO")] }.into_iter()
@@ -4773,12 +13618,58 @@ impl InvertMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`InvertMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`InvertMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`InvertMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`InvertMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`InvertMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`InvertMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`InvertMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl ConstantSpeedMania {
     /// The acronym of [`ConstantSpeedMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CS") }
     }
+    /// The name of [`ConstantSpeedMania`]
+    pub const fn name() -> &'static str {
+        "Constant Speed"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ConstantSpeedMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -4791,12 +13682,58 @@ impl ConstantSpeedMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`ConstantSpeedMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`ConstantSpeedMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ConstantSpeedMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ConstantSpeedMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ConstantSpeedMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl HoldOffMania {
     /// The acronym of [`HoldOffMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("HO") }
     }
+    /// The name of [`HoldOffMania`]
+    pub const fn name() -> &'static str {
+        "Hold Off"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`HoldOffMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4815,12 +13752,58 @@ impl HoldOffMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`HoldOffMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`HoldOffMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`HoldOffMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`HoldOffMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`HoldOffMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`HoldOffMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`HoldOffMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl OneKeyMania {
     /// The acronym of [`OneKeyMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("1K") }
     }
+    /// The name of [`OneKeyMania`]
+    pub const fn name() -> &'static str {
+        "One Key"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`OneKeyMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4842,15 +13825,57 @@ impl OneKeyMania {
     pub const fn description() -> &'static str {
         "Play with one key."
     }
-    /// The [`GameModKind`] of [`OneKeyMania`]
-    pub const fn kind() -> GameModKind {
-        GameModKind::Conversion
+    /// The [`GameModKind`] of [`OneKeyMania`]
+    pub const fn kind() -> GameModKind {
+        GameModKind::Conversion
+    }
+    /// Bit value of [`OneKeyMania`]
+    ///
+    /// See <https://github.com/ppy/osu-api/wiki#mods>
+    pub const fn bits() -> u32 {
+        67108864
+    }
+    /// Whether [`OneKeyMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`OneKeyMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`OneKeyMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`OneKeyMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`OneKeyMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`OneKeyMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`OneKeyMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
     }
-    /// Bit value of [`OneKeyMania`]
+    /// Set or unset the setting with the given name
     ///
-    /// See <https://github.com/ppy/osu-api/wiki#mods>
-    pub const fn bits() -> u32 {
-        67108864
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
     }
 }
 impl TwoKeysMania {
@@ -4858,6 +13883,10 @@ impl TwoKeysMania {
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("2K") }
     }
+    /// The name of [`TwoKeysMania`]
+    pub const fn name() -> &'static str {
+        "Two Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TwoKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4889,12 +13918,58 @@ impl TwoKeysMania {
     pub const fn bits() -> u32 {
         268435456
     }
+    /// Whether [`TwoKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TwoKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`TwoKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`TwoKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TwoKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`TwoKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`TwoKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl ThreeKeysMania {
     /// The acronym of [`ThreeKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("3K") }
     }
+    /// The name of [`ThreeKeysMania`]
+    pub const fn name() -> &'static str {
+        "Three Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ThreeKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4926,12 +14001,58 @@ impl ThreeKeysMania {
     pub const fn bits() -> u32 {
         134217728
     }
+    /// Whether [`ThreeKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`ThreeKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ThreeKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`ThreeKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ThreeKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ThreeKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ThreeKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl FourKeysMania {
     /// The acronym of [`FourKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("4K") }
     }
+    /// The name of [`FourKeysMania`]
+    pub const fn name() -> &'static str {
+        "Four Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FourKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -4963,12 +14084,58 @@ impl FourKeysMania {
     pub const fn bits() -> u32 {
         32768
     }
+    /// Whether [`FourKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FourKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FourKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`FourKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FourKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FourKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`FourKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl FiveKeysMania {
     /// The acronym of [`FiveKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("5K") }
     }
+    /// The name of [`FiveKeysMania`]
+    pub const fn name() -> &'static str {
+        "Five Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`FiveKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5000,12 +14167,58 @@ impl FiveKeysMania {
     pub const fn bits() -> u32 {
         65536
     }
+    /// Whether [`FiveKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`FiveKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`FiveKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`FiveKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`FiveKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`FiveKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`FiveKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SixKeysMania {
     /// The acronym of [`SixKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("6K") }
     }
+    /// The name of [`SixKeysMania`]
+    pub const fn name() -> &'static str {
+        "Six Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SixKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5037,12 +14250,58 @@ impl SixKeysMania {
     pub const fn bits() -> u32 {
         131072
     }
+    /// Whether [`SixKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SixKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SixKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`SixKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SixKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SixKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SixKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl SevenKeysMania {
     /// The acronym of [`SevenKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("7K") }
     }
+    /// The name of [`SevenKeysMania`]
+    pub const fn name() -> &'static str {
+        "Seven Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`SevenKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5074,12 +14333,58 @@ impl SevenKeysMania {
     pub const fn bits() -> u32 {
         262144
     }
+    /// Whether [`SevenKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`SevenKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`SevenKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`SevenKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`SevenKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`SevenKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`SevenKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl EightKeysMania {
     /// The acronym of [`EightKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("8K") }
     }
+    /// The name of [`EightKeysMania`]
+    pub const fn name() -> &'static str {
+        "Eight Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`EightKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5111,12 +14416,58 @@ impl EightKeysMania {
     pub const fn bits() -> u32 {
         524288
     }
+    /// Whether [`EightKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`EightKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`EightKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`EightKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`EightKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`EightKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`EightKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl NineKeysMania {
     /// The acronym of [`NineKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("9K") }
     }
+    /// The name of [`NineKeysMania`]
+    pub const fn name() -> &'static str {
+        "Nine Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`NineKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5148,12 +14499,58 @@ impl NineKeysMania {
     pub const fn bits() -> u32 {
         16777216
     }
+    /// Whether [`NineKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`NineKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`NineKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`NineKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`NineKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`NineKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`NineKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl TenKeysMania {
     /// The acronym of [`TenKeysMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("10K") }
     }
+    /// The name of [`TenKeysMania`]
+    pub const fn name() -> &'static str {
+        "Ten Keys"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`TenKeysMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5179,12 +14576,58 @@ impl TenKeysMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Conversion
     }
+    /// Whether [`TenKeysMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`TenKeysMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`TenKeysMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`TenKeysMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`TenKeysMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`TenKeysMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`TenKeysMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl AutoplayMania {
     /// The acronym of [`AutoplayMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AT") }
     }
+    /// The name of [`AutoplayMania`]
+    pub const fn name() -> &'static str {
+        "Autoplay"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AutoplayMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5209,12 +14652,58 @@ impl AutoplayMania {
     pub const fn bits() -> u32 {
         2048
     }
+    /// Whether [`AutoplayMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`AutoplayMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AutoplayMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AutoplayMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AutoplayMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AutoplayMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`AutoplayMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl CinemaMania {
     /// The acronym of [`CinemaMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("CN") }
     }
+    /// The name of [`CinemaMania`]
+    pub const fn name() -> &'static str {
+        "Cinema"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`CinemaMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5243,12 +14732,58 @@ impl CinemaMania {
     pub const fn bits() -> u32 {
         4194304
     }
+    /// Whether [`CinemaMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`CinemaMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`CinemaMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`CinemaMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`CinemaMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`CinemaMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`CinemaMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl WindUpMania {
     /// The acronym of [`WindUpMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WU") }
     }
+    /// The name of [`WindUpMania`]
+    pub const fn name() -> &'static str {
+        "Wind Up"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindUpMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5271,12 +14806,127 @@ impl WindUpMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindUpMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindUpMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindUpMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindUpMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindUpMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindUpMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(1.5)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindUpMania::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindUpMania::final_rate`] or its default `1.5` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 1.5,
+        }
+    }
+    /// The value of [`WindUpMania::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindUpMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(1.5)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl WindDownMania {
     /// The acronym of [`WindDownMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("WD") }
     }
+    /// The name of [`WindDownMania`]
+    pub const fn name() -> &'static str {
+        "Wind Down"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`WindDownMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5299,12 +14949,127 @@ impl WindDownMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`WindDownMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`WindDownMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`WindDownMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`WindDownMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`WindDownMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`WindDownMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.51,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "final_rate",
+                kind: SettingKind::Number,
+                description: "The final speed to ramp to",
+                default: Some(SettingDefault::Number(0.75)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 1.99,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`WindDownMania::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`WindDownMania::final_rate`] or its default `0.75` if unset
+    pub const fn effective_final_rate(&self) -> f64 {
+        match self.final_rate {
+            Some(value) => value,
+            None => 0.75,
+        }
+    }
+    /// The value of [`WindDownMania::adjust_pitch`] or its default `false` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// Replace all unset settings of [`WindDownMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            final_rate: Some(self.final_rate.unwrap_or(0.75)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(false)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "final_rate" => self.final_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("final_rate", None) => self.final_rate = None,
+            ("final_rate", Some(SettingSimple::Number(value))) => self.final_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl MutedMania {
     /// The acronym of [`MutedMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("MU") }
     }
+    /// The name of [`MutedMania`]
+    pub const fn name() -> &'static str {
+        "Muted"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`MutedMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -5317,12 +15082,149 @@ impl MutedMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`MutedMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`MutedMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`MutedMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        true
+    }
+    /// Whether [`MutedMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`MutedMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`MutedMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "inverse_muting",
+                kind: SettingKind::Bool,
+                description: "Increase volume as combo builds.",
+                default: Some(SettingDefault::Bool(false)),
+                range: None,
+            },
+            SettingSchema {
+                name: "enable_metronome",
+                kind: SettingKind::Bool,
+                description: "Add a metronome beat to help you keep track of the rhythm.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+            SettingSchema {
+                name: "mute_combo_count",
+                kind: SettingKind::Number,
+                description: "The combo count at which point the track reaches its final volume.",
+                default: Some(SettingDefault::Number(100.0)),
+                range: Some(SettingRange {
+                    min: 0.0,
+                    max: 500.0,
+                    precision: 1.0,
+                }),
+            },
+            SettingSchema {
+                name: "affects_hit_sounds",
+                kind: SettingKind::Bool,
+                description: "Hit sounds are also muted alongside the track.",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`MutedMania::inverse_muting`] or its default `false` if unset
+    pub const fn effective_inverse_muting(&self) -> bool {
+        match self.inverse_muting {
+            Some(value) => value,
+            None => false,
+        }
+    }
+    /// The value of [`MutedMania::enable_metronome`] or its default `true` if unset
+    pub const fn effective_enable_metronome(&self) -> bool {
+        match self.enable_metronome {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// The value of [`MutedMania::mute_combo_count`] or its default `100.0` if unset
+    pub const fn effective_mute_combo_count(&self) -> f64 {
+        match self.mute_combo_count {
+            Some(value) => value,
+            None => 100.0,
+        }
+    }
+    /// The value of [`MutedMania::affects_hit_sounds`] or its default `true` if unset
+    pub const fn effective_affects_hit_sounds(&self) -> bool {
+        match self.affects_hit_sounds {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`MutedMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            inverse_muting: Some(self.inverse_muting.unwrap_or(false)),
+            enable_metronome: Some(self.enable_metronome.unwrap_or(true)),
+            mute_combo_count: Some(self.mute_combo_count.unwrap_or(100.0)),
+            affects_hit_sounds: Some(self.affects_hit_sounds.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "inverse_muting" => self.inverse_muting.map(SettingSimple::Bool),
+            "enable_metronome" => self.enable_metronome.map(SettingSimple::Bool),
+            "mute_combo_count" => self.mute_combo_count.map(SettingSimple::Number),
+            "affects_hit_sounds" => self.affects_hit_sounds.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("inverse_muting", None) => self.inverse_muting = None,
+            ("inverse_muting", Some(SettingSimple::Bool(value))) => {
+                self.inverse_muting = Some(value)
+            }
+            ("enable_metronome", None) => self.enable_metronome = None,
+            ("enable_metronome", Some(SettingSimple::Bool(value))) => {
+                self.enable_metronome = Some(value)
+            }
+            ("mute_combo_count", None) => self.mute_combo_count = None,
+            ("mute_combo_count", Some(SettingSimple::Number(value))) => {
+                self.mute_combo_count = Some(value)
+            }
+            ("affects_hit_sounds", None) => self.affects_hit_sounds = None,
+            ("affects_hit_sounds", Some(SettingSimple::Bool(value))) => {
+                self.affects_hit_sounds = Some(value)
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 impl AdaptiveSpeedMania {
     /// The acronym of [`AdaptiveSpeedMania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("AS") }
     }
+    /// The name of [`AdaptiveSpeedMania`]
+    pub const fn name() -> &'static str {
+        "Adaptive Speed"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`AdaptiveSpeedMania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         unsafe {
@@ -5347,12 +15249,105 @@ impl AdaptiveSpeedMania {
     pub const fn kind() -> GameModKind {
         GameModKind::Fun
     }
+    /// Whether [`AdaptiveSpeedMania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        true
+    }
+    /// Whether [`AdaptiveSpeedMania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedMania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedMania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`AdaptiveSpeedMania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`AdaptiveSpeedMania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[
+            SettingSchema {
+                name: "initial_rate",
+                kind: SettingKind::Number,
+                description: "The starting speed of the track",
+                default: Some(SettingDefault::Number(1.0)),
+                range: Some(SettingRange {
+                    min: 0.5,
+                    max: 2.0,
+                    precision: 0.01,
+                }),
+            },
+            SettingSchema {
+                name: "adjust_pitch",
+                kind: SettingKind::Bool,
+                description: "Should pitch be adjusted with speed",
+                default: Some(SettingDefault::Bool(true)),
+                range: None,
+            },
+        ]
+    }
+    /// The value of [`AdaptiveSpeedMania::initial_rate`] or its default `1.0` if unset
+    pub const fn effective_initial_rate(&self) -> f64 {
+        match self.initial_rate {
+            Some(value) => value,
+            None => 1.0,
+        }
+    }
+    /// The value of [`AdaptiveSpeedMania::adjust_pitch`] or its default `true` if unset
+    pub const fn effective_adjust_pitch(&self) -> bool {
+        match self.adjust_pitch {
+            Some(value) => value,
+            None => true,
+        }
+    }
+    /// Replace all unset settings of [`AdaptiveSpeedMania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        Self {
+            initial_rate: Some(self.initial_rate.unwrap_or(1.0)),
+            adjust_pitch: Some(self.adjust_pitch.unwrap_or(true)),
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match name {
+            "initial_rate" => self.initial_rate.map(SettingSimple::Number),
+            "adjust_pitch" => self.adjust_pitch.map(SettingSimple::Bool),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match (name, value) {
+            ("initial_rate", None) => self.initial_rate = None,
+            ("initial_rate", Some(SettingSimple::Number(value))) => self.initial_rate = Some(value),
+            ("adjust_pitch", None) => self.adjust_pitch = None,
+            ("adjust_pitch", Some(SettingSimple::Bool(value))) => self.adjust_pitch = Some(value),
+            _ => return false,
+        }
+        true
+    }
 }
 impl ScoreV2Mania {
     /// The acronym of [`ScoreV2Mania`]
     pub const fn acronym() -> Acronym {
         unsafe { Acronym::from_str_unchecked("SV2") }
     }
+    /// The name of [`ScoreV2Mania`]
+    pub const fn name() -> &'static str {
+        "Score V2"
+    }
     /// Iterator of [`Acronym`] for mods that are incompatible with [`ScoreV2Mania`]
     pub fn incompatible_mods() -> impl Iterator<Item = Acronym> {
         [].into_iter()
@@ -5371,6 +15366,48 @@ impl ScoreV2Mania {
     pub const fn bits() -> u32 {
         536870912
     }
+    /// Whether [`ScoreV2Mania`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Mania`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer() -> bool {
+        true
+    }
+    /// Whether [`ScoreV2Mania`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Mania`] needs to be configured before it can be used
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Whether [`ScoreV2Mania`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// The [`SettingSchema`] for each setting of [`ScoreV2Mania`]
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
+    /// Replace all unset settings of [`ScoreV2Mania`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub const fn with_defaults_filled(self) -> Self {
+        self
+    }
+    /// The value of the setting with the given name if it is set
+    pub const fn setting(&self, _name: &str) -> Option<SettingSimple> {
+        None
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, _name: &str, _value: Option<SettingSimple>) -> bool {
+        false
+    }
 }
 impl UnknownMod {
     /// The default [`Acronym`] for an unknown mod without specific
@@ -5384,6 +15421,10 @@ impl UnknownMod {
     pub const fn incompatible_mods() -> std::iter::Empty<Acronym> {
         std::iter::empty()
     }
+    /// A custom name for any unknown mod
+    pub const fn name() -> &'static str {
+        "Unknown"
+    }
     /// A custom description for any unknown mod
     pub const fn description() -> &'static str {
         "Some unknown mod"
@@ -5392,6 +15433,30 @@ impl UnknownMod {
     pub const fn kind() -> GameModKind {
         GameModKind::System
     }
+    /// Returns `false`
+    pub const fn is_user_playable() -> bool {
+        false
+    }
+    /// Returns `false`
+    pub const fn is_valid_for_multiplayer() -> bool {
+        false
+    }
+    /// Returns `false`
+    pub const fn is_valid_for_multiplayer_as_free_mod() -> bool {
+        false
+    }
+    /// Returns `false`
+    pub const fn requires_configuration() -> bool {
+        false
+    }
+    /// Returns `false`
+    pub const fn is_always_valid_for_submission() -> bool {
+        false
+    }
+    /// Returns an empty slice
+    pub const fn settings_schema() -> &'static [SettingSchema] {
+        &[]
+    }
 }
 impl Default for UnknownMod {
     fn default() -> Self {
@@ -5479,6 +15544,232 @@ pub(crate) mod intermode {
     }
 }
 impl GameModIntermode {
+    /// All known [`GameModIntermode`]s i.e. every variant except `Unknown`, sorted by name
+    pub const ALL: &'static [Self] = &[
+        Self::AccuracyChallenge,
+        Self::AdaptiveSpeed,
+        Self::Alternate,
+        Self::ApproachDifferent,
+        Self::Autopilot,
+        Self::Autoplay,
+        Self::BarrelRoll,
+        Self::Blinds,
+        Self::Bloom,
+        Self::Bubbles,
+        Self::Cinema,
+        Self::Classic,
+        Self::ConstantSpeed,
+        Self::Cover,
+        Self::Daycore,
+        Self::Deflate,
+        Self::Depth,
+        Self::DifficultyAdjust,
+        Self::DoubleTime,
+        Self::DualStages,
+        Self::Easy,
+        Self::EightKeys,
+        Self::FadeIn,
+        Self::FiveKeys,
+        Self::Flashlight,
+        Self::FloatingFruits,
+        Self::FourKeys,
+        Self::FreezeFrame,
+        Self::Grow,
+        Self::HalfTime,
+        Self::HardRock,
+        Self::Hidden,
+        Self::HoldOff,
+        Self::Invert,
+        Self::Magnetised,
+        Self::Mirror,
+        Self::MovingFast,
+        Self::Muted,
+        Self::Nightcore,
+        Self::NineKeys,
+        Self::NoFail,
+        Self::NoRelease,
+        Self::NoScope,
+        Self::OneKey,
+        Self::Perfect,
+        Self::Random,
+        Self::Relax,
+        Self::Repel,
+        Self::ScoreV2,
+        Self::SevenKeys,
+        Self::SimplifiedRhythm,
+        Self::SingleTap,
+        Self::SixKeys,
+        Self::SpinIn,
+        Self::SpunOut,
+        Self::StrictTracking,
+        Self::SuddenDeath,
+        Self::Swap,
+        Self::Synesthesia,
+        Self::TargetPractice,
+        Self::TenKeys,
+        Self::ThreeKeys,
+        Self::TouchDevice,
+        Self::Traceable,
+        Self::Transform,
+        Self::TwoKeys,
+        Self::Wiggle,
+        Self::WindDown,
+        Self::WindUp,
+    ];
+    /// All [`GameModIntermode`]s that are available in the given mode,
+    /// in the same order as osu!lazer lists them
+    pub const fn all_for_mode(mode: GameMode) -> &'static [Self] {
+        match mode {
+            GameMode::Osu => &[
+                Self::Easy,
+                Self::NoFail,
+                Self::HalfTime,
+                Self::Daycore,
+                Self::HardRock,
+                Self::SuddenDeath,
+                Self::Perfect,
+                Self::DoubleTime,
+                Self::Nightcore,
+                Self::Hidden,
+                Self::Traceable,
+                Self::Flashlight,
+                Self::Blinds,
+                Self::StrictTracking,
+                Self::AccuracyChallenge,
+                Self::TargetPractice,
+                Self::DifficultyAdjust,
+                Self::Classic,
+                Self::Random,
+                Self::Mirror,
+                Self::Alternate,
+                Self::SingleTap,
+                Self::Autoplay,
+                Self::Cinema,
+                Self::Relax,
+                Self::Autopilot,
+                Self::SpunOut,
+                Self::Transform,
+                Self::Wiggle,
+                Self::SpinIn,
+                Self::Grow,
+                Self::Deflate,
+                Self::WindUp,
+                Self::WindDown,
+                Self::BarrelRoll,
+                Self::ApproachDifferent,
+                Self::Muted,
+                Self::NoScope,
+                Self::Magnetised,
+                Self::Repel,
+                Self::AdaptiveSpeed,
+                Self::FreezeFrame,
+                Self::Bubbles,
+                Self::Synesthesia,
+                Self::Depth,
+                Self::Bloom,
+                Self::TouchDevice,
+                Self::ScoreV2,
+            ],
+            GameMode::Taiko => &[
+                Self::Easy,
+                Self::NoFail,
+                Self::HalfTime,
+                Self::Daycore,
+                Self::SimplifiedRhythm,
+                Self::HardRock,
+                Self::SuddenDeath,
+                Self::Perfect,
+                Self::DoubleTime,
+                Self::Nightcore,
+                Self::Hidden,
+                Self::Flashlight,
+                Self::AccuracyChallenge,
+                Self::Random,
+                Self::DifficultyAdjust,
+                Self::Classic,
+                Self::Swap,
+                Self::SingleTap,
+                Self::ConstantSpeed,
+                Self::Autoplay,
+                Self::Cinema,
+                Self::Relax,
+                Self::WindUp,
+                Self::WindDown,
+                Self::Muted,
+                Self::AdaptiveSpeed,
+                Self::ScoreV2,
+            ],
+            GameMode::Catch => &[
+                Self::Easy,
+                Self::NoFail,
+                Self::HalfTime,
+                Self::Daycore,
+                Self::HardRock,
+                Self::SuddenDeath,
+                Self::Perfect,
+                Self::DoubleTime,
+                Self::Nightcore,
+                Self::Hidden,
+                Self::Flashlight,
+                Self::AccuracyChallenge,
+                Self::DifficultyAdjust,
+                Self::Classic,
+                Self::Mirror,
+                Self::Autoplay,
+                Self::Cinema,
+                Self::Relax,
+                Self::WindUp,
+                Self::WindDown,
+                Self::FloatingFruits,
+                Self::Muted,
+                Self::NoScope,
+                Self::MovingFast,
+                Self::ScoreV2,
+            ],
+            GameMode::Mania => &[
+                Self::Easy,
+                Self::NoFail,
+                Self::HalfTime,
+                Self::Daycore,
+                Self::NoRelease,
+                Self::HardRock,
+                Self::SuddenDeath,
+                Self::Perfect,
+                Self::DoubleTime,
+                Self::Nightcore,
+                Self::FadeIn,
+                Self::Hidden,
+                Self::Cover,
+                Self::Flashlight,
+                Self::AccuracyChallenge,
+                Self::Random,
+                Self::DualStages,
+                Self::Mirror,
+                Self::DifficultyAdjust,
+                Self::Classic,
+                Self::Invert,
+                Self::ConstantSpeed,
+                Self::HoldOff,
+                Self::OneKey,
+                Self::TwoKeys,
+                Self::ThreeKeys,
+                Self::FourKeys,
+                Self::FiveKeys,
+                Self::SixKeys,
+                Self::SevenKeys,
+                Self::EightKeys,
+                Self::NineKeys,
+                Self::TenKeys,
+                Self::Autoplay,
+                Self::Cinema,
+                Self::WindUp,
+                Self::WindDown,
+                Self::Muted,
+                Self::AdaptiveSpeed,
+                Self::ScoreV2,
+            ],
+        }
+    }
     /// The [`Acronym`] of this [`GameModIntermode`]
     pub const fn acronym(&self) -> Acronym {
         unsafe {
@@ -5556,6 +15847,81 @@ impl GameModIntermode {
             }
         }
     }
+    /// The name of this [`GameModIntermode`]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::AccuracyChallenge => "Accuracy Challenge",
+            Self::AdaptiveSpeed => "Adaptive Speed",
+            Self::Alternate => "Alternate",
+            Self::ApproachDifferent => "Approach Different",
+            Self::Autopilot => "Autopilot",
+            Self::Autoplay => "Autoplay",
+            Self::BarrelRoll => "Barrel Roll",
+            Self::Blinds => "Blinds",
+            Self::Bloom => "Bloom",
+            Self::Bubbles => "Bubbles",
+            Self::Cinema => "Cinema",
+            Self::Classic => "Classic",
+            Self::ConstantSpeed => "Constant Speed",
+            Self::Cover => "Cover",
+            Self::Daycore => "Daycore",
+            Self::Deflate => "Deflate",
+            Self::Depth => "Depth",
+            Self::DifficultyAdjust => "Difficulty Adjust",
+            Self::DoubleTime => "Double Time",
+            Self::DualStages => "Dual Stages",
+            Self::Easy => "Easy",
+            Self::EightKeys => "Eight Keys",
+            Self::FadeIn => "Fade In",
+            Self::FiveKeys => "Five Keys",
+            Self::Flashlight => "Flashlight",
+            Self::FloatingFruits => "Floating Fruits",
+            Self::FourKeys => "Four Keys",
+            Self::FreezeFrame => "Freeze Frame",
+            Self::Grow => "Grow",
+            Self::HalfTime => "Half Time",
+            Self::HardRock => "Hard Rock",
+            Self::Hidden => "Hidden",
+            Self::HoldOff => "Hold Off",
+            Self::Invert => "Invert",
+            Self::Magnetised => "Magnetised",
+            Self::Mirror => "Mirror",
+            Self::MovingFast => "Moving Fast",
+            Self::Muted => "Muted",
+            Self::Nightcore => "Nightcore",
+            Self::NineKeys => "Nine Keys",
+            Self::NoFail => "No Fail",
+            Self::NoRelease => "No Release",
+            Self::NoScope => "No Scope",
+            Self::OneKey => "One Key",
+            Self::Perfect => "Perfect",
+            Self::Random => "Random",
+            Self::Relax => "Relax",
+            Self::Repel => "Repel",
+            Self::ScoreV2 => "Score V2",
+            Self::SevenKeys => "Seven Keys",
+            Self::SimplifiedRhythm => "Simplified Rhythm",
+            Self::SingleTap => "Single Tap",
+            Self::SixKeys => "Six Keys",
+            Self::SpinIn => "Spin In",
+            Self::SpunOut => "Spun Out",
+            Self::StrictTracking => "Strict Tracking",
+            Self::SuddenDeath => "Sudden Death",
+            Self::Swap => "Swap",
+            Self::Synesthesia => "Synesthesia",
+            Self::TargetPractice => "Target Practice",
+            Self::TenKeys => "Ten Keys",
+            Self::ThreeKeys => "Three Keys",
+            Self::TouchDevice => "Touch Device",
+            Self::Traceable => "Traceable",
+            Self::Transform => "Transform",
+            Self::TwoKeys => "Two Keys",
+            Self::Wiggle => "Wiggle",
+            Self::WindDown => "Wind Down",
+            Self::WindUp => "Wind Up",
+            Self::Unknown(_) => UnknownMod::name(),
+        }
+    }
     /// Bit value of the [`GameModIntermode`]
     ///
     /// See <https://github.com/ppy/osu-api/wiki#mods>
@@ -5708,6 +16074,381 @@ impl GameModIntermode {
             Self::Unknown(_) => GameModKind::System,
         }
     }
+    /// Whether this [`GameModIntermode`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable(&self) -> bool {
+        match self {
+            Self::AccuracyChallenge => true,
+            Self::AdaptiveSpeed => true,
+            Self::Alternate => true,
+            Self::ApproachDifferent => true,
+            Self::Autopilot => true,
+            Self::Autoplay => false,
+            Self::BarrelRoll => true,
+            Self::Blinds => true,
+            Self::Bloom => true,
+            Self::Bubbles => true,
+            Self::Cinema => false,
+            Self::Classic => true,
+            Self::ConstantSpeed => true,
+            Self::Cover => true,
+            Self::Daycore => true,
+            Self::Deflate => true,
+            Self::Depth => true,
+            Self::DifficultyAdjust => true,
+            Self::DoubleTime => true,
+            Self::DualStages => true,
+            Self::Easy => true,
+            Self::EightKeys => true,
+            Self::FadeIn => true,
+            Self::FiveKeys => true,
+            Self::Flashlight => true,
+            Self::FloatingFruits => true,
+            Self::FourKeys => true,
+            Self::FreezeFrame => true,
+            Self::Grow => true,
+            Self::HalfTime => true,
+            Self::HardRock => true,
+            Self::Hidden => true,
+            Self::HoldOff => true,
+            Self::Invert => true,
+            Self::Magnetised => true,
+            Self::Mirror => true,
+            Self::MovingFast => true,
+            Self::Muted => true,
+            Self::Nightcore => true,
+            Self::NineKeys => true,
+            Self::NoFail => true,
+            Self::NoRelease => true,
+            Self::NoScope => true,
+            Self::OneKey => true,
+            Self::Perfect => true,
+            Self::Random => true,
+            Self::Relax => true,
+            Self::Repel => true,
+            Self::ScoreV2 => false,
+            Self::SevenKeys => true,
+            Self::SimplifiedRhythm => true,
+            Self::SingleTap => true,
+            Self::SixKeys => true,
+            Self::SpinIn => true,
+            Self::SpunOut => true,
+            Self::StrictTracking => true,
+            Self::SuddenDeath => true,
+            Self::Swap => true,
+            Self::Synesthesia => true,
+            Self::TargetPractice => true,
+            Self::TenKeys => true,
+            Self::ThreeKeys => true,
+            Self::TouchDevice => true,
+            Self::Traceable => true,
+            Self::Transform => true,
+            Self::TwoKeys => true,
+            Self::Wiggle => true,
+            Self::WindDown => true,
+            Self::WindUp => true,
+            Self::Unknown(_) => UnknownMod::is_user_playable(),
+        }
+    }
+    /// Whether this [`GameModIntermode`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer(&self) -> bool {
+        match self {
+            Self::AccuracyChallenge => true,
+            Self::AdaptiveSpeed => false,
+            Self::Alternate => true,
+            Self::ApproachDifferent => true,
+            Self::Autopilot => true,
+            Self::Autoplay => false,
+            Self::BarrelRoll => true,
+            Self::Blinds => true,
+            Self::Bloom => true,
+            Self::Bubbles => true,
+            Self::Cinema => false,
+            Self::Classic => true,
+            Self::ConstantSpeed => true,
+            Self::Cover => true,
+            Self::Daycore => true,
+            Self::Deflate => true,
+            Self::Depth => true,
+            Self::DifficultyAdjust => true,
+            Self::DoubleTime => true,
+            Self::DualStages => true,
+            Self::Easy => true,
+            Self::EightKeys => true,
+            Self::FadeIn => true,
+            Self::FiveKeys => true,
+            Self::Flashlight => true,
+            Self::FloatingFruits => true,
+            Self::FourKeys => true,
+            Self::FreezeFrame => true,
+            Self::Grow => true,
+            Self::HalfTime => true,
+            Self::HardRock => true,
+            Self::Hidden => true,
+            Self::HoldOff => true,
+            Self::Invert => true,
+            Self::Magnetised => true,
+            Self::Mirror => true,
+            Self::MovingFast => true,
+            Self::Muted => true,
+            Self::Nightcore => true,
+            Self::NineKeys => true,
+            Self::NoFail => true,
+            Self::NoRelease => true,
+            Self::NoScope => true,
+            Self::OneKey => true,
+            Self::Perfect => true,
+            Self::Random => true,
+            Self::Relax => true,
+            Self::Repel => true,
+            Self::ScoreV2 => true,
+            Self::SevenKeys => true,
+            Self::SimplifiedRhythm => true,
+            Self::SingleTap => true,
+            Self::SixKeys => true,
+            Self::SpinIn => true,
+            Self::SpunOut => true,
+            Self::StrictTracking => true,
+            Self::SuddenDeath => true,
+            Self::Swap => true,
+            Self::Synesthesia => true,
+            Self::TargetPractice => true,
+            Self::TenKeys => true,
+            Self::ThreeKeys => true,
+            Self::TouchDevice => false,
+            Self::Traceable => true,
+            Self::Transform => true,
+            Self::TwoKeys => true,
+            Self::Wiggle => true,
+            Self::WindDown => true,
+            Self::WindUp => true,
+            Self::Unknown(_) => UnknownMod::is_valid_for_multiplayer(),
+        }
+    }
+    /// Whether this [`GameModIntermode`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod(&self) -> bool {
+        match self {
+            Self::AccuracyChallenge => false,
+            Self::AdaptiveSpeed => false,
+            Self::Alternate => true,
+            Self::ApproachDifferent => true,
+            Self::Autopilot => true,
+            Self::Autoplay => false,
+            Self::BarrelRoll => true,
+            Self::Blinds => true,
+            Self::Bloom => true,
+            Self::Bubbles => true,
+            Self::Cinema => false,
+            Self::Classic => true,
+            Self::ConstantSpeed => true,
+            Self::Cover => true,
+            Self::Daycore => false,
+            Self::Deflate => true,
+            Self::Depth => true,
+            Self::DifficultyAdjust => true,
+            Self::DoubleTime => false,
+            Self::DualStages => true,
+            Self::Easy => true,
+            Self::EightKeys => false,
+            Self::FadeIn => true,
+            Self::FiveKeys => false,
+            Self::Flashlight => true,
+            Self::FloatingFruits => true,
+            Self::FourKeys => false,
+            Self::FreezeFrame => true,
+            Self::Grow => true,
+            Self::HalfTime => false,
+            Self::HardRock => true,
+            Self::Hidden => true,
+            Self::HoldOff => true,
+            Self::Invert => true,
+            Self::Magnetised => true,
+            Self::Mirror => true,
+            Self::MovingFast => true,
+            Self::Muted => true,
+            Self::Nightcore => false,
+            Self::NineKeys => false,
+            Self::NoFail => true,
+            Self::NoRelease => true,
+            Self::NoScope => true,
+            Self::OneKey => false,
+            Self::Perfect => true,
+            Self::Random => true,
+            Self::Relax => true,
+            Self::Repel => true,
+            Self::ScoreV2 => false,
+            Self::SevenKeys => false,
+            Self::SimplifiedRhythm => true,
+            Self::SingleTap => true,
+            Self::SixKeys => false,
+            Self::SpinIn => true,
+            Self::SpunOut => true,
+            Self::StrictTracking => true,
+            Self::SuddenDeath => true,
+            Self::Swap => true,
+            Self::Synesthesia => true,
+            Self::TargetPractice => true,
+            Self::TenKeys => false,
+            Self::ThreeKeys => false,
+            Self::TouchDevice => false,
+            Self::Traceable => true,
+            Self::Transform => true,
+            Self::TwoKeys => false,
+            Self::Wiggle => true,
+            Self::WindDown => false,
+            Self::WindUp => false,
+            Self::Unknown(_) => UnknownMod::is_valid_for_multiplayer_as_free_mod(),
+        }
+    }
+    /// Whether this [`GameModIntermode`] needs to be configured before it can be used
+    pub const fn requires_configuration(&self) -> bool {
+        match self {
+            Self::AccuracyChallenge => false,
+            Self::AdaptiveSpeed => false,
+            Self::Alternate => false,
+            Self::ApproachDifferent => false,
+            Self::Autopilot => false,
+            Self::Autoplay => false,
+            Self::BarrelRoll => false,
+            Self::Blinds => false,
+            Self::Bloom => false,
+            Self::Bubbles => false,
+            Self::Cinema => false,
+            Self::Classic => false,
+            Self::ConstantSpeed => false,
+            Self::Cover => false,
+            Self::Daycore => false,
+            Self::Deflate => false,
+            Self::Depth => false,
+            Self::DifficultyAdjust => true,
+            Self::DoubleTime => false,
+            Self::DualStages => false,
+            Self::Easy => false,
+            Self::EightKeys => false,
+            Self::FadeIn => false,
+            Self::FiveKeys => false,
+            Self::Flashlight => false,
+            Self::FloatingFruits => false,
+            Self::FourKeys => false,
+            Self::FreezeFrame => false,
+            Self::Grow => false,
+            Self::HalfTime => false,
+            Self::HardRock => false,
+            Self::Hidden => false,
+            Self::HoldOff => false,
+            Self::Invert => false,
+            Self::Magnetised => false,
+            Self::Mirror => false,
+            Self::MovingFast => false,
+            Self::Muted => false,
+            Self::Nightcore => false,
+            Self::NineKeys => false,
+            Self::NoFail => false,
+            Self::NoRelease => false,
+            Self::NoScope => false,
+            Self::OneKey => false,
+            Self::Perfect => false,
+            Self::Random => false,
+            Self::Relax => false,
+            Self::Repel => false,
+            Self::ScoreV2 => false,
+            Self::SevenKeys => false,
+            Self::SimplifiedRhythm => false,
+            Self::SingleTap => false,
+            Self::SixKeys => false,
+            Self::SpinIn => false,
+            Self::SpunOut => false,
+            Self::StrictTracking => false,
+            Self::SuddenDeath => false,
+            Self::Swap => false,
+            Self::Synesthesia => false,
+            Self::TargetPractice => false,
+            Self::TenKeys => false,
+            Self::ThreeKeys => false,
+            Self::TouchDevice => false,
+            Self::Traceable => false,
+            Self::Transform => false,
+            Self::TwoKeys => false,
+            Self::Wiggle => false,
+            Self::WindDown => false,
+            Self::WindUp => false,
+            Self::Unknown(_) => UnknownMod::requires_configuration(),
+        }
+    }
+    /// Whether this [`GameModIntermode`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission(&self) -> bool {
+        match self {
+            Self::AccuracyChallenge => false,
+            Self::AdaptiveSpeed => false,
+            Self::Alternate => false,
+            Self::ApproachDifferent => false,
+            Self::Autopilot => false,
+            Self::Autoplay => false,
+            Self::BarrelRoll => false,
+            Self::Blinds => false,
+            Self::Bloom => false,
+            Self::Bubbles => false,
+            Self::Cinema => false,
+            Self::Classic => false,
+            Self::ConstantSpeed => false,
+            Self::Cover => false,
+            Self::Daycore => false,
+            Self::Deflate => false,
+            Self::Depth => false,
+            Self::DifficultyAdjust => false,
+            Self::DoubleTime => false,
+            Self::DualStages => false,
+            Self::Easy => false,
+            Self::EightKeys => false,
+            Self::FadeIn => false,
+            Self::FiveKeys => false,
+            Self::Flashlight => false,
+            Self::FloatingFruits => false,
+            Self::FourKeys => false,
+            Self::FreezeFrame => false,
+            Self::Grow => false,
+            Self::HalfTime => false,
+            Self::HardRock => false,
+            Self::Hidden => false,
+            Self::HoldOff => false,
+            Self::Invert => false,
+            Self::Magnetised => false,
+            Self::Mirror => false,
+            Self::MovingFast => false,
+            Self::Muted => false,
+            Self::Nightcore => false,
+            Self::NineKeys => false,
+            Self::NoFail => false,
+            Self::NoRelease => false,
+            Self::NoScope => false,
+            Self::OneKey => false,
+            Self::Perfect => false,
+            Self::Random => false,
+            Self::Relax => false,
+            Self::Repel => false,
+            Self::ScoreV2 => false,
+            Self::SevenKeys => false,
+            Self::SimplifiedRhythm => false,
+            Self::SingleTap => false,
+            Self::SixKeys => false,
+            Self::SpinIn => false,
+            Self::SpunOut => false,
+            Self::StrictTracking => false,
+            Self::SuddenDeath => false,
+            Self::Swap => false,
+            Self::Synesthesia => false,
+            Self::TargetPractice => false,
+            Self::TenKeys => false,
+            Self::ThreeKeys => false,
+            Self::TouchDevice => true,
+            Self::Traceable => false,
+            Self::Transform => false,
+            Self::TwoKeys => false,
+            Self::Wiggle => false,
+            Self::WindDown => false,
+            Self::WindUp => false,
+            Self::Unknown(_) => UnknownMod::is_always_valid_for_submission(),
+        }
+    }
     /// Parse an [`Acronym`] into a [`GameModIntermode`]
     pub fn from_acronym(acronym: Acronym) -> Self {
         match acronym.as_str() {
@@ -5783,6 +16524,91 @@ impl GameModIntermode {
             _ => Self::Unknown(UnknownMod { acronym }),
         }
     }
+    /// Parse the name of a mod into a [`GameModIntermode`]
+    ///
+    /// Whitespace and casing are ignored so both `"DoubleTime"` and
+    /// `"Double Time"` are accepted.
+    ///
+    /// Returns `None` if the name does not belong to any known mod.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        match name.as_str() {
+            "accuracychallenge" => Some(Self::AccuracyChallenge),
+            "adaptivespeed" => Some(Self::AdaptiveSpeed),
+            "alternate" => Some(Self::Alternate),
+            "approachdifferent" => Some(Self::ApproachDifferent),
+            "autopilot" => Some(Self::Autopilot),
+            "autoplay" => Some(Self::Autoplay),
+            "barrelroll" => Some(Self::BarrelRoll),
+            "blinds" => Some(Self::Blinds),
+            "bloom" => Some(Self::Bloom),
+            "bubbles" => Some(Self::Bubbles),
+            "cinema" => Some(Self::Cinema),
+            "classic" => Some(Self::Classic),
+            "constantspeed" => Some(Self::ConstantSpeed),
+            "cover" => Some(Self::Cover),
+            "daycore" => Some(Self::Daycore),
+            "deflate" => Some(Self::Deflate),
+            "depth" => Some(Self::Depth),
+            "difficultyadjust" => Some(Self::DifficultyAdjust),
+            "doubletime" => Some(Self::DoubleTime),
+            "dualstages" => Some(Self::DualStages),
+            "easy" => Some(Self::Easy),
+            "eightkeys" => Some(Self::EightKeys),
+            "fadein" => Some(Self::FadeIn),
+            "fivekeys" => Some(Self::FiveKeys),
+            "flashlight" => Some(Self::Flashlight),
+            "floatingfruits" => Some(Self::FloatingFruits),
+            "fourkeys" => Some(Self::FourKeys),
+            "freezeframe" => Some(Self::FreezeFrame),
+            "grow" => Some(Self::Grow),
+            "halftime" => Some(Self::HalfTime),
+            "hardrock" => Some(Self::HardRock),
+            "hidden" => Some(Self::Hidden),
+            "holdoff" => Some(Self::HoldOff),
+            "invert" => Some(Self::Invert),
+            "magnetised" => Some(Self::Magnetised),
+            "mirror" => Some(Self::Mirror),
+            "movingfast" => Some(Self::MovingFast),
+            "muted" => Some(Self::Muted),
+            "nightcore" => Some(Self::Nightcore),
+            "ninekeys" => Some(Self::NineKeys),
+            "nofail" => Some(Self::NoFail),
+            "norelease" => Some(Self::NoRelease),
+            "noscope" => Some(Self::NoScope),
+            "onekey" => Some(Self::OneKey),
+            "perfect" => Some(Self::Perfect),
+            "random" => Some(Self::Random),
+            "relax" => Some(Self::Relax),
+            "repel" => Some(Self::Repel),
+            "scorev2" => Some(Self::ScoreV2),
+            "sevenkeys" => Some(Self::SevenKeys),
+            "simplifiedrhythm" => Some(Self::SimplifiedRhythm),
+            "singletap" => Some(Self::SingleTap),
+            "sixkeys" => Some(Self::SixKeys),
+            "spinin" => Some(Self::SpinIn),
+            "spunout" => Some(Self::SpunOut),
+            "stricttracking" => Some(Self::StrictTracking),
+            "suddendeath" => Some(Self::SuddenDeath),
+            "swap" => Some(Self::Swap),
+            "synesthesia" => Some(Self::Synesthesia),
+            "targetpractice" => Some(Self::TargetPractice),
+            "tenkeys" => Some(Self::TenKeys),
+            "threekeys" => Some(Self::ThreeKeys),
+            "touchdevice" => Some(Self::TouchDevice),
+            "traceable" => Some(Self::Traceable),
+            "transform" => Some(Self::Transform),
+            "twokeys" => Some(Self::TwoKeys),
+            "wiggle" => Some(Self::Wiggle),
+            "winddown" => Some(Self::WindDown),
+            "windup" => Some(Self::WindUp),
+            _ => None,
+        }
+    }
     /// Try to convert bitvalues into a [`GameModIntermode`]
     pub const fn try_from_bits(bits: u32) -> Option<Self> {
         match bits {
@@ -5827,6 +16653,173 @@ impl GameModIntermode {
             settings: HashMap::new(),
         }
     }
+    /// The [`SettingSchema`] for each setting of this [`GameModIntermode`]
+    /// in the given [`GameMode`].
+    ///
+    /// Returns `None` if the mod is not available in the mode.
+    pub const fn settings_schema(&self, mode: GameMode) -> Option<&'static [SettingSchema]> {
+        match (self, mode) {
+            (Self::Easy, GameMode::Osu) => Some(EasyOsu::settings_schema()),
+            (Self::NoFail, GameMode::Osu) => Some(NoFailOsu::settings_schema()),
+            (Self::HalfTime, GameMode::Osu) => Some(HalfTimeOsu::settings_schema()),
+            (Self::Daycore, GameMode::Osu) => Some(DaycoreOsu::settings_schema()),
+            (Self::HardRock, GameMode::Osu) => Some(HardRockOsu::settings_schema()),
+            (Self::SuddenDeath, GameMode::Osu) => Some(SuddenDeathOsu::settings_schema()),
+            (Self::Perfect, GameMode::Osu) => Some(PerfectOsu::settings_schema()),
+            (Self::DoubleTime, GameMode::Osu) => Some(DoubleTimeOsu::settings_schema()),
+            (Self::Nightcore, GameMode::Osu) => Some(NightcoreOsu::settings_schema()),
+            (Self::Hidden, GameMode::Osu) => Some(HiddenOsu::settings_schema()),
+            (Self::Traceable, GameMode::Osu) => Some(TraceableOsu::settings_schema()),
+            (Self::Flashlight, GameMode::Osu) => Some(FlashlightOsu::settings_schema()),
+            (Self::Blinds, GameMode::Osu) => Some(BlindsOsu::settings_schema()),
+            (Self::StrictTracking, GameMode::Osu) => Some(StrictTrackingOsu::settings_schema()),
+            (Self::AccuracyChallenge, GameMode::Osu) => {
+                Some(AccuracyChallengeOsu::settings_schema())
+            }
+            (Self::TargetPractice, GameMode::Osu) => Some(TargetPracticeOsu::settings_schema()),
+            (Self::DifficultyAdjust, GameMode::Osu) => Some(DifficultyAdjustOsu::settings_schema()),
+            (Self::Classic, GameMode::Osu) => Some(ClassicOsu::settings_schema()),
+            (Self::Random, GameMode::Osu) => Some(RandomOsu::settings_schema()),
+            (Self::Mirror, GameMode::Osu) => Some(MirrorOsu::settings_schema()),
+            (Self::Alternate, GameMode::Osu) => Some(AlternateOsu::settings_schema()),
+            (Self::SingleTap, GameMode::Osu) => Some(SingleTapOsu::settings_schema()),
+            (Self::Autoplay, GameMode::Osu) => Some(AutoplayOsu::settings_schema()),
+            (Self::Cinema, GameMode::Osu) => Some(CinemaOsu::settings_schema()),
+            (Self::Relax, GameMode::Osu) => Some(RelaxOsu::settings_schema()),
+            (Self::Autopilot, GameMode::Osu) => Some(AutopilotOsu::settings_schema()),
+            (Self::SpunOut, GameMode::Osu) => Some(SpunOutOsu::settings_schema()),
+            (Self::Transform, GameMode::Osu) => Some(TransformOsu::settings_schema()),
+            (Self::Wiggle, GameMode::Osu) => Some(WiggleOsu::settings_schema()),
+            (Self::SpinIn, GameMode::Osu) => Some(SpinInOsu::settings_schema()),
+            (Self::Grow, GameMode::Osu) => Some(GrowOsu::settings_schema()),
+            (Self::Deflate, GameMode::Osu) => Some(DeflateOsu::settings_schema()),
+            (Self::WindUp, GameMode::Osu) => Some(WindUpOsu::settings_schema()),
+            (Self::WindDown, GameMode::Osu) => Some(WindDownOsu::settings_schema()),
+            (Self::BarrelRoll, GameMode::Osu) => Some(BarrelRollOsu::settings_schema()),
+            (Self::ApproachDifferent, GameMode::Osu) => {
+                Some(ApproachDifferentOsu::settings_schema())
+            }
+            (Self::Muted, GameMode::Osu) => Some(MutedOsu::settings_schema()),
+            (Self::NoScope, GameMode::Osu) => Some(NoScopeOsu::settings_schema()),
+            (Self::Magnetised, GameMode::Osu) => Some(MagnetisedOsu::settings_schema()),
+            (Self::Repel, GameMode::Osu) => Some(RepelOsu::settings_schema()),
+            (Self::AdaptiveSpeed, GameMode::Osu) => Some(AdaptiveSpeedOsu::settings_schema()),
+            (Self::FreezeFrame, GameMode::Osu) => Some(FreezeFrameOsu::settings_schema()),
+            (Self::Bubbles, GameMode::Osu) => Some(BubblesOsu::settings_schema()),
+            (Self::Synesthesia, GameMode::Osu) => Some(SynesthesiaOsu::settings_schema()),
+            (Self::Depth, GameMode::Osu) => Some(DepthOsu::settings_schema()),
+            (Self::Bloom, GameMode::Osu) => Some(BloomOsu::settings_schema()),
+            (Self::TouchDevice, GameMode::Osu) => Some(TouchDeviceOsu::settings_schema()),
+            (Self::ScoreV2, GameMode::Osu) => Some(ScoreV2Osu::settings_schema()),
+            (Self::Easy, GameMode::Taiko) => Some(EasyTaiko::settings_schema()),
+            (Self::NoFail, GameMode::Taiko) => Some(NoFailTaiko::settings_schema()),
+            (Self::HalfTime, GameMode::Taiko) => Some(HalfTimeTaiko::settings_schema()),
+            (Self::Daycore, GameMode::Taiko) => Some(DaycoreTaiko::settings_schema()),
+            (Self::SimplifiedRhythm, GameMode::Taiko) => {
+                Some(SimplifiedRhythmTaiko::settings_schema())
+            }
+            (Self::HardRock, GameMode::Taiko) => Some(HardRockTaiko::settings_schema()),
+            (Self::SuddenDeath, GameMode::Taiko) => Some(SuddenDeathTaiko::settings_schema()),
+            (Self::Perfect, GameMode::Taiko) => Some(PerfectTaiko::settings_schema()),
+            (Self::DoubleTime, GameMode::Taiko) => Some(DoubleTimeTaiko::settings_schema()),
+            (Self::Nightcore, GameMode::Taiko) => Some(NightcoreTaiko::settings_schema()),
+            (Self::Hidden, GameMode::Taiko) => Some(HiddenTaiko::settings_schema()),
+            (Self::Flashlight, GameMode::Taiko) => Some(FlashlightTaiko::settings_schema()),
+            (Self::AccuracyChallenge, GameMode::Taiko) => {
+                Some(AccuracyChallengeTaiko::settings_schema())
+            }
+            (Self::Random, GameMode::Taiko) => Some(RandomTaiko::settings_schema()),
+            (Self::DifficultyAdjust, GameMode::Taiko) => {
+                Some(DifficultyAdjustTaiko::settings_schema())
+            }
+            (Self::Classic, GameMode::Taiko) => Some(ClassicTaiko::settings_schema()),
+            (Self::Swap, GameMode::Taiko) => Some(SwapTaiko::settings_schema()),
+            (Self::SingleTap, GameMode::Taiko) => Some(SingleTapTaiko::settings_schema()),
+            (Self::ConstantSpeed, GameMode::Taiko) => Some(ConstantSpeedTaiko::settings_schema()),
+            (Self::Autoplay, GameMode::Taiko) => Some(AutoplayTaiko::settings_schema()),
+            (Self::Cinema, GameMode::Taiko) => Some(CinemaTaiko::settings_schema()),
+            (Self::Relax, GameMode::Taiko) => Some(RelaxTaiko::settings_schema()),
+            (Self::WindUp, GameMode::Taiko) => Some(WindUpTaiko::settings_schema()),
+            (Self::WindDown, GameMode::Taiko) => Some(WindDownTaiko::settings_schema()),
+            (Self::Muted, GameMode::Taiko) => Some(MutedTaiko::settings_schema()),
+            (Self::AdaptiveSpeed, GameMode::Taiko) => Some(AdaptiveSpeedTaiko::settings_schema()),
+            (Self::ScoreV2, GameMode::Taiko) => Some(ScoreV2Taiko::settings_schema()),
+            (Self::Easy, GameMode::Catch) => Some(EasyCatch::settings_schema()),
+            (Self::NoFail, GameMode::Catch) => Some(NoFailCatch::settings_schema()),
+            (Self::HalfTime, GameMode::Catch) => Some(HalfTimeCatch::settings_schema()),
+            (Self::Daycore, GameMode::Catch) => Some(DaycoreCatch::settings_schema()),
+            (Self::HardRock, GameMode::Catch) => Some(HardRockCatch::settings_schema()),
+            (Self::SuddenDeath, GameMode::Catch) => Some(SuddenDeathCatch::settings_schema()),
+            (Self::Perfect, GameMode::Catch) => Some(PerfectCatch::settings_schema()),
+            (Self::DoubleTime, GameMode::Catch) => Some(DoubleTimeCatch::settings_schema()),
+            (Self::Nightcore, GameMode::Catch) => Some(NightcoreCatch::settings_schema()),
+            (Self::Hidden, GameMode::Catch) => Some(HiddenCatch::settings_schema()),
+            (Self::Flashlight, GameMode::Catch) => Some(FlashlightCatch::settings_schema()),
+            (Self::AccuracyChallenge, GameMode::Catch) => {
+                Some(AccuracyChallengeCatch::settings_schema())
+            }
+            (Self::DifficultyAdjust, GameMode::Catch) => {
+                Some(DifficultyAdjustCatch::settings_schema())
+            }
+            (Self::Classic, GameMode::Catch) => Some(ClassicCatch::settings_schema()),
+            (Self::Mirror, GameMode::Catch) => Some(MirrorCatch::settings_schema()),
+            (Self::Autoplay, GameMode::Catch) => Some(AutoplayCatch::settings_schema()),
+            (Self::Cinema, GameMode::Catch) => Some(CinemaCatch::settings_schema()),
+            (Self::Relax, GameMode::Catch) => Some(RelaxCatch::settings_schema()),
+            (Self::WindUp, GameMode::Catch) => Some(WindUpCatch::settings_schema()),
+            (Self::WindDown, GameMode::Catch) => Some(WindDownCatch::settings_schema()),
+            (Self::FloatingFruits, GameMode::Catch) => Some(FloatingFruitsCatch::settings_schema()),
+            (Self::Muted, GameMode::Catch) => Some(MutedCatch::settings_schema()),
+            (Self::NoScope, GameMode::Catch) => Some(NoScopeCatch::settings_schema()),
+            (Self::MovingFast, GameMode::Catch) => Some(MovingFastCatch::settings_schema()),
+            (Self::ScoreV2, GameMode::Catch) => Some(ScoreV2Catch::settings_schema()),
+            (Self::Easy, GameMode::Mania) => Some(EasyMania::settings_schema()),
+            (Self::NoFail, GameMode::Mania) => Some(NoFailMania::settings_schema()),
+            (Self::HalfTime, GameMode::Mania) => Some(HalfTimeMania::settings_schema()),
+            (Self::Daycore, GameMode::Mania) => Some(DaycoreMania::settings_schema()),
+            (Self::NoRelease, GameMode::Mania) => Some(NoReleaseMania::settings_schema()),
+            (Self::HardRock, GameMode::Mania) => Some(HardRockMania::settings_schema()),
+            (Self::SuddenDeath, GameMode::Mania) => Some(SuddenDeathMania::settings_schema()),
+            (Self::Perfect, GameMode::Mania) => Some(PerfectMania::settings_schema()),
+            (Self::DoubleTime, GameMode::Mania) => Some(DoubleTimeMania::settings_schema()),
+            (Self::Nightcore, GameMode::Mania) => Some(NightcoreMania::settings_schema()),
+            (Self::FadeIn, GameMode::Mania) => Some(FadeInMania::settings_schema()),
+            (Self::Hidden, GameMode::Mania) => Some(HiddenMania::settings_schema()),
+            (Self::Cover, GameMode::Mania) => Some(CoverMania::settings_schema()),
+            (Self::Flashlight, GameMode::Mania) => Some(FlashlightMania::settings_schema()),
+            (Self::AccuracyChallenge, GameMode::Mania) => {
+                Some(AccuracyChallengeMania::settings_schema())
+            }
+            (Self::Random, GameMode::Mania) => Some(RandomMania::settings_schema()),
+            (Self::DualStages, GameMode::Mania) => Some(DualStagesMania::settings_schema()),
+            (Self::Mirror, GameMode::Mania) => Some(MirrorMania::settings_schema()),
+            (Self::DifficultyAdjust, GameMode::Mania) => {
+                Some(DifficultyAdjustMania::settings_schema())
+            }
+            (Self::Classic, GameMode::Mania) => Some(ClassicMania::settings_schema()),
+            (Self::Invert, GameMode::Mania) => Some(InvertMania::settings_schema()),
+            (Self::ConstantSpeed, GameMode::Mania) => Some(ConstantSpeedMania::settings_schema()),
+            (Self::HoldOff, GameMode::Mania) => Some(HoldOffMania::settings_schema()),
+            (Self::OneKey, GameMode::Mania) => Some(OneKeyMania::settings_schema()),
+            (Self::TwoKeys, GameMode::Mania) => Some(TwoKeysMania::settings_schema()),
+            (Self::ThreeKeys, GameMode::Mania) => Some(ThreeKeysMania::settings_schema()),
+            (Self::FourKeys, GameMode::Mania) => Some(FourKeysMania::settings_schema()),
+            (Self::FiveKeys, GameMode::Mania) => Some(FiveKeysMania::settings_schema()),
+            (Self::SixKeys, GameMode::Mania) => Some(SixKeysMania::settings_schema()),
+            (Self::SevenKeys, GameMode::Mania) => Some(SevenKeysMania::settings_schema()),
+            (Self::EightKeys, GameMode::Mania) => Some(EightKeysMania::settings_schema()),
+            (Self::NineKeys, GameMode::Mania) => Some(NineKeysMania::settings_schema()),
+            (Self::TenKeys, GameMode::Mania) => Some(TenKeysMania::settings_schema()),
+            (Self::Autoplay, GameMode::Mania) => Some(AutoplayMania::settings_schema()),
+            (Self::Cinema, GameMode::Mania) => Some(CinemaMania::settings_schema()),
+            (Self::WindUp, GameMode::Mania) => Some(WindUpMania::settings_schema()),
+            (Self::WindDown, GameMode::Mania) => Some(WindDownMania::settings_schema()),
+            (Self::Muted, GameMode::Mania) => Some(MutedMania::settings_schema()),
+            (Self::AdaptiveSpeed, GameMode::Mania) => Some(AdaptiveSpeedMania::settings_schema()),
+            (Self::ScoreV2, GameMode::Mania) => Some(ScoreV2Mania::settings_schema()),
+            _ => None,
+        }
+    }
 }
 impl From<GameModIntermode> for GameModSimple {
     fn from(gamemod: GameModIntermode) -> Self {
@@ -6323,6 +17316,152 @@ impl GameMod {
             | Self::UnknownMania(m) => m.acronym(),
         }
     }
+    /// The name of this [`GameMod`]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::name(),
+            Self::NoFailOsu(_) => NoFailOsu::name(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::name(),
+            Self::DaycoreOsu(_) => DaycoreOsu::name(),
+            Self::HardRockOsu(_) => HardRockOsu::name(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::name(),
+            Self::PerfectOsu(_) => PerfectOsu::name(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::name(),
+            Self::NightcoreOsu(_) => NightcoreOsu::name(),
+            Self::HiddenOsu(_) => HiddenOsu::name(),
+            Self::TraceableOsu(_) => TraceableOsu::name(),
+            Self::FlashlightOsu(_) => FlashlightOsu::name(),
+            Self::BlindsOsu(_) => BlindsOsu::name(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::name(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::name(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::name(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::name(),
+            Self::ClassicOsu(_) => ClassicOsu::name(),
+            Self::RandomOsu(_) => RandomOsu::name(),
+            Self::MirrorOsu(_) => MirrorOsu::name(),
+            Self::AlternateOsu(_) => AlternateOsu::name(),
+            Self::SingleTapOsu(_) => SingleTapOsu::name(),
+            Self::AutoplayOsu(_) => AutoplayOsu::name(),
+            Self::CinemaOsu(_) => CinemaOsu::name(),
+            Self::RelaxOsu(_) => RelaxOsu::name(),
+            Self::AutopilotOsu(_) => AutopilotOsu::name(),
+            Self::SpunOutOsu(_) => SpunOutOsu::name(),
+            Self::TransformOsu(_) => TransformOsu::name(),
+            Self::WiggleOsu(_) => WiggleOsu::name(),
+            Self::SpinInOsu(_) => SpinInOsu::name(),
+            Self::GrowOsu(_) => GrowOsu::name(),
+            Self::DeflateOsu(_) => DeflateOsu::name(),
+            Self::WindUpOsu(_) => WindUpOsu::name(),
+            Self::WindDownOsu(_) => WindDownOsu::name(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::name(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::name(),
+            Self::MutedOsu(_) => MutedOsu::name(),
+            Self::NoScopeOsu(_) => NoScopeOsu::name(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::name(),
+            Self::RepelOsu(_) => RepelOsu::name(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::name(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::name(),
+            Self::BubblesOsu(_) => BubblesOsu::name(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::name(),
+            Self::DepthOsu(_) => DepthOsu::name(),
+            Self::BloomOsu(_) => BloomOsu::name(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::name(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::name(),
+            Self::EasyTaiko(_) => EasyTaiko::name(),
+            Self::NoFailTaiko(_) => NoFailTaiko::name(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::name(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::name(),
+            Self::SimplifiedRhythmTaiko(_) => SimplifiedRhythmTaiko::name(),
+            Self::HardRockTaiko(_) => HardRockTaiko::name(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::name(),
+            Self::PerfectTaiko(_) => PerfectTaiko::name(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::name(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::name(),
+            Self::HiddenTaiko(_) => HiddenTaiko::name(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::name(),
+            Self::AccuracyChallengeTaiko(_) => AccuracyChallengeTaiko::name(),
+            Self::RandomTaiko(_) => RandomTaiko::name(),
+            Self::DifficultyAdjustTaiko(_) => DifficultyAdjustTaiko::name(),
+            Self::ClassicTaiko(_) => ClassicTaiko::name(),
+            Self::SwapTaiko(_) => SwapTaiko::name(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::name(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::name(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::name(),
+            Self::CinemaTaiko(_) => CinemaTaiko::name(),
+            Self::RelaxTaiko(_) => RelaxTaiko::name(),
+            Self::WindUpTaiko(_) => WindUpTaiko::name(),
+            Self::WindDownTaiko(_) => WindDownTaiko::name(),
+            Self::MutedTaiko(_) => MutedTaiko::name(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::name(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::name(),
+            Self::EasyCatch(_) => EasyCatch::name(),
+            Self::NoFailCatch(_) => NoFailCatch::name(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::name(),
+            Self::DaycoreCatch(_) => DaycoreCatch::name(),
+            Self::HardRockCatch(_) => HardRockCatch::name(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::name(),
+            Self::PerfectCatch(_) => PerfectCatch::name(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::name(),
+            Self::NightcoreCatch(_) => NightcoreCatch::name(),
+            Self::HiddenCatch(_) => HiddenCatch::name(),
+            Self::FlashlightCatch(_) => FlashlightCatch::name(),
+            Self::AccuracyChallengeCatch(_) => AccuracyChallengeCatch::name(),
+            Self::DifficultyAdjustCatch(_) => DifficultyAdjustCatch::name(),
+            Self::ClassicCatch(_) => ClassicCatch::name(),
+            Self::MirrorCatch(_) => MirrorCatch::name(),
+            Self::AutoplayCatch(_) => AutoplayCatch::name(),
+            Self::CinemaCatch(_) => CinemaCatch::name(),
+            Self::RelaxCatch(_) => RelaxCatch::name(),
+            Self::WindUpCatch(_) => WindUpCatch::name(),
+            Self::WindDownCatch(_) => WindDownCatch::name(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::name(),
+            Self::MutedCatch(_) => MutedCatch::name(),
+            Self::NoScopeCatch(_) => NoScopeCatch::name(),
+            Self::MovingFastCatch(_) => MovingFastCatch::name(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::name(),
+            Self::EasyMania(_) => EasyMania::name(),
+            Self::NoFailMania(_) => NoFailMania::name(),
+            Self::HalfTimeMania(_) => HalfTimeMania::name(),
+            Self::DaycoreMania(_) => DaycoreMania::name(),
+            Self::NoReleaseMania(_) => NoReleaseMania::name(),
+            Self::HardRockMania(_) => HardRockMania::name(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::name(),
+            Self::PerfectMania(_) => PerfectMania::name(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::name(),
+            Self::NightcoreMania(_) => NightcoreMania::name(),
+            Self::FadeInMania(_) => FadeInMania::name(),
+            Self::HiddenMania(_) => HiddenMania::name(),
+            Self::CoverMania(_) => CoverMania::name(),
+            Self::FlashlightMania(_) => FlashlightMania::name(),
+            Self::AccuracyChallengeMania(_) => AccuracyChallengeMania::name(),
+            Self::RandomMania(_) => RandomMania::name(),
+            Self::DualStagesMania(_) => DualStagesMania::name(),
+            Self::MirrorMania(_) => MirrorMania::name(),
+            Self::DifficultyAdjustMania(_) => DifficultyAdjustMania::name(),
+            Self::ClassicMania(_) => ClassicMania::name(),
+            Self::InvertMania(_) => InvertMania::name(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::name(),
+            Self::HoldOffMania(_) => HoldOffMania::name(),
+            Self::OneKeyMania(_) => OneKeyMania::name(),
+            Self::TwoKeysMania(_) => TwoKeysMania::name(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::name(),
+            Self::FourKeysMania(_) => FourKeysMania::name(),
+            Self::FiveKeysMania(_) => FiveKeysMania::name(),
+            Self::SixKeysMania(_) => SixKeysMania::name(),
+            Self::SevenKeysMania(_) => SevenKeysMania::name(),
+            Self::EightKeysMania(_) => EightKeysMania::name(),
+            Self::NineKeysMania(_) => NineKeysMania::name(),
+            Self::TenKeysMania(_) => TenKeysMania::name(),
+            Self::AutoplayMania(_) => AutoplayMania::name(),
+            Self::CinemaMania(_) => CinemaMania::name(),
+            Self::WindUpMania(_) => WindUpMania::name(),
+            Self::WindDownMania(_) => WindDownMania::name(),
+            Self::MutedMania(_) => MutedMania::name(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::name(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::name(),
+            _ => UnknownMod::name(),
+        }
+    }
     /// List of [`Acronym`] for mods that are incompatible with this [`GameMod`]
     pub fn incompatible_mods(&self) -> Box<[Acronym]> {
         match self {
@@ -6851,6 +17990,780 @@ impl GameMod {
             _ => None,
         }
     }
+    /// Whether this [`GameMod`] is playable by a user i.e. the user interacts with the game
+    pub const fn is_user_playable(&self) -> bool {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::is_user_playable(),
+            Self::NoFailOsu(_) => NoFailOsu::is_user_playable(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::is_user_playable(),
+            Self::DaycoreOsu(_) => DaycoreOsu::is_user_playable(),
+            Self::HardRockOsu(_) => HardRockOsu::is_user_playable(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::is_user_playable(),
+            Self::PerfectOsu(_) => PerfectOsu::is_user_playable(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::is_user_playable(),
+            Self::NightcoreOsu(_) => NightcoreOsu::is_user_playable(),
+            Self::HiddenOsu(_) => HiddenOsu::is_user_playable(),
+            Self::TraceableOsu(_) => TraceableOsu::is_user_playable(),
+            Self::FlashlightOsu(_) => FlashlightOsu::is_user_playable(),
+            Self::BlindsOsu(_) => BlindsOsu::is_user_playable(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::is_user_playable(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::is_user_playable(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::is_user_playable(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::is_user_playable(),
+            Self::ClassicOsu(_) => ClassicOsu::is_user_playable(),
+            Self::RandomOsu(_) => RandomOsu::is_user_playable(),
+            Self::MirrorOsu(_) => MirrorOsu::is_user_playable(),
+            Self::AlternateOsu(_) => AlternateOsu::is_user_playable(),
+            Self::SingleTapOsu(_) => SingleTapOsu::is_user_playable(),
+            Self::AutoplayOsu(_) => AutoplayOsu::is_user_playable(),
+            Self::CinemaOsu(_) => CinemaOsu::is_user_playable(),
+            Self::RelaxOsu(_) => RelaxOsu::is_user_playable(),
+            Self::AutopilotOsu(_) => AutopilotOsu::is_user_playable(),
+            Self::SpunOutOsu(_) => SpunOutOsu::is_user_playable(),
+            Self::TransformOsu(_) => TransformOsu::is_user_playable(),
+            Self::WiggleOsu(_) => WiggleOsu::is_user_playable(),
+            Self::SpinInOsu(_) => SpinInOsu::is_user_playable(),
+            Self::GrowOsu(_) => GrowOsu::is_user_playable(),
+            Self::DeflateOsu(_) => DeflateOsu::is_user_playable(),
+            Self::WindUpOsu(_) => WindUpOsu::is_user_playable(),
+            Self::WindDownOsu(_) => WindDownOsu::is_user_playable(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::is_user_playable(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::is_user_playable(),
+            Self::MutedOsu(_) => MutedOsu::is_user_playable(),
+            Self::NoScopeOsu(_) => NoScopeOsu::is_user_playable(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::is_user_playable(),
+            Self::RepelOsu(_) => RepelOsu::is_user_playable(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::is_user_playable(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::is_user_playable(),
+            Self::BubblesOsu(_) => BubblesOsu::is_user_playable(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::is_user_playable(),
+            Self::DepthOsu(_) => DepthOsu::is_user_playable(),
+            Self::BloomOsu(_) => BloomOsu::is_user_playable(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::is_user_playable(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::is_user_playable(),
+            Self::EasyTaiko(_) => EasyTaiko::is_user_playable(),
+            Self::NoFailTaiko(_) => NoFailTaiko::is_user_playable(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::is_user_playable(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::is_user_playable(),
+            Self::SimplifiedRhythmTaiko(_) => SimplifiedRhythmTaiko::is_user_playable(),
+            Self::HardRockTaiko(_) => HardRockTaiko::is_user_playable(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::is_user_playable(),
+            Self::PerfectTaiko(_) => PerfectTaiko::is_user_playable(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::is_user_playable(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::is_user_playable(),
+            Self::HiddenTaiko(_) => HiddenTaiko::is_user_playable(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::is_user_playable(),
+            Self::AccuracyChallengeTaiko(_) => AccuracyChallengeTaiko::is_user_playable(),
+            Self::RandomTaiko(_) => RandomTaiko::is_user_playable(),
+            Self::DifficultyAdjustTaiko(_) => DifficultyAdjustTaiko::is_user_playable(),
+            Self::ClassicTaiko(_) => ClassicTaiko::is_user_playable(),
+            Self::SwapTaiko(_) => SwapTaiko::is_user_playable(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::is_user_playable(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::is_user_playable(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::is_user_playable(),
+            Self::CinemaTaiko(_) => CinemaTaiko::is_user_playable(),
+            Self::RelaxTaiko(_) => RelaxTaiko::is_user_playable(),
+            Self::WindUpTaiko(_) => WindUpTaiko::is_user_playable(),
+            Self::WindDownTaiko(_) => WindDownTaiko::is_user_playable(),
+            Self::MutedTaiko(_) => MutedTaiko::is_user_playable(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::is_user_playable(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::is_user_playable(),
+            Self::EasyCatch(_) => EasyCatch::is_user_playable(),
+            Self::NoFailCatch(_) => NoFailCatch::is_user_playable(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::is_user_playable(),
+            Self::DaycoreCatch(_) => DaycoreCatch::is_user_playable(),
+            Self::HardRockCatch(_) => HardRockCatch::is_user_playable(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::is_user_playable(),
+            Self::PerfectCatch(_) => PerfectCatch::is_user_playable(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::is_user_playable(),
+            Self::NightcoreCatch(_) => NightcoreCatch::is_user_playable(),
+            Self::HiddenCatch(_) => HiddenCatch::is_user_playable(),
+            Self::FlashlightCatch(_) => FlashlightCatch::is_user_playable(),
+            Self::AccuracyChallengeCatch(_) => AccuracyChallengeCatch::is_user_playable(),
+            Self::DifficultyAdjustCatch(_) => DifficultyAdjustCatch::is_user_playable(),
+            Self::ClassicCatch(_) => ClassicCatch::is_user_playable(),
+            Self::MirrorCatch(_) => MirrorCatch::is_user_playable(),
+            Self::AutoplayCatch(_) => AutoplayCatch::is_user_playable(),
+            Self::CinemaCatch(_) => CinemaCatch::is_user_playable(),
+            Self::RelaxCatch(_) => RelaxCatch::is_user_playable(),
+            Self::WindUpCatch(_) => WindUpCatch::is_user_playable(),
+            Self::WindDownCatch(_) => WindDownCatch::is_user_playable(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::is_user_playable(),
+            Self::MutedCatch(_) => MutedCatch::is_user_playable(),
+            Self::NoScopeCatch(_) => NoScopeCatch::is_user_playable(),
+            Self::MovingFastCatch(_) => MovingFastCatch::is_user_playable(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::is_user_playable(),
+            Self::EasyMania(_) => EasyMania::is_user_playable(),
+            Self::NoFailMania(_) => NoFailMania::is_user_playable(),
+            Self::HalfTimeMania(_) => HalfTimeMania::is_user_playable(),
+            Self::DaycoreMania(_) => DaycoreMania::is_user_playable(),
+            Self::NoReleaseMania(_) => NoReleaseMania::is_user_playable(),
+            Self::HardRockMania(_) => HardRockMania::is_user_playable(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::is_user_playable(),
+            Self::PerfectMania(_) => PerfectMania::is_user_playable(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::is_user_playable(),
+            Self::NightcoreMania(_) => NightcoreMania::is_user_playable(),
+            Self::FadeInMania(_) => FadeInMania::is_user_playable(),
+            Self::HiddenMania(_) => HiddenMania::is_user_playable(),
+            Self::CoverMania(_) => CoverMania::is_user_playable(),
+            Self::FlashlightMania(_) => FlashlightMania::is_user_playable(),
+            Self::AccuracyChallengeMania(_) => AccuracyChallengeMania::is_user_playable(),
+            Self::RandomMania(_) => RandomMania::is_user_playable(),
+            Self::DualStagesMania(_) => DualStagesMania::is_user_playable(),
+            Self::MirrorMania(_) => MirrorMania::is_user_playable(),
+            Self::DifficultyAdjustMania(_) => DifficultyAdjustMania::is_user_playable(),
+            Self::ClassicMania(_) => ClassicMania::is_user_playable(),
+            Self::InvertMania(_) => InvertMania::is_user_playable(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::is_user_playable(),
+            Self::HoldOffMania(_) => HoldOffMania::is_user_playable(),
+            Self::OneKeyMania(_) => OneKeyMania::is_user_playable(),
+            Self::TwoKeysMania(_) => TwoKeysMania::is_user_playable(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::is_user_playable(),
+            Self::FourKeysMania(_) => FourKeysMania::is_user_playable(),
+            Self::FiveKeysMania(_) => FiveKeysMania::is_user_playable(),
+            Self::SixKeysMania(_) => SixKeysMania::is_user_playable(),
+            Self::SevenKeysMania(_) => SevenKeysMania::is_user_playable(),
+            Self::EightKeysMania(_) => EightKeysMania::is_user_playable(),
+            Self::NineKeysMania(_) => NineKeysMania::is_user_playable(),
+            Self::TenKeysMania(_) => TenKeysMania::is_user_playable(),
+            Self::AutoplayMania(_) => AutoplayMania::is_user_playable(),
+            Self::CinemaMania(_) => CinemaMania::is_user_playable(),
+            Self::WindUpMania(_) => WindUpMania::is_user_playable(),
+            Self::WindDownMania(_) => WindDownMania::is_user_playable(),
+            Self::MutedMania(_) => MutedMania::is_user_playable(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::is_user_playable(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::is_user_playable(),
+            _ => UnknownMod::is_user_playable(),
+        }
+    }
+    /// Whether this [`GameMod`] can be selected in multiplayer
+    pub const fn is_valid_for_multiplayer(&self) -> bool {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::is_valid_for_multiplayer(),
+            Self::NoFailOsu(_) => NoFailOsu::is_valid_for_multiplayer(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::is_valid_for_multiplayer(),
+            Self::DaycoreOsu(_) => DaycoreOsu::is_valid_for_multiplayer(),
+            Self::HardRockOsu(_) => HardRockOsu::is_valid_for_multiplayer(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::is_valid_for_multiplayer(),
+            Self::PerfectOsu(_) => PerfectOsu::is_valid_for_multiplayer(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::is_valid_for_multiplayer(),
+            Self::NightcoreOsu(_) => NightcoreOsu::is_valid_for_multiplayer(),
+            Self::HiddenOsu(_) => HiddenOsu::is_valid_for_multiplayer(),
+            Self::TraceableOsu(_) => TraceableOsu::is_valid_for_multiplayer(),
+            Self::FlashlightOsu(_) => FlashlightOsu::is_valid_for_multiplayer(),
+            Self::BlindsOsu(_) => BlindsOsu::is_valid_for_multiplayer(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::is_valid_for_multiplayer(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::is_valid_for_multiplayer(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::is_valid_for_multiplayer(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::is_valid_for_multiplayer(),
+            Self::ClassicOsu(_) => ClassicOsu::is_valid_for_multiplayer(),
+            Self::RandomOsu(_) => RandomOsu::is_valid_for_multiplayer(),
+            Self::MirrorOsu(_) => MirrorOsu::is_valid_for_multiplayer(),
+            Self::AlternateOsu(_) => AlternateOsu::is_valid_for_multiplayer(),
+            Self::SingleTapOsu(_) => SingleTapOsu::is_valid_for_multiplayer(),
+            Self::AutoplayOsu(_) => AutoplayOsu::is_valid_for_multiplayer(),
+            Self::CinemaOsu(_) => CinemaOsu::is_valid_for_multiplayer(),
+            Self::RelaxOsu(_) => RelaxOsu::is_valid_for_multiplayer(),
+            Self::AutopilotOsu(_) => AutopilotOsu::is_valid_for_multiplayer(),
+            Self::SpunOutOsu(_) => SpunOutOsu::is_valid_for_multiplayer(),
+            Self::TransformOsu(_) => TransformOsu::is_valid_for_multiplayer(),
+            Self::WiggleOsu(_) => WiggleOsu::is_valid_for_multiplayer(),
+            Self::SpinInOsu(_) => SpinInOsu::is_valid_for_multiplayer(),
+            Self::GrowOsu(_) => GrowOsu::is_valid_for_multiplayer(),
+            Self::DeflateOsu(_) => DeflateOsu::is_valid_for_multiplayer(),
+            Self::WindUpOsu(_) => WindUpOsu::is_valid_for_multiplayer(),
+            Self::WindDownOsu(_) => WindDownOsu::is_valid_for_multiplayer(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::is_valid_for_multiplayer(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::is_valid_for_multiplayer(),
+            Self::MutedOsu(_) => MutedOsu::is_valid_for_multiplayer(),
+            Self::NoScopeOsu(_) => NoScopeOsu::is_valid_for_multiplayer(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::is_valid_for_multiplayer(),
+            Self::RepelOsu(_) => RepelOsu::is_valid_for_multiplayer(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::is_valid_for_multiplayer(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::is_valid_for_multiplayer(),
+            Self::BubblesOsu(_) => BubblesOsu::is_valid_for_multiplayer(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::is_valid_for_multiplayer(),
+            Self::DepthOsu(_) => DepthOsu::is_valid_for_multiplayer(),
+            Self::BloomOsu(_) => BloomOsu::is_valid_for_multiplayer(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::is_valid_for_multiplayer(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::is_valid_for_multiplayer(),
+            Self::EasyTaiko(_) => EasyTaiko::is_valid_for_multiplayer(),
+            Self::NoFailTaiko(_) => NoFailTaiko::is_valid_for_multiplayer(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::is_valid_for_multiplayer(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::is_valid_for_multiplayer(),
+            Self::SimplifiedRhythmTaiko(_) => SimplifiedRhythmTaiko::is_valid_for_multiplayer(),
+            Self::HardRockTaiko(_) => HardRockTaiko::is_valid_for_multiplayer(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::is_valid_for_multiplayer(),
+            Self::PerfectTaiko(_) => PerfectTaiko::is_valid_for_multiplayer(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::is_valid_for_multiplayer(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::is_valid_for_multiplayer(),
+            Self::HiddenTaiko(_) => HiddenTaiko::is_valid_for_multiplayer(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::is_valid_for_multiplayer(),
+            Self::AccuracyChallengeTaiko(_) => AccuracyChallengeTaiko::is_valid_for_multiplayer(),
+            Self::RandomTaiko(_) => RandomTaiko::is_valid_for_multiplayer(),
+            Self::DifficultyAdjustTaiko(_) => DifficultyAdjustTaiko::is_valid_for_multiplayer(),
+            Self::ClassicTaiko(_) => ClassicTaiko::is_valid_for_multiplayer(),
+            Self::SwapTaiko(_) => SwapTaiko::is_valid_for_multiplayer(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::is_valid_for_multiplayer(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::is_valid_for_multiplayer(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::is_valid_for_multiplayer(),
+            Self::CinemaTaiko(_) => CinemaTaiko::is_valid_for_multiplayer(),
+            Self::RelaxTaiko(_) => RelaxTaiko::is_valid_for_multiplayer(),
+            Self::WindUpTaiko(_) => WindUpTaiko::is_valid_for_multiplayer(),
+            Self::WindDownTaiko(_) => WindDownTaiko::is_valid_for_multiplayer(),
+            Self::MutedTaiko(_) => MutedTaiko::is_valid_for_multiplayer(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::is_valid_for_multiplayer(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::is_valid_for_multiplayer(),
+            Self::EasyCatch(_) => EasyCatch::is_valid_for_multiplayer(),
+            Self::NoFailCatch(_) => NoFailCatch::is_valid_for_multiplayer(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::is_valid_for_multiplayer(),
+            Self::DaycoreCatch(_) => DaycoreCatch::is_valid_for_multiplayer(),
+            Self::HardRockCatch(_) => HardRockCatch::is_valid_for_multiplayer(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::is_valid_for_multiplayer(),
+            Self::PerfectCatch(_) => PerfectCatch::is_valid_for_multiplayer(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::is_valid_for_multiplayer(),
+            Self::NightcoreCatch(_) => NightcoreCatch::is_valid_for_multiplayer(),
+            Self::HiddenCatch(_) => HiddenCatch::is_valid_for_multiplayer(),
+            Self::FlashlightCatch(_) => FlashlightCatch::is_valid_for_multiplayer(),
+            Self::AccuracyChallengeCatch(_) => AccuracyChallengeCatch::is_valid_for_multiplayer(),
+            Self::DifficultyAdjustCatch(_) => DifficultyAdjustCatch::is_valid_for_multiplayer(),
+            Self::ClassicCatch(_) => ClassicCatch::is_valid_for_multiplayer(),
+            Self::MirrorCatch(_) => MirrorCatch::is_valid_for_multiplayer(),
+            Self::AutoplayCatch(_) => AutoplayCatch::is_valid_for_multiplayer(),
+            Self::CinemaCatch(_) => CinemaCatch::is_valid_for_multiplayer(),
+            Self::RelaxCatch(_) => RelaxCatch::is_valid_for_multiplayer(),
+            Self::WindUpCatch(_) => WindUpCatch::is_valid_for_multiplayer(),
+            Self::WindDownCatch(_) => WindDownCatch::is_valid_for_multiplayer(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::is_valid_for_multiplayer(),
+            Self::MutedCatch(_) => MutedCatch::is_valid_for_multiplayer(),
+            Self::NoScopeCatch(_) => NoScopeCatch::is_valid_for_multiplayer(),
+            Self::MovingFastCatch(_) => MovingFastCatch::is_valid_for_multiplayer(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::is_valid_for_multiplayer(),
+            Self::EasyMania(_) => EasyMania::is_valid_for_multiplayer(),
+            Self::NoFailMania(_) => NoFailMania::is_valid_for_multiplayer(),
+            Self::HalfTimeMania(_) => HalfTimeMania::is_valid_for_multiplayer(),
+            Self::DaycoreMania(_) => DaycoreMania::is_valid_for_multiplayer(),
+            Self::NoReleaseMania(_) => NoReleaseMania::is_valid_for_multiplayer(),
+            Self::HardRockMania(_) => HardRockMania::is_valid_for_multiplayer(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::is_valid_for_multiplayer(),
+            Self::PerfectMania(_) => PerfectMania::is_valid_for_multiplayer(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::is_valid_for_multiplayer(),
+            Self::NightcoreMania(_) => NightcoreMania::is_valid_for_multiplayer(),
+            Self::FadeInMania(_) => FadeInMania::is_valid_for_multiplayer(),
+            Self::HiddenMania(_) => HiddenMania::is_valid_for_multiplayer(),
+            Self::CoverMania(_) => CoverMania::is_valid_for_multiplayer(),
+            Self::FlashlightMania(_) => FlashlightMania::is_valid_for_multiplayer(),
+            Self::AccuracyChallengeMania(_) => AccuracyChallengeMania::is_valid_for_multiplayer(),
+            Self::RandomMania(_) => RandomMania::is_valid_for_multiplayer(),
+            Self::DualStagesMania(_) => DualStagesMania::is_valid_for_multiplayer(),
+            Self::MirrorMania(_) => MirrorMania::is_valid_for_multiplayer(),
+            Self::DifficultyAdjustMania(_) => DifficultyAdjustMania::is_valid_for_multiplayer(),
+            Self::ClassicMania(_) => ClassicMania::is_valid_for_multiplayer(),
+            Self::InvertMania(_) => InvertMania::is_valid_for_multiplayer(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::is_valid_for_multiplayer(),
+            Self::HoldOffMania(_) => HoldOffMania::is_valid_for_multiplayer(),
+            Self::OneKeyMania(_) => OneKeyMania::is_valid_for_multiplayer(),
+            Self::TwoKeysMania(_) => TwoKeysMania::is_valid_for_multiplayer(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::is_valid_for_multiplayer(),
+            Self::FourKeysMania(_) => FourKeysMania::is_valid_for_multiplayer(),
+            Self::FiveKeysMania(_) => FiveKeysMania::is_valid_for_multiplayer(),
+            Self::SixKeysMania(_) => SixKeysMania::is_valid_for_multiplayer(),
+            Self::SevenKeysMania(_) => SevenKeysMania::is_valid_for_multiplayer(),
+            Self::EightKeysMania(_) => EightKeysMania::is_valid_for_multiplayer(),
+            Self::NineKeysMania(_) => NineKeysMania::is_valid_for_multiplayer(),
+            Self::TenKeysMania(_) => TenKeysMania::is_valid_for_multiplayer(),
+            Self::AutoplayMania(_) => AutoplayMania::is_valid_for_multiplayer(),
+            Self::CinemaMania(_) => CinemaMania::is_valid_for_multiplayer(),
+            Self::WindUpMania(_) => WindUpMania::is_valid_for_multiplayer(),
+            Self::WindDownMania(_) => WindDownMania::is_valid_for_multiplayer(),
+            Self::MutedMania(_) => MutedMania::is_valid_for_multiplayer(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::is_valid_for_multiplayer(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::is_valid_for_multiplayer(),
+            _ => UnknownMod::is_valid_for_multiplayer(),
+        }
+    }
+    /// Whether this [`GameMod`] can be selected as free mod in multiplayer
+    pub const fn is_valid_for_multiplayer_as_free_mod(&self) -> bool {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoFailOsu(_) => NoFailOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::DaycoreOsu(_) => DaycoreOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::HardRockOsu(_) => HardRockOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::PerfectOsu(_) => PerfectOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::NightcoreOsu(_) => NightcoreOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::HiddenOsu(_) => HiddenOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::TraceableOsu(_) => TraceableOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::FlashlightOsu(_) => FlashlightOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::BlindsOsu(_) => BlindsOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::AccuracyChallengeOsu(_) => {
+                AccuracyChallengeOsu::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::DifficultyAdjustOsu(_) => {
+                DifficultyAdjustOsu::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ClassicOsu(_) => ClassicOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::RandomOsu(_) => RandomOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::MirrorOsu(_) => MirrorOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::AlternateOsu(_) => AlternateOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::SingleTapOsu(_) => SingleTapOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::AutoplayOsu(_) => AutoplayOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::CinemaOsu(_) => CinemaOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::RelaxOsu(_) => RelaxOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::AutopilotOsu(_) => AutopilotOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::SpunOutOsu(_) => SpunOutOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::TransformOsu(_) => TransformOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::WiggleOsu(_) => WiggleOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::SpinInOsu(_) => SpinInOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::GrowOsu(_) => GrowOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::DeflateOsu(_) => DeflateOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindUpOsu(_) => WindUpOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindDownOsu(_) => WindDownOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::ApproachDifferentOsu(_) => {
+                ApproachDifferentOsu::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::MutedOsu(_) => MutedOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoScopeOsu(_) => NoScopeOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::RepelOsu(_) => RepelOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::BubblesOsu(_) => BubblesOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::DepthOsu(_) => DepthOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::BloomOsu(_) => BloomOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::is_valid_for_multiplayer_as_free_mod(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::is_valid_for_multiplayer_as_free_mod(),
+            Self::EasyTaiko(_) => EasyTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoFailTaiko(_) => NoFailTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::SimplifiedRhythmTaiko(_) => {
+                SimplifiedRhythmTaiko::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::HardRockTaiko(_) => HardRockTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::PerfectTaiko(_) => PerfectTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::HiddenTaiko(_) => HiddenTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::AccuracyChallengeTaiko(_) => {
+                AccuracyChallengeTaiko::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::RandomTaiko(_) => RandomTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::DifficultyAdjustTaiko(_) => {
+                DifficultyAdjustTaiko::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ClassicTaiko(_) => ClassicTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::SwapTaiko(_) => SwapTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::ConstantSpeedTaiko(_) => {
+                ConstantSpeedTaiko::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::AutoplayTaiko(_) => AutoplayTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::CinemaTaiko(_) => CinemaTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::RelaxTaiko(_) => RelaxTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindUpTaiko(_) => WindUpTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindDownTaiko(_) => WindDownTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::MutedTaiko(_) => MutedTaiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::AdaptiveSpeedTaiko(_) => {
+                AdaptiveSpeedTaiko::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::is_valid_for_multiplayer_as_free_mod(),
+            Self::EasyCatch(_) => EasyCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoFailCatch(_) => NoFailCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::DaycoreCatch(_) => DaycoreCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::HardRockCatch(_) => HardRockCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::PerfectCatch(_) => PerfectCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::NightcoreCatch(_) => NightcoreCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::HiddenCatch(_) => HiddenCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::FlashlightCatch(_) => FlashlightCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::AccuracyChallengeCatch(_) => {
+                AccuracyChallengeCatch::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::DifficultyAdjustCatch(_) => {
+                DifficultyAdjustCatch::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ClassicCatch(_) => ClassicCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::MirrorCatch(_) => MirrorCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::AutoplayCatch(_) => AutoplayCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::CinemaCatch(_) => CinemaCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::RelaxCatch(_) => RelaxCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindUpCatch(_) => WindUpCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindDownCatch(_) => WindDownCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::FloatingFruitsCatch(_) => {
+                FloatingFruitsCatch::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::MutedCatch(_) => MutedCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoScopeCatch(_) => NoScopeCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::MovingFastCatch(_) => MovingFastCatch::is_valid_for_multiplayer_as_free_mod(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::is_valid_for_multiplayer_as_free_mod(),
+            Self::EasyMania(_) => EasyMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoFailMania(_) => NoFailMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::HalfTimeMania(_) => HalfTimeMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::DaycoreMania(_) => DaycoreMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::NoReleaseMania(_) => NoReleaseMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::HardRockMania(_) => HardRockMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::PerfectMania(_) => PerfectMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::NightcoreMania(_) => NightcoreMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::FadeInMania(_) => FadeInMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::HiddenMania(_) => HiddenMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::CoverMania(_) => CoverMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::FlashlightMania(_) => FlashlightMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::AccuracyChallengeMania(_) => {
+                AccuracyChallengeMania::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::RandomMania(_) => RandomMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::DualStagesMania(_) => DualStagesMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::MirrorMania(_) => MirrorMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::DifficultyAdjustMania(_) => {
+                DifficultyAdjustMania::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ClassicMania(_) => ClassicMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::InvertMania(_) => InvertMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::ConstantSpeedMania(_) => {
+                ConstantSpeedMania::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::HoldOffMania(_) => HoldOffMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::OneKeyMania(_) => OneKeyMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::TwoKeysMania(_) => TwoKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::FourKeysMania(_) => FourKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::FiveKeysMania(_) => FiveKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::SixKeysMania(_) => SixKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::SevenKeysMania(_) => SevenKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::EightKeysMania(_) => EightKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::NineKeysMania(_) => NineKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::TenKeysMania(_) => TenKeysMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::AutoplayMania(_) => AutoplayMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::CinemaMania(_) => CinemaMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindUpMania(_) => WindUpMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::WindDownMania(_) => WindDownMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::MutedMania(_) => MutedMania::is_valid_for_multiplayer_as_free_mod(),
+            Self::AdaptiveSpeedMania(_) => {
+                AdaptiveSpeedMania::is_valid_for_multiplayer_as_free_mod()
+            }
+            Self::ScoreV2Mania(_) => ScoreV2Mania::is_valid_for_multiplayer_as_free_mod(),
+            _ => UnknownMod::is_valid_for_multiplayer_as_free_mod(),
+        }
+    }
+    /// Whether this [`GameMod`] needs to be configured before it can be used
+    pub const fn requires_configuration(&self) -> bool {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::requires_configuration(),
+            Self::NoFailOsu(_) => NoFailOsu::requires_configuration(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::requires_configuration(),
+            Self::DaycoreOsu(_) => DaycoreOsu::requires_configuration(),
+            Self::HardRockOsu(_) => HardRockOsu::requires_configuration(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::requires_configuration(),
+            Self::PerfectOsu(_) => PerfectOsu::requires_configuration(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::requires_configuration(),
+            Self::NightcoreOsu(_) => NightcoreOsu::requires_configuration(),
+            Self::HiddenOsu(_) => HiddenOsu::requires_configuration(),
+            Self::TraceableOsu(_) => TraceableOsu::requires_configuration(),
+            Self::FlashlightOsu(_) => FlashlightOsu::requires_configuration(),
+            Self::BlindsOsu(_) => BlindsOsu::requires_configuration(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::requires_configuration(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::requires_configuration(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::requires_configuration(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::requires_configuration(),
+            Self::ClassicOsu(_) => ClassicOsu::requires_configuration(),
+            Self::RandomOsu(_) => RandomOsu::requires_configuration(),
+            Self::MirrorOsu(_) => MirrorOsu::requires_configuration(),
+            Self::AlternateOsu(_) => AlternateOsu::requires_configuration(),
+            Self::SingleTapOsu(_) => SingleTapOsu::requires_configuration(),
+            Self::AutoplayOsu(_) => AutoplayOsu::requires_configuration(),
+            Self::CinemaOsu(_) => CinemaOsu::requires_configuration(),
+            Self::RelaxOsu(_) => RelaxOsu::requires_configuration(),
+            Self::AutopilotOsu(_) => AutopilotOsu::requires_configuration(),
+            Self::SpunOutOsu(_) => SpunOutOsu::requires_configuration(),
+            Self::TransformOsu(_) => TransformOsu::requires_configuration(),
+            Self::WiggleOsu(_) => WiggleOsu::requires_configuration(),
+            Self::SpinInOsu(_) => SpinInOsu::requires_configuration(),
+            Self::GrowOsu(_) => GrowOsu::requires_configuration(),
+            Self::DeflateOsu(_) => DeflateOsu::requires_configuration(),
+            Self::WindUpOsu(_) => WindUpOsu::requires_configuration(),
+            Self::WindDownOsu(_) => WindDownOsu::requires_configuration(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::requires_configuration(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::requires_configuration(),
+            Self::MutedOsu(_) => MutedOsu::requires_configuration(),
+            Self::NoScopeOsu(_) => NoScopeOsu::requires_configuration(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::requires_configuration(),
+            Self::RepelOsu(_) => RepelOsu::requires_configuration(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::requires_configuration(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::requires_configuration(),
+            Self::BubblesOsu(_) => BubblesOsu::requires_configuration(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::requires_configuration(),
+            Self::DepthOsu(_) => DepthOsu::requires_configuration(),
+            Self::BloomOsu(_) => BloomOsu::requires_configuration(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::requires_configuration(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::requires_configuration(),
+            Self::EasyTaiko(_) => EasyTaiko::requires_configuration(),
+            Self::NoFailTaiko(_) => NoFailTaiko::requires_configuration(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::requires_configuration(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::requires_configuration(),
+            Self::SimplifiedRhythmTaiko(_) => SimplifiedRhythmTaiko::requires_configuration(),
+            Self::HardRockTaiko(_) => HardRockTaiko::requires_configuration(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::requires_configuration(),
+            Self::PerfectTaiko(_) => PerfectTaiko::requires_configuration(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::requires_configuration(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::requires_configuration(),
+            Self::HiddenTaiko(_) => HiddenTaiko::requires_configuration(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::requires_configuration(),
+            Self::AccuracyChallengeTaiko(_) => AccuracyChallengeTaiko::requires_configuration(),
+            Self::RandomTaiko(_) => RandomTaiko::requires_configuration(),
+            Self::DifficultyAdjustTaiko(_) => DifficultyAdjustTaiko::requires_configuration(),
+            Self::ClassicTaiko(_) => ClassicTaiko::requires_configuration(),
+            Self::SwapTaiko(_) => SwapTaiko::requires_configuration(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::requires_configuration(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::requires_configuration(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::requires_configuration(),
+            Self::CinemaTaiko(_) => CinemaTaiko::requires_configuration(),
+            Self::RelaxTaiko(_) => RelaxTaiko::requires_configuration(),
+            Self::WindUpTaiko(_) => WindUpTaiko::requires_configuration(),
+            Self::WindDownTaiko(_) => WindDownTaiko::requires_configuration(),
+            Self::MutedTaiko(_) => MutedTaiko::requires_configuration(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::requires_configuration(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::requires_configuration(),
+            Self::EasyCatch(_) => EasyCatch::requires_configuration(),
+            Self::NoFailCatch(_) => NoFailCatch::requires_configuration(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::requires_configuration(),
+            Self::DaycoreCatch(_) => DaycoreCatch::requires_configuration(),
+            Self::HardRockCatch(_) => HardRockCatch::requires_configuration(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::requires_configuration(),
+            Self::PerfectCatch(_) => PerfectCatch::requires_configuration(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::requires_configuration(),
+            Self::NightcoreCatch(_) => NightcoreCatch::requires_configuration(),
+            Self::HiddenCatch(_) => HiddenCatch::requires_configuration(),
+            Self::FlashlightCatch(_) => FlashlightCatch::requires_configuration(),
+            Self::AccuracyChallengeCatch(_) => AccuracyChallengeCatch::requires_configuration(),
+            Self::DifficultyAdjustCatch(_) => DifficultyAdjustCatch::requires_configuration(),
+            Self::ClassicCatch(_) => ClassicCatch::requires_configuration(),
+            Self::MirrorCatch(_) => MirrorCatch::requires_configuration(),
+            Self::AutoplayCatch(_) => AutoplayCatch::requires_configuration(),
+            Self::CinemaCatch(_) => CinemaCatch::requires_configuration(),
+            Self::RelaxCatch(_) => RelaxCatch::requires_configuration(),
+            Self::WindUpCatch(_) => WindUpCatch::requires_configuration(),
+            Self::WindDownCatch(_) => WindDownCatch::requires_configuration(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::requires_configuration(),
+            Self::MutedCatch(_) => MutedCatch::requires_configuration(),
+            Self::NoScopeCatch(_) => NoScopeCatch::requires_configuration(),
+            Self::MovingFastCatch(_) => MovingFastCatch::requires_configuration(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::requires_configuration(),
+            Self::EasyMania(_) => EasyMania::requires_configuration(),
+            Self::NoFailMania(_) => NoFailMania::requires_configuration(),
+            Self::HalfTimeMania(_) => HalfTimeMania::requires_configuration(),
+            Self::DaycoreMania(_) => DaycoreMania::requires_configuration(),
+            Self::NoReleaseMania(_) => NoReleaseMania::requires_configuration(),
+            Self::HardRockMania(_) => HardRockMania::requires_configuration(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::requires_configuration(),
+            Self::PerfectMania(_) => PerfectMania::requires_configuration(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::requires_configuration(),
+            Self::NightcoreMania(_) => NightcoreMania::requires_configuration(),
+            Self::FadeInMania(_) => FadeInMania::requires_configuration(),
+            Self::HiddenMania(_) => HiddenMania::requires_configuration(),
+            Self::CoverMania(_) => CoverMania::requires_configuration(),
+            Self::FlashlightMania(_) => FlashlightMania::requires_configuration(),
+            Self::AccuracyChallengeMania(_) => AccuracyChallengeMania::requires_configuration(),
+            Self::RandomMania(_) => RandomMania::requires_configuration(),
+            Self::DualStagesMania(_) => DualStagesMania::requires_configuration(),
+            Self::MirrorMania(_) => MirrorMania::requires_configuration(),
+            Self::DifficultyAdjustMania(_) => DifficultyAdjustMania::requires_configuration(),
+            Self::ClassicMania(_) => ClassicMania::requires_configuration(),
+            Self::InvertMania(_) => InvertMania::requires_configuration(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::requires_configuration(),
+            Self::HoldOffMania(_) => HoldOffMania::requires_configuration(),
+            Self::OneKeyMania(_) => OneKeyMania::requires_configuration(),
+            Self::TwoKeysMania(_) => TwoKeysMania::requires_configuration(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::requires_configuration(),
+            Self::FourKeysMania(_) => FourKeysMania::requires_configuration(),
+            Self::FiveKeysMania(_) => FiveKeysMania::requires_configuration(),
+            Self::SixKeysMania(_) => SixKeysMania::requires_configuration(),
+            Self::SevenKeysMania(_) => SevenKeysMania::requires_configuration(),
+            Self::EightKeysMania(_) => EightKeysMania::requires_configuration(),
+            Self::NineKeysMania(_) => NineKeysMania::requires_configuration(),
+            Self::TenKeysMania(_) => TenKeysMania::requires_configuration(),
+            Self::AutoplayMania(_) => AutoplayMania::requires_configuration(),
+            Self::CinemaMania(_) => CinemaMania::requires_configuration(),
+            Self::WindUpMania(_) => WindUpMania::requires_configuration(),
+            Self::WindDownMania(_) => WindDownMania::requires_configuration(),
+            Self::MutedMania(_) => MutedMania::requires_configuration(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::requires_configuration(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::requires_configuration(),
+            _ => UnknownMod::requires_configuration(),
+        }
+    }
+    /// Whether this [`GameMod`] is valid for score submission regardless of other mods or its settings
+    pub const fn is_always_valid_for_submission(&self) -> bool {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::is_always_valid_for_submission(),
+            Self::NoFailOsu(_) => NoFailOsu::is_always_valid_for_submission(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::is_always_valid_for_submission(),
+            Self::DaycoreOsu(_) => DaycoreOsu::is_always_valid_for_submission(),
+            Self::HardRockOsu(_) => HardRockOsu::is_always_valid_for_submission(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::is_always_valid_for_submission(),
+            Self::PerfectOsu(_) => PerfectOsu::is_always_valid_for_submission(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::is_always_valid_for_submission(),
+            Self::NightcoreOsu(_) => NightcoreOsu::is_always_valid_for_submission(),
+            Self::HiddenOsu(_) => HiddenOsu::is_always_valid_for_submission(),
+            Self::TraceableOsu(_) => TraceableOsu::is_always_valid_for_submission(),
+            Self::FlashlightOsu(_) => FlashlightOsu::is_always_valid_for_submission(),
+            Self::BlindsOsu(_) => BlindsOsu::is_always_valid_for_submission(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::is_always_valid_for_submission(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::is_always_valid_for_submission(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::is_always_valid_for_submission(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::is_always_valid_for_submission(),
+            Self::ClassicOsu(_) => ClassicOsu::is_always_valid_for_submission(),
+            Self::RandomOsu(_) => RandomOsu::is_always_valid_for_submission(),
+            Self::MirrorOsu(_) => MirrorOsu::is_always_valid_for_submission(),
+            Self::AlternateOsu(_) => AlternateOsu::is_always_valid_for_submission(),
+            Self::SingleTapOsu(_) => SingleTapOsu::is_always_valid_for_submission(),
+            Self::AutoplayOsu(_) => AutoplayOsu::is_always_valid_for_submission(),
+            Self::CinemaOsu(_) => CinemaOsu::is_always_valid_for_submission(),
+            Self::RelaxOsu(_) => RelaxOsu::is_always_valid_for_submission(),
+            Self::AutopilotOsu(_) => AutopilotOsu::is_always_valid_for_submission(),
+            Self::SpunOutOsu(_) => SpunOutOsu::is_always_valid_for_submission(),
+            Self::TransformOsu(_) => TransformOsu::is_always_valid_for_submission(),
+            Self::WiggleOsu(_) => WiggleOsu::is_always_valid_for_submission(),
+            Self::SpinInOsu(_) => SpinInOsu::is_always_valid_for_submission(),
+            Self::GrowOsu(_) => GrowOsu::is_always_valid_for_submission(),
+            Self::DeflateOsu(_) => DeflateOsu::is_always_valid_for_submission(),
+            Self::WindUpOsu(_) => WindUpOsu::is_always_valid_for_submission(),
+            Self::WindDownOsu(_) => WindDownOsu::is_always_valid_for_submission(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::is_always_valid_for_submission(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::is_always_valid_for_submission(),
+            Self::MutedOsu(_) => MutedOsu::is_always_valid_for_submission(),
+            Self::NoScopeOsu(_) => NoScopeOsu::is_always_valid_for_submission(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::is_always_valid_for_submission(),
+            Self::RepelOsu(_) => RepelOsu::is_always_valid_for_submission(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::is_always_valid_for_submission(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::is_always_valid_for_submission(),
+            Self::BubblesOsu(_) => BubblesOsu::is_always_valid_for_submission(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::is_always_valid_for_submission(),
+            Self::DepthOsu(_) => DepthOsu::is_always_valid_for_submission(),
+            Self::BloomOsu(_) => BloomOsu::is_always_valid_for_submission(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::is_always_valid_for_submission(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::is_always_valid_for_submission(),
+            Self::EasyTaiko(_) => EasyTaiko::is_always_valid_for_submission(),
+            Self::NoFailTaiko(_) => NoFailTaiko::is_always_valid_for_submission(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::is_always_valid_for_submission(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::is_always_valid_for_submission(),
+            Self::SimplifiedRhythmTaiko(_) => {
+                SimplifiedRhythmTaiko::is_always_valid_for_submission()
+            }
+            Self::HardRockTaiko(_) => HardRockTaiko::is_always_valid_for_submission(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::is_always_valid_for_submission(),
+            Self::PerfectTaiko(_) => PerfectTaiko::is_always_valid_for_submission(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::is_always_valid_for_submission(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::is_always_valid_for_submission(),
+            Self::HiddenTaiko(_) => HiddenTaiko::is_always_valid_for_submission(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::is_always_valid_for_submission(),
+            Self::AccuracyChallengeTaiko(_) => {
+                AccuracyChallengeTaiko::is_always_valid_for_submission()
+            }
+            Self::RandomTaiko(_) => RandomTaiko::is_always_valid_for_submission(),
+            Self::DifficultyAdjustTaiko(_) => {
+                DifficultyAdjustTaiko::is_always_valid_for_submission()
+            }
+            Self::ClassicTaiko(_) => ClassicTaiko::is_always_valid_for_submission(),
+            Self::SwapTaiko(_) => SwapTaiko::is_always_valid_for_submission(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::is_always_valid_for_submission(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::is_always_valid_for_submission(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::is_always_valid_for_submission(),
+            Self::CinemaTaiko(_) => CinemaTaiko::is_always_valid_for_submission(),
+            Self::RelaxTaiko(_) => RelaxTaiko::is_always_valid_for_submission(),
+            Self::WindUpTaiko(_) => WindUpTaiko::is_always_valid_for_submission(),
+            Self::WindDownTaiko(_) => WindDownTaiko::is_always_valid_for_submission(),
+            Self::MutedTaiko(_) => MutedTaiko::is_always_valid_for_submission(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::is_always_valid_for_submission(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::is_always_valid_for_submission(),
+            Self::EasyCatch(_) => EasyCatch::is_always_valid_for_submission(),
+            Self::NoFailCatch(_) => NoFailCatch::is_always_valid_for_submission(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::is_always_valid_for_submission(),
+            Self::DaycoreCatch(_) => DaycoreCatch::is_always_valid_for_submission(),
+            Self::HardRockCatch(_) => HardRockCatch::is_always_valid_for_submission(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::is_always_valid_for_submission(),
+            Self::PerfectCatch(_) => PerfectCatch::is_always_valid_for_submission(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::is_always_valid_for_submission(),
+            Self::NightcoreCatch(_) => NightcoreCatch::is_always_valid_for_submission(),
+            Self::HiddenCatch(_) => HiddenCatch::is_always_valid_for_submission(),
+            Self::FlashlightCatch(_) => FlashlightCatch::is_always_valid_for_submission(),
+            Self::AccuracyChallengeCatch(_) => {
+                AccuracyChallengeCatch::is_always_valid_for_submission()
+            }
+            Self::DifficultyAdjustCatch(_) => {
+                DifficultyAdjustCatch::is_always_valid_for_submission()
+            }
+            Self::ClassicCatch(_) => ClassicCatch::is_always_valid_for_submission(),
+            Self::MirrorCatch(_) => MirrorCatch::is_always_valid_for_submission(),
+            Self::AutoplayCatch(_) => AutoplayCatch::is_always_valid_for_submission(),
+            Self::CinemaCatch(_) => CinemaCatch::is_always_valid_for_submission(),
+            Self::RelaxCatch(_) => RelaxCatch::is_always_valid_for_submission(),
+            Self::WindUpCatch(_) => WindUpCatch::is_always_valid_for_submission(),
+            Self::WindDownCatch(_) => WindDownCatch::is_always_valid_for_submission(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::is_always_valid_for_submission(),
+            Self::MutedCatch(_) => MutedCatch::is_always_valid_for_submission(),
+            Self::NoScopeCatch(_) => NoScopeCatch::is_always_valid_for_submission(),
+            Self::MovingFastCatch(_) => MovingFastCatch::is_always_valid_for_submission(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::is_always_valid_for_submission(),
+            Self::EasyMania(_) => EasyMania::is_always_valid_for_submission(),
+            Self::NoFailMania(_) => NoFailMania::is_always_valid_for_submission(),
+            Self::HalfTimeMania(_) => HalfTimeMania::is_always_valid_for_submission(),
+            Self::DaycoreMania(_) => DaycoreMania::is_always_valid_for_submission(),
+            Self::NoReleaseMania(_) => NoReleaseMania::is_always_valid_for_submission(),
+            Self::HardRockMania(_) => HardRockMania::is_always_valid_for_submission(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::is_always_valid_for_submission(),
+            Self::PerfectMania(_) => PerfectMania::is_always_valid_for_submission(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::is_always_valid_for_submission(),
+            Self::NightcoreMania(_) => NightcoreMania::is_always_valid_for_submission(),
+            Self::FadeInMania(_) => FadeInMania::is_always_valid_for_submission(),
+            Self::HiddenMania(_) => HiddenMania::is_always_valid_for_submission(),
+            Self::CoverMania(_) => CoverMania::is_always_valid_for_submission(),
+            Self::FlashlightMania(_) => FlashlightMania::is_always_valid_for_submission(),
+            Self::AccuracyChallengeMania(_) => {
+                AccuracyChallengeMania::is_always_valid_for_submission()
+            }
+            Self::RandomMania(_) => RandomMania::is_always_valid_for_submission(),
+            Self::DualStagesMania(_) => DualStagesMania::is_always_valid_for_submission(),
+            Self::MirrorMania(_) => MirrorMania::is_always_valid_for_submission(),
+            Self::DifficultyAdjustMania(_) => {
+                DifficultyAdjustMania::is_always_valid_for_submission()
+            }
+            Self::ClassicMania(_) => ClassicMania::is_always_valid_for_submission(),
+            Self::InvertMania(_) => InvertMania::is_always_valid_for_submission(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::is_always_valid_for_submission(),
+            Self::HoldOffMania(_) => HoldOffMania::is_always_valid_for_submission(),
+            Self::OneKeyMania(_) => OneKeyMania::is_always_valid_for_submission(),
+            Self::TwoKeysMania(_) => TwoKeysMania::is_always_valid_for_submission(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::is_always_valid_for_submission(),
+            Self::FourKeysMania(_) => FourKeysMania::is_always_valid_for_submission(),
+            Self::FiveKeysMania(_) => FiveKeysMania::is_always_valid_for_submission(),
+            Self::SixKeysMania(_) => SixKeysMania::is_always_valid_for_submission(),
+            Self::SevenKeysMania(_) => SevenKeysMania::is_always_valid_for_submission(),
+            Self::EightKeysMania(_) => EightKeysMania::is_always_valid_for_submission(),
+            Self::NineKeysMania(_) => NineKeysMania::is_always_valid_for_submission(),
+            Self::TenKeysMania(_) => TenKeysMania::is_always_valid_for_submission(),
+            Self::AutoplayMania(_) => AutoplayMania::is_always_valid_for_submission(),
+            Self::CinemaMania(_) => CinemaMania::is_always_valid_for_submission(),
+            Self::WindUpMania(_) => WindUpMania::is_always_valid_for_submission(),
+            Self::WindDownMania(_) => WindDownMania::is_always_valid_for_submission(),
+            Self::MutedMania(_) => MutedMania::is_always_valid_for_submission(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::is_always_valid_for_submission(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::is_always_valid_for_submission(),
+            _ => UnknownMod::is_always_valid_for_submission(),
+        }
+    }
     /// The [`GameMode`] of a [`GameMod`]
     pub const fn mode(&self) -> GameMode {
         match self {
@@ -7979,6 +19892,404 @@ impl GameMod {
         };
         GameModSimple { acronym, settings }
     }
+    /// The [`SettingSchema`] for each setting of this [`GameMod`]
+    pub const fn settings_schema(&self) -> &'static [SettingSchema] {
+        match self {
+            Self::EasyOsu(_) => EasyOsu::settings_schema(),
+            Self::NoFailOsu(_) => NoFailOsu::settings_schema(),
+            Self::HalfTimeOsu(_) => HalfTimeOsu::settings_schema(),
+            Self::DaycoreOsu(_) => DaycoreOsu::settings_schema(),
+            Self::HardRockOsu(_) => HardRockOsu::settings_schema(),
+            Self::SuddenDeathOsu(_) => SuddenDeathOsu::settings_schema(),
+            Self::PerfectOsu(_) => PerfectOsu::settings_schema(),
+            Self::DoubleTimeOsu(_) => DoubleTimeOsu::settings_schema(),
+            Self::NightcoreOsu(_) => NightcoreOsu::settings_schema(),
+            Self::HiddenOsu(_) => HiddenOsu::settings_schema(),
+            Self::TraceableOsu(_) => TraceableOsu::settings_schema(),
+            Self::FlashlightOsu(_) => FlashlightOsu::settings_schema(),
+            Self::BlindsOsu(_) => BlindsOsu::settings_schema(),
+            Self::StrictTrackingOsu(_) => StrictTrackingOsu::settings_schema(),
+            Self::AccuracyChallengeOsu(_) => AccuracyChallengeOsu::settings_schema(),
+            Self::TargetPracticeOsu(_) => TargetPracticeOsu::settings_schema(),
+            Self::DifficultyAdjustOsu(_) => DifficultyAdjustOsu::settings_schema(),
+            Self::ClassicOsu(_) => ClassicOsu::settings_schema(),
+            Self::RandomOsu(_) => RandomOsu::settings_schema(),
+            Self::MirrorOsu(_) => MirrorOsu::settings_schema(),
+            Self::AlternateOsu(_) => AlternateOsu::settings_schema(),
+            Self::SingleTapOsu(_) => SingleTapOsu::settings_schema(),
+            Self::AutoplayOsu(_) => AutoplayOsu::settings_schema(),
+            Self::CinemaOsu(_) => CinemaOsu::settings_schema(),
+            Self::RelaxOsu(_) => RelaxOsu::settings_schema(),
+            Self::AutopilotOsu(_) => AutopilotOsu::settings_schema(),
+            Self::SpunOutOsu(_) => SpunOutOsu::settings_schema(),
+            Self::TransformOsu(_) => TransformOsu::settings_schema(),
+            Self::WiggleOsu(_) => WiggleOsu::settings_schema(),
+            Self::SpinInOsu(_) => SpinInOsu::settings_schema(),
+            Self::GrowOsu(_) => GrowOsu::settings_schema(),
+            Self::DeflateOsu(_) => DeflateOsu::settings_schema(),
+            Self::WindUpOsu(_) => WindUpOsu::settings_schema(),
+            Self::WindDownOsu(_) => WindDownOsu::settings_schema(),
+            Self::BarrelRollOsu(_) => BarrelRollOsu::settings_schema(),
+            Self::ApproachDifferentOsu(_) => ApproachDifferentOsu::settings_schema(),
+            Self::MutedOsu(_) => MutedOsu::settings_schema(),
+            Self::NoScopeOsu(_) => NoScopeOsu::settings_schema(),
+            Self::MagnetisedOsu(_) => MagnetisedOsu::settings_schema(),
+            Self::RepelOsu(_) => RepelOsu::settings_schema(),
+            Self::AdaptiveSpeedOsu(_) => AdaptiveSpeedOsu::settings_schema(),
+            Self::FreezeFrameOsu(_) => FreezeFrameOsu::settings_schema(),
+            Self::BubblesOsu(_) => BubblesOsu::settings_schema(),
+            Self::SynesthesiaOsu(_) => SynesthesiaOsu::settings_schema(),
+            Self::DepthOsu(_) => DepthOsu::settings_schema(),
+            Self::BloomOsu(_) => BloomOsu::settings_schema(),
+            Self::TouchDeviceOsu(_) => TouchDeviceOsu::settings_schema(),
+            Self::ScoreV2Osu(_) => ScoreV2Osu::settings_schema(),
+            Self::EasyTaiko(_) => EasyTaiko::settings_schema(),
+            Self::NoFailTaiko(_) => NoFailTaiko::settings_schema(),
+            Self::HalfTimeTaiko(_) => HalfTimeTaiko::settings_schema(),
+            Self::DaycoreTaiko(_) => DaycoreTaiko::settings_schema(),
+            Self::SimplifiedRhythmTaiko(_) => SimplifiedRhythmTaiko::settings_schema(),
+            Self::HardRockTaiko(_) => HardRockTaiko::settings_schema(),
+            Self::SuddenDeathTaiko(_) => SuddenDeathTaiko::settings_schema(),
+            Self::PerfectTaiko(_) => PerfectTaiko::settings_schema(),
+            Self::DoubleTimeTaiko(_) => DoubleTimeTaiko::settings_schema(),
+            Self::NightcoreTaiko(_) => NightcoreTaiko::settings_schema(),
+            Self::HiddenTaiko(_) => HiddenTaiko::settings_schema(),
+            Self::FlashlightTaiko(_) => FlashlightTaiko::settings_schema(),
+            Self::AccuracyChallengeTaiko(_) => AccuracyChallengeTaiko::settings_schema(),
+            Self::RandomTaiko(_) => RandomTaiko::settings_schema(),
+            Self::DifficultyAdjustTaiko(_) => DifficultyAdjustTaiko::settings_schema(),
+            Self::ClassicTaiko(_) => ClassicTaiko::settings_schema(),
+            Self::SwapTaiko(_) => SwapTaiko::settings_schema(),
+            Self::SingleTapTaiko(_) => SingleTapTaiko::settings_schema(),
+            Self::ConstantSpeedTaiko(_) => ConstantSpeedTaiko::settings_schema(),
+            Self::AutoplayTaiko(_) => AutoplayTaiko::settings_schema(),
+            Self::CinemaTaiko(_) => CinemaTaiko::settings_schema(),
+            Self::RelaxTaiko(_) => RelaxTaiko::settings_schema(),
+            Self::WindUpTaiko(_) => WindUpTaiko::settings_schema(),
+            Self::WindDownTaiko(_) => WindDownTaiko::settings_schema(),
+            Self::MutedTaiko(_) => MutedTaiko::settings_schema(),
+            Self::AdaptiveSpeedTaiko(_) => AdaptiveSpeedTaiko::settings_schema(),
+            Self::ScoreV2Taiko(_) => ScoreV2Taiko::settings_schema(),
+            Self::EasyCatch(_) => EasyCatch::settings_schema(),
+            Self::NoFailCatch(_) => NoFailCatch::settings_schema(),
+            Self::HalfTimeCatch(_) => HalfTimeCatch::settings_schema(),
+            Self::DaycoreCatch(_) => DaycoreCatch::settings_schema(),
+            Self::HardRockCatch(_) => HardRockCatch::settings_schema(),
+            Self::SuddenDeathCatch(_) => SuddenDeathCatch::settings_schema(),
+            Self::PerfectCatch(_) => PerfectCatch::settings_schema(),
+            Self::DoubleTimeCatch(_) => DoubleTimeCatch::settings_schema(),
+            Self::NightcoreCatch(_) => NightcoreCatch::settings_schema(),
+            Self::HiddenCatch(_) => HiddenCatch::settings_schema(),
+            Self::FlashlightCatch(_) => FlashlightCatch::settings_schema(),
+            Self::AccuracyChallengeCatch(_) => AccuracyChallengeCatch::settings_schema(),
+            Self::DifficultyAdjustCatch(_) => DifficultyAdjustCatch::settings_schema(),
+            Self::ClassicCatch(_) => ClassicCatch::settings_schema(),
+            Self::MirrorCatch(_) => MirrorCatch::settings_schema(),
+            Self::AutoplayCatch(_) => AutoplayCatch::settings_schema(),
+            Self::CinemaCatch(_) => CinemaCatch::settings_schema(),
+            Self::RelaxCatch(_) => RelaxCatch::settings_schema(),
+            Self::WindUpCatch(_) => WindUpCatch::settings_schema(),
+            Self::WindDownCatch(_) => WindDownCatch::settings_schema(),
+            Self::FloatingFruitsCatch(_) => FloatingFruitsCatch::settings_schema(),
+            Self::MutedCatch(_) => MutedCatch::settings_schema(),
+            Self::NoScopeCatch(_) => NoScopeCatch::settings_schema(),
+            Self::MovingFastCatch(_) => MovingFastCatch::settings_schema(),
+            Self::ScoreV2Catch(_) => ScoreV2Catch::settings_schema(),
+            Self::EasyMania(_) => EasyMania::settings_schema(),
+            Self::NoFailMania(_) => NoFailMania::settings_schema(),
+            Self::HalfTimeMania(_) => HalfTimeMania::settings_schema(),
+            Self::DaycoreMania(_) => DaycoreMania::settings_schema(),
+            Self::NoReleaseMania(_) => NoReleaseMania::settings_schema(),
+            Self::HardRockMania(_) => HardRockMania::settings_schema(),
+            Self::SuddenDeathMania(_) => SuddenDeathMania::settings_schema(),
+            Self::PerfectMania(_) => PerfectMania::settings_schema(),
+            Self::DoubleTimeMania(_) => DoubleTimeMania::settings_schema(),
+            Self::NightcoreMania(_) => NightcoreMania::settings_schema(),
+            Self::FadeInMania(_) => FadeInMania::settings_schema(),
+            Self::HiddenMania(_) => HiddenMania::settings_schema(),
+            Self::CoverMania(_) => CoverMania::settings_schema(),
+            Self::FlashlightMania(_) => FlashlightMania::settings_schema(),
+            Self::AccuracyChallengeMania(_) => AccuracyChallengeMania::settings_schema(),
+            Self::RandomMania(_) => RandomMania::settings_schema(),
+            Self::DualStagesMania(_) => DualStagesMania::settings_schema(),
+            Self::MirrorMania(_) => MirrorMania::settings_schema(),
+            Self::DifficultyAdjustMania(_) => DifficultyAdjustMania::settings_schema(),
+            Self::ClassicMania(_) => ClassicMania::settings_schema(),
+            Self::InvertMania(_) => InvertMania::settings_schema(),
+            Self::ConstantSpeedMania(_) => ConstantSpeedMania::settings_schema(),
+            Self::HoldOffMania(_) => HoldOffMania::settings_schema(),
+            Self::OneKeyMania(_) => OneKeyMania::settings_schema(),
+            Self::TwoKeysMania(_) => TwoKeysMania::settings_schema(),
+            Self::ThreeKeysMania(_) => ThreeKeysMania::settings_schema(),
+            Self::FourKeysMania(_) => FourKeysMania::settings_schema(),
+            Self::FiveKeysMania(_) => FiveKeysMania::settings_schema(),
+            Self::SixKeysMania(_) => SixKeysMania::settings_schema(),
+            Self::SevenKeysMania(_) => SevenKeysMania::settings_schema(),
+            Self::EightKeysMania(_) => EightKeysMania::settings_schema(),
+            Self::NineKeysMania(_) => NineKeysMania::settings_schema(),
+            Self::TenKeysMania(_) => TenKeysMania::settings_schema(),
+            Self::AutoplayMania(_) => AutoplayMania::settings_schema(),
+            Self::CinemaMania(_) => CinemaMania::settings_schema(),
+            Self::WindUpMania(_) => WindUpMania::settings_schema(),
+            Self::WindDownMania(_) => WindDownMania::settings_schema(),
+            Self::MutedMania(_) => MutedMania::settings_schema(),
+            Self::AdaptiveSpeedMania(_) => AdaptiveSpeedMania::settings_schema(),
+            Self::ScoreV2Mania(_) => ScoreV2Mania::settings_schema(),
+            _ => UnknownMod::settings_schema(),
+        }
+    }
+    /// Replace all unset settings of this [`GameMod`] with their default value
+    ///
+    /// Settings without a fixed default value remain unchanged.
+    #[must_use]
+    pub fn with_defaults_filled(self) -> Self {
+        match self {
+            Self::EasyOsu(m) => Self::EasyOsu(m.with_defaults_filled()),
+            Self::HalfTimeOsu(m) => Self::HalfTimeOsu(m.with_defaults_filled()),
+            Self::DaycoreOsu(m) => Self::DaycoreOsu(m.with_defaults_filled()),
+            Self::SuddenDeathOsu(m) => Self::SuddenDeathOsu(m.with_defaults_filled()),
+            Self::PerfectOsu(m) => Self::PerfectOsu(m.with_defaults_filled()),
+            Self::DoubleTimeOsu(m) => Self::DoubleTimeOsu(m.with_defaults_filled()),
+            Self::NightcoreOsu(m) => Self::NightcoreOsu(m.with_defaults_filled()),
+            Self::HiddenOsu(m) => Self::HiddenOsu(m.with_defaults_filled()),
+            Self::FlashlightOsu(m) => Self::FlashlightOsu(m.with_defaults_filled()),
+            Self::AccuracyChallengeOsu(m) => Self::AccuracyChallengeOsu(m.with_defaults_filled()),
+            Self::TargetPracticeOsu(m) => Self::TargetPracticeOsu(m.with_defaults_filled()),
+            Self::DifficultyAdjustOsu(m) => Self::DifficultyAdjustOsu(m.with_defaults_filled()),
+            Self::ClassicOsu(m) => Self::ClassicOsu(m.with_defaults_filled()),
+            Self::RandomOsu(m) => Self::RandomOsu(m.with_defaults_filled()),
+            Self::MirrorOsu(m) => Self::MirrorOsu(m.with_defaults_filled()),
+            Self::WiggleOsu(m) => Self::WiggleOsu(m.with_defaults_filled()),
+            Self::GrowOsu(m) => Self::GrowOsu(m.with_defaults_filled()),
+            Self::DeflateOsu(m) => Self::DeflateOsu(m.with_defaults_filled()),
+            Self::WindUpOsu(m) => Self::WindUpOsu(m.with_defaults_filled()),
+            Self::WindDownOsu(m) => Self::WindDownOsu(m.with_defaults_filled()),
+            Self::BarrelRollOsu(m) => Self::BarrelRollOsu(m.with_defaults_filled()),
+            Self::ApproachDifferentOsu(m) => Self::ApproachDifferentOsu(m.with_defaults_filled()),
+            Self::MutedOsu(m) => Self::MutedOsu(m.with_defaults_filled()),
+            Self::NoScopeOsu(m) => Self::NoScopeOsu(m.with_defaults_filled()),
+            Self::MagnetisedOsu(m) => Self::MagnetisedOsu(m.with_defaults_filled()),
+            Self::RepelOsu(m) => Self::RepelOsu(m.with_defaults_filled()),
+            Self::AdaptiveSpeedOsu(m) => Self::AdaptiveSpeedOsu(m.with_defaults_filled()),
+            Self::DepthOsu(m) => Self::DepthOsu(m.with_defaults_filled()),
+            Self::BloomOsu(m) => Self::BloomOsu(m.with_defaults_filled()),
+            Self::HalfTimeTaiko(m) => Self::HalfTimeTaiko(m.with_defaults_filled()),
+            Self::DaycoreTaiko(m) => Self::DaycoreTaiko(m.with_defaults_filled()),
+            Self::SimplifiedRhythmTaiko(m) => Self::SimplifiedRhythmTaiko(m.with_defaults_filled()),
+            Self::SuddenDeathTaiko(m) => Self::SuddenDeathTaiko(m.with_defaults_filled()),
+            Self::PerfectTaiko(m) => Self::PerfectTaiko(m.with_defaults_filled()),
+            Self::DoubleTimeTaiko(m) => Self::DoubleTimeTaiko(m.with_defaults_filled()),
+            Self::NightcoreTaiko(m) => Self::NightcoreTaiko(m.with_defaults_filled()),
+            Self::FlashlightTaiko(m) => Self::FlashlightTaiko(m.with_defaults_filled()),
+            Self::AccuracyChallengeTaiko(m) => {
+                Self::AccuracyChallengeTaiko(m.with_defaults_filled())
+            }
+            Self::RandomTaiko(m) => Self::RandomTaiko(m.with_defaults_filled()),
+            Self::DifficultyAdjustTaiko(m) => Self::DifficultyAdjustTaiko(m.with_defaults_filled()),
+            Self::WindUpTaiko(m) => Self::WindUpTaiko(m.with_defaults_filled()),
+            Self::WindDownTaiko(m) => Self::WindDownTaiko(m.with_defaults_filled()),
+            Self::MutedTaiko(m) => Self::MutedTaiko(m.with_defaults_filled()),
+            Self::AdaptiveSpeedTaiko(m) => Self::AdaptiveSpeedTaiko(m.with_defaults_filled()),
+            Self::EasyCatch(m) => Self::EasyCatch(m.with_defaults_filled()),
+            Self::HalfTimeCatch(m) => Self::HalfTimeCatch(m.with_defaults_filled()),
+            Self::DaycoreCatch(m) => Self::DaycoreCatch(m.with_defaults_filled()),
+            Self::SuddenDeathCatch(m) => Self::SuddenDeathCatch(m.with_defaults_filled()),
+            Self::PerfectCatch(m) => Self::PerfectCatch(m.with_defaults_filled()),
+            Self::DoubleTimeCatch(m) => Self::DoubleTimeCatch(m.with_defaults_filled()),
+            Self::NightcoreCatch(m) => Self::NightcoreCatch(m.with_defaults_filled()),
+            Self::FlashlightCatch(m) => Self::FlashlightCatch(m.with_defaults_filled()),
+            Self::AccuracyChallengeCatch(m) => {
+                Self::AccuracyChallengeCatch(m.with_defaults_filled())
+            }
+            Self::DifficultyAdjustCatch(m) => Self::DifficultyAdjustCatch(m.with_defaults_filled()),
+            Self::WindUpCatch(m) => Self::WindUpCatch(m.with_defaults_filled()),
+            Self::WindDownCatch(m) => Self::WindDownCatch(m.with_defaults_filled()),
+            Self::MutedCatch(m) => Self::MutedCatch(m.with_defaults_filled()),
+            Self::NoScopeCatch(m) => Self::NoScopeCatch(m.with_defaults_filled()),
+            Self::EasyMania(m) => Self::EasyMania(m.with_defaults_filled()),
+            Self::HalfTimeMania(m) => Self::HalfTimeMania(m.with_defaults_filled()),
+            Self::DaycoreMania(m) => Self::DaycoreMania(m.with_defaults_filled()),
+            Self::SuddenDeathMania(m) => Self::SuddenDeathMania(m.with_defaults_filled()),
+            Self::PerfectMania(m) => Self::PerfectMania(m.with_defaults_filled()),
+            Self::DoubleTimeMania(m) => Self::DoubleTimeMania(m.with_defaults_filled()),
+            Self::NightcoreMania(m) => Self::NightcoreMania(m.with_defaults_filled()),
+            Self::CoverMania(m) => Self::CoverMania(m.with_defaults_filled()),
+            Self::FlashlightMania(m) => Self::FlashlightMania(m.with_defaults_filled()),
+            Self::AccuracyChallengeMania(m) => {
+                Self::AccuracyChallengeMania(m.with_defaults_filled())
+            }
+            Self::RandomMania(m) => Self::RandomMania(m.with_defaults_filled()),
+            Self::DifficultyAdjustMania(m) => Self::DifficultyAdjustMania(m.with_defaults_filled()),
+            Self::WindUpMania(m) => Self::WindUpMania(m.with_defaults_filled()),
+            Self::WindDownMania(m) => Self::WindDownMania(m.with_defaults_filled()),
+            Self::MutedMania(m) => Self::MutedMania(m.with_defaults_filled()),
+            Self::AdaptiveSpeedMania(m) => Self::AdaptiveSpeedMania(m.with_defaults_filled()),
+            other => other,
+        }
+    }
+    /// The value of the setting with the given name if it is set
+    pub fn setting(&self, name: &str) -> Option<SettingSimple> {
+        match self {
+            Self::EasyOsu(m) => m.setting(name),
+            Self::HalfTimeOsu(m) => m.setting(name),
+            Self::DaycoreOsu(m) => m.setting(name),
+            Self::SuddenDeathOsu(m) => m.setting(name),
+            Self::PerfectOsu(m) => m.setting(name),
+            Self::DoubleTimeOsu(m) => m.setting(name),
+            Self::NightcoreOsu(m) => m.setting(name),
+            Self::HiddenOsu(m) => m.setting(name),
+            Self::FlashlightOsu(m) => m.setting(name),
+            Self::AccuracyChallengeOsu(m) => m.setting(name),
+            Self::TargetPracticeOsu(m) => m.setting(name),
+            Self::DifficultyAdjustOsu(m) => m.setting(name),
+            Self::ClassicOsu(m) => m.setting(name),
+            Self::RandomOsu(m) => m.setting(name),
+            Self::MirrorOsu(m) => m.setting(name),
+            Self::WiggleOsu(m) => m.setting(name),
+            Self::GrowOsu(m) => m.setting(name),
+            Self::DeflateOsu(m) => m.setting(name),
+            Self::WindUpOsu(m) => m.setting(name),
+            Self::WindDownOsu(m) => m.setting(name),
+            Self::BarrelRollOsu(m) => m.setting(name),
+            Self::ApproachDifferentOsu(m) => m.setting(name),
+            Self::MutedOsu(m) => m.setting(name),
+            Self::NoScopeOsu(m) => m.setting(name),
+            Self::MagnetisedOsu(m) => m.setting(name),
+            Self::RepelOsu(m) => m.setting(name),
+            Self::AdaptiveSpeedOsu(m) => m.setting(name),
+            Self::DepthOsu(m) => m.setting(name),
+            Self::BloomOsu(m) => m.setting(name),
+            Self::HalfTimeTaiko(m) => m.setting(name),
+            Self::DaycoreTaiko(m) => m.setting(name),
+            Self::SimplifiedRhythmTaiko(m) => m.setting(name),
+            Self::SuddenDeathTaiko(m) => m.setting(name),
+            Self::PerfectTaiko(m) => m.setting(name),
+            Self::DoubleTimeTaiko(m) => m.setting(name),
+            Self::NightcoreTaiko(m) => m.setting(name),
+            Self::FlashlightTaiko(m) => m.setting(name),
+            Self::AccuracyChallengeTaiko(m) => m.setting(name),
+            Self::RandomTaiko(m) => m.setting(name),
+            Self::DifficultyAdjustTaiko(m) => m.setting(name),
+            Self::WindUpTaiko(m) => m.setting(name),
+            Self::WindDownTaiko(m) => m.setting(name),
+            Self::MutedTaiko(m) => m.setting(name),
+            Self::AdaptiveSpeedTaiko(m) => m.setting(name),
+            Self::EasyCatch(m) => m.setting(name),
+            Self::HalfTimeCatch(m) => m.setting(name),
+            Self::DaycoreCatch(m) => m.setting(name),
+            Self::SuddenDeathCatch(m) => m.setting(name),
+            Self::PerfectCatch(m) => m.setting(name),
+            Self::DoubleTimeCatch(m) => m.setting(name),
+            Self::NightcoreCatch(m) => m.setting(name),
+            Self::FlashlightCatch(m) => m.setting(name),
+            Self::AccuracyChallengeCatch(m) => m.setting(name),
+            Self::DifficultyAdjustCatch(m) => m.setting(name),
+            Self::WindUpCatch(m) => m.setting(name),
+            Self::WindDownCatch(m) => m.setting(name),
+            Self::MutedCatch(m) => m.setting(name),
+            Self::NoScopeCatch(m) => m.setting(name),
+            Self::EasyMania(m) => m.setting(name),
+            Self::HalfTimeMania(m) => m.setting(name),
+            Self::DaycoreMania(m) => m.setting(name),
+            Self::SuddenDeathMania(m) => m.setting(name),
+            Self::PerfectMania(m) => m.setting(name),
+            Self::DoubleTimeMania(m) => m.setting(name),
+            Self::NightcoreMania(m) => m.setting(name),
+            Self::CoverMania(m) => m.setting(name),
+            Self::FlashlightMania(m) => m.setting(name),
+            Self::AccuracyChallengeMania(m) => m.setting(name),
+            Self::RandomMania(m) => m.setting(name),
+            Self::DifficultyAdjustMania(m) => m.setting(name),
+            Self::WindUpMania(m) => m.setting(name),
+            Self::WindDownMania(m) => m.setting(name),
+            Self::MutedMania(m) => m.setting(name),
+            Self::AdaptiveSpeedMania(m) => m.setting(name),
+            _ => None,
+        }
+    }
+    /// Set or unset the setting with the given name
+    ///
+    /// Returns `false` if there is no such setting or if the value has the
+    /// wrong type.
+    pub fn set_setting(&mut self, name: &str, value: Option<SettingSimple>) -> bool {
+        match self {
+            Self::EasyOsu(m) => m.set_setting(name, value),
+            Self::HalfTimeOsu(m) => m.set_setting(name, value),
+            Self::DaycoreOsu(m) => m.set_setting(name, value),
+            Self::SuddenDeathOsu(m) => m.set_setting(name, value),
+            Self::PerfectOsu(m) => m.set_setting(name, value),
+            Self::DoubleTimeOsu(m) => m.set_setting(name, value),
+            Self::NightcoreOsu(m) => m.set_setting(name, value),
+            Self::HiddenOsu(m) => m.set_setting(name, value),
+            Self::FlashlightOsu(m) => m.set_setting(name, value),
+            Self::AccuracyChallengeOsu(m) => m.set_setting(name, value),
+            Self::TargetPracticeOsu(m) => m.set_setting(name, value),
+            Self::DifficultyAdjustOsu(m) => m.set_setting(name, value),
+            Self::ClassicOsu(m) => m.set_setting(name, value),
+            Self::RandomOsu(m) => m.set_setting(name, value),
+            Self::MirrorOsu(m) => m.set_setting(name, value),
+            Self::WiggleOsu(m) => m.set_setting(name, value),
+            Self::GrowOsu(m) => m.set_setting(name, value),
+            Self::DeflateOsu(m) => m.set_setting(name, value),
+            Self::WindUpOsu(m) => m.set_setting(name, value),
+            Self::WindDownOsu(m) => m.set_setting(name, value),
+            Self::BarrelRollOsu(m) => m.set_setting(name, value),
+            Self::ApproachDifferentOsu(m) => m.set_setting(name, value),
+            Self::MutedOsu(m) => m.set_setting(name, value),
+            Self::NoScopeOsu(m) => m.set_setting(name, value),
+            Self::MagnetisedOsu(m) => m.set_setting(name, value),
+            Self::RepelOsu(m) => m.set_setting(name, value),
+            Self::AdaptiveSpeedOsu(m) => m.set_setting(name, value),
+            Self::DepthOsu(m) => m.set_setting(name, value),
+            Self::BloomOsu(m) => m.set_setting(name, value),
+            Self::HalfTimeTaiko(m) => m.set_setting(name, value),
+            Self::DaycoreTaiko(m) => m.set_setting(name, value),
+            Self::SimplifiedRhythmTaiko(m) => m.set_setting(name, value),
+            Self::SuddenDeathTaiko(m) => m.set_setting(name, value),
+            Self::PerfectTaiko(m) => m.set_setting(name, value),
+            Self::DoubleTimeTaiko(m) => m.set_setting(name, value),
+            Self::NightcoreTaiko(m) => m.set_setting(name, value),
+            Self::FlashlightTaiko(m) => m.set_setting(name, value),
+            Self::AccuracyChallengeTaiko(m) => m.set_setting(name, value),
+            Self::RandomTaiko(m) => m.set_setting(name, value),
+            Self::DifficultyAdjustTaiko(m) => m.set_setting(name, value),
+            Self::WindUpTaiko(m) => m.set_setting(name, value),
+            Self::WindDownTaiko(m) => m.set_setting(name, value),
+            Self::MutedTaiko(m) => m.set_setting(name, value),
+            Self::AdaptiveSpeedTaiko(m) => m.set_setting(name, value),
+            Self::EasyCatch(m) => m.set_setting(name, value),
+            Self::HalfTimeCatch(m) => m.set_setting(name, value),
+            Self::DaycoreCatch(m) => m.set_setting(name, value),
+            Self::SuddenDeathCatch(m) => m.set_setting(name, value),
+            Self::PerfectCatch(m) => m.set_setting(name, value),
+            Self::DoubleTimeCatch(m) => m.set_setting(name, value),
+            Self::NightcoreCatch(m) => m.set_setting(name, value),
+            Self::FlashlightCatch(m) => m.set_setting(name, value),
+            Self::AccuracyChallengeCatch(m) => m.set_setting(name, value),
+            Self::DifficultyAdjustCatch(m) => m.set_setting(name, value),
+            Self::WindUpCatch(m) => m.set_setting(name, value),
+            Self::WindDownCatch(m) => m.set_setting(name, value),
+            Self::MutedCatch(m) => m.set_setting(name, value),
+            Self::NoScopeCatch(m) => m.set_setting(name, value),
+            Self::EasyMania(m) => m.set_setting(name, value),
+            Self::HalfTimeMania(m) => m.set_setting(name, value),
+            Self::DaycoreMania(m) => m.set_setting(name, value),
+            Self::SuddenDeathMania(m) => m.set_setting(name, value),
+            Self::PerfectMania(m) => m.set_setting(name, value),
+            Self::DoubleTimeMania(m) => m.set_setting(name, value),
+            Self::NightcoreMania(m) => m.set_setting(name, value),
+            Self::CoverMania(m) => m.set_setting(name, value),
+            Self::FlashlightMania(m) => m.set_setting(name, value),
+            Self::AccuracyChallengeMania(m) => m.set_setting(name, value),
+            Self::RandomMania(m) => m.set_setting(name, value),
+            Self::DifficultyAdjustMania(m) => m.set_setting(name, value),
+            Self::WindUpMania(m) => m.set_setting(name, value),
+            Self::WindDownMania(m) => m.set_setting(name, value),
+            Self::MutedMania(m) => m.set_setting(name, value),
+            Self::AdaptiveSpeedMania(m) => m.set_setting(name, value),
+            _ => false,
+        }
+    }
 }
 impl From<GameMod> for GameModSimple {
     fn from(gamemod: GameMod) -> Self {
@@ -8,8 +8,9 @@ use std::{
 };
 
 use crate::{
+    error::AcronymsParseError,
     generated_mods::{DoubleTimeOsu, NightcoreOsu, PerfectOsu, SuddenDeathOsu},
-    util, GameModsLegacy,
+    util, GameModsFormatter, GameModsLegacy,
 };
 
 use crate::GameMode;
@@ -296,6 +297,9 @@ impl GameModsIntermode {
     ///
     /// Returns `None` if an unknown acronym was encountered.
     ///
+    /// Use [`GameModsIntermode::parse_acronyms`] to get details on why
+    /// parsing failed.
+    ///
     /// # Example
     /// ```rust
     /// use rosu_mods::GameModsIntermode;
@@ -306,59 +310,102 @@ impl GameModsIntermode {
     /// assert!(GameModsIntermode::try_from_acronyms("QQQ").is_none());
     /// ```
     pub fn try_from_acronyms(s: &str) -> Option<Self> {
-        let uppercased = util::to_uppercase(s);
-
-        if uppercased == "NM" {
-            return Some(Self::new());
-        }
+        Self::parse_acronyms(s).ok()
+    }
 
-        // We currently don't allow a gamemod to have an acronym of length 1
-        if s.len() == 1 {
-            return None;
+    /// Parse a combination of mod acronyms into [`GameModsIntermode`].
+    ///
+    /// On failure, the returned error contains the byte offset and substring
+    /// that caused it as well as the closest known acronym.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{error::AcronymsParseErrorKind, GameModsIntermode};
+    ///
+    /// let mods = GameModsIntermode::parse_acronyms("HDSV2DT").unwrap();
+    /// assert_eq!(mods.to_string(), "DTHDSV2");
+    ///
+    /// let err = GameModsIntermode::parse_acronyms("HDRHDT").unwrap_err();
+    /// assert_eq!(err.offset, 2);
+    /// assert_eq!(err.substring.as_ref(), "RH");
+    /// assert_eq!(err.kind, AcronymsParseErrorKind::UnknownAcronym);
+    /// assert_eq!(err.suggestion.unwrap().as_str(), "HR");
+    ///
+    /// let err = GameModsIntermode::parse_acronyms("SV2K").unwrap_err();
+    /// assert_eq!(err.kind, AcronymsParseErrorKind::AmbiguousSplit);
+    /// ```
+    pub fn parse_acronyms(s: &str) -> Result<Self, AcronymsParseError> {
+        if s.eq_ignore_ascii_case("NM") {
+            return Ok(Self::new());
         }
 
-        let mut remaining = uppercased.as_ref();
-        let mut mods = BTreeSet::new();
-
-        while !remaining.is_empty() {
-            // Split off the first two characters and check if it's an acronym
-            let (candidate, rest) = util::split_prefix::<2>(remaining);
+        let inner = split_acronyms(s)?.into_iter().collect();
 
-            // SAFETY: `candidate` is guaranteed to be of length 2 and has been capitalized
-            let acronym = unsafe { Acronym::from_str_unchecked(candidate) };
-            let gamemod = GameModIntermode::from_acronym(acronym);
-
-            if !matches!(gamemod, GameModIntermode::Unknown(_)) && rest.len() != 1 {
-                mods.insert(gamemod);
-                remaining = rest;
+        Ok(Self { inner })
+    }
 
-                continue;
-            }
+    /// Parse a combination of mod acronyms into [`GameModsIntermode`] while
+    /// accepting common notations.
+    ///
+    /// - Acronyms may be separated by whitespace, `,`, `;`, `|`, `/`, or `+`.
+    /// - Acronyms after a `-` are removed from the result until the next `+`.
+    /// - `NM`, `None`, and `No Mod` denote no mods.
+    /// - Two- and three-letter acronyms can be mixed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameModsIntermode;
+    ///
+    /// let parse = |s| GameModsIntermode::parse_lenient(s).unwrap().to_string();
+    ///
+    /// assert_eq!(parse("+HD,DT"), "DTHD");
+    /// assert_eq!(parse("hd+dt"), "DTHD");
+    /// assert_eq!(parse("+HDDTNF -NF"), "DTHD");
+    /// assert_eq!(parse("SV2HD"), "HDSV2");
+    /// assert_eq!(parse("No Mod"), "NM");
+    ///
+    /// let err = GameModsIntermode::parse_lenient("HD, QQ").unwrap_err();
+    /// assert_eq!(err.offset, 4);
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, AcronymsParseError> {
+        let mut included = Self::new();
+        let mut excluded = Vec::new();
 
-            // Repeat for the first three characters
-            let (candidate, rest) = util::split_prefix::<3>(remaining);
+        for group in util::lenient_groups(s) {
+            let mods = split_acronyms(group.acronyms).map_err(|mut err| {
+                err.offset += group.offset;
 
-            // SAFETY: `candidate` is guaranteed to be of length 3 and has been capitalized
-            let acronym = unsafe { Acronym::from_str_unchecked(candidate) };
-            let gamemod = GameModIntermode::from_acronym(acronym);
+                err
+            })?;
 
-            if matches!(gamemod, GameModIntermode::Unknown(_)) {
-                return None;
+            if group.excluded {
+                excluded.extend(mods);
+            } else {
+                included.extend(mods);
             }
-
-            mods.insert(gamemod);
-            remaining = rest;
         }
 
-        Some(Self { inner: mods })
+        included.remove_all(excluded);
+
+        Ok(included)
     }
 
     /// Parse a combination of mod acronyms into [`GameModsIntermode`].
     ///
+    /// Two- and three-letter acronyms can be mixed just like for
+    /// [`parse_acronyms`]. If the string can't be split into known acronyms,
+    /// it is instead split greedily and the unrecognized parts are kept as
+    /// [`GameModIntermode::Unknown`].
+    ///
+    /// [`parse_acronyms`]: GameModsIntermode::parse_acronyms
+    ///
     /// # Example
     /// ```rust
     /// use rosu_mods::GameModsIntermode;
     ///
+    /// let hdsv2 = GameModsIntermode::from_acronyms("SV2HD");
+    /// assert_eq!(hdsv2.to_string(), "HDSV2");
+    ///
     /// let hdhrwu = GameModsIntermode::from_acronyms("HRWUHD");
     /// assert_eq!(hdhrwu.len(), 3);
     /// assert_eq!(hdhrwu.to_string(), "HDHRWU");
@@ -375,6 +422,12 @@ impl GameModsIntermode {
             return Self::new();
         }
 
+        if let Ok(mods) = split_acronyms(s) {
+            return Self {
+                inner: mods.into_iter().collect(),
+            };
+        }
+
         let mut mods = BTreeSet::new();
 
         // We currently don't allow a gamemod to have an acronym of length 1
@@ -583,6 +636,48 @@ impl GameModsIntermode {
     pub fn try_as_legacy(&self) -> Option<GameModsLegacy> {
         self.checked_bits().map(GameModsLegacy::from_bits)
     }
+
+    /// Returns a [`GameModsFormatter`] to configure how [`GameModsIntermode`]
+    /// are displayed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameModIntermode, GameModsIntermode};
+    ///
+    /// let mods: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::Easy]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(mods.format().full_names(true).separator(", ").to_string(), "Easy, Hidden");
+    /// ```
+    pub const fn format(&self) -> GameModsFormatter<'_> {
+        GameModsFormatter::intermode(self)
+    }
+}
+
+/// Splits a string of acronyms into mods while keeping their order.
+pub(crate) fn split_acronyms(s: &str) -> Result<Vec<GameModIntermode>, AcronymsParseError> {
+    let lookup = |candidate: &str| {
+        // SAFETY: `candidate` is of length 2 or 3, ASCII, and capitalized
+        let acronym = unsafe { Acronym::from_str_unchecked(candidate) };
+
+        match GameModIntermode::from_acronym(acronym) {
+            GameModIntermode::Unknown(_) => None,
+            gamemod => Some(gamemod),
+        }
+    };
+
+    let suggest = |substring: &str| {
+        let acronyms: Vec<_> = GameModIntermode::ALL
+            .iter()
+            .map(GameModIntermode::acronym)
+            .collect();
+        let candidates = acronyms.iter().map(Acronym::as_str);
+
+        util::closest_match(substring, candidates).and_then(|acronym| acronym.parse().ok())
+    };
+
+    util::split_acronyms(s, lookup, suggest)
 }
 
 impl Debug for GameModsIntermode {
@@ -833,6 +928,8 @@ const _: () = {
 
 #[cfg(test)]
 mod tests {
+    use crate::error::AcronymsParseErrorKind;
+
     use super::*;
 
     #[test]
@@ -889,6 +986,45 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn parse_acronyms_mixed_lengths() {
+        let mods = GameModsIntermode::parse_acronyms("sv2hd10kDt").unwrap();
+
+        let mut expected = GameModsIntermode::default();
+        expected.insert(GameModIntermode::ScoreV2);
+        expected.insert(GameModIntermode::Hidden);
+        expected.insert(GameModIntermode::TenKeys);
+        expected.insert(GameModIntermode::DoubleTime);
+
+        assert_eq!(mods, expected);
+        assert_eq!(GameModsIntermode::from_acronyms("sv2hd10kDt"), expected);
+    }
+
+    #[test]
+    fn parse_acronyms_unknown() {
+        let err = GameModsIntermode::parse_acronyms("HDdhQQ").unwrap_err();
+
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.substring.as_ref(), "dh");
+        assert_eq!(err.kind, AcronymsParseErrorKind::UnknownAcronym);
+        assert_eq!(err.suggestion, Some(GameModIntermode::Hidden.acronym()));
+
+        let err = GameModsIntermode::parse_acronyms("HDQQQ").unwrap_err();
+
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.substring.as_ref(), "QQQ");
+        assert_eq!(err.suggestion, None);
+    }
+
+    #[test]
+    fn parse_acronyms_ambiguous() {
+        let err = GameModsIntermode::parse_acronyms("HDSV2K").unwrap_err();
+
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.substring.as_ref(), "SV2K");
+        assert_eq!(err.kind, AcronymsParseErrorKind::AmbiguousSplit);
+    }
+
     #[test]
     fn contains() {
         let mut mods = GameModsIntermode::default();
@@ -939,6 +1075,61 @@ mod tests {
         assert_eq!(intersection.next(), None);
     }
 
+    #[test]
+    fn catalog() {
+        for gamemod in GameModIntermode::ALL {
+            assert_eq!(GameModIntermode::from_acronym(gamemod.acronym()), *gamemod);
+        }
+
+        let mut per_mode = GameModsIntermode::new();
+
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            for gamemod in GameMod::all_for_mode(mode) {
+                assert_eq!(gamemod.mode(), mode);
+                assert!(!matches!(gamemod.intermode(), GameModIntermode::Unknown(_)));
+                per_mode.insert(gamemod.intermode());
+            }
+        }
+
+        assert_eq!(per_mode.len(), GameModIntermode::ALL.len());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(GameModIntermode::DoubleTime.name(), "Double Time");
+        assert_eq!(GameModIntermode::ScoreV2.name(), "Score V2");
+        assert_eq!(
+            GameMod::AccuracyChallengeTaiko(Default::default()).name(),
+            "Accuracy Challenge"
+        );
+        assert_eq!(
+            GameModIntermode::Unknown(Default::default()).name(),
+            "Unknown"
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            GameModIntermode::from_name("DoubleTime"),
+            Some(GameModIntermode::DoubleTime)
+        );
+        assert_eq!(
+            GameModIntermode::from_name("Double Time"),
+            Some(GameModIntermode::DoubleTime)
+        );
+        assert_eq!(
+            GameModIntermode::from_name(" hard  rock"),
+            Some(GameModIntermode::HardRock)
+        );
+        assert_eq!(GameModIntermode::from_name("Double"), None);
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
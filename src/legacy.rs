@@ -5,7 +5,9 @@ use std::{
 };
 
 use crate::{
-    error::GameModsLegacyParseError, iter::GameModsLegacyIter, util, Acronym, GameModsIntermode,
+    error::{AcronymsParseError, GameModsLegacyParseError},
+    iter::GameModsLegacyIter,
+    mod_manual, util, Acronym, GameMod, GameMode, GameMods, GameModsFormatter, GameModsIntermode,
 };
 
 /// Lightweight bitflag type for legacy mods.
@@ -59,12 +61,13 @@ impl GameModsLegacy {
     pub const DoubleTime: Self = Self::from_bits_retain(1 << 6);
     pub const Relax: Self = Self::from_bits_retain(1 << 7);
     pub const HalfTime: Self = Self::from_bits_retain(1 << 8);
-    pub const Nightcore: Self = Self::from_bits_retain((1 << 9) | Self::DoubleTime.bits());
+    pub const Nightcore: Self =
+        Self::from_bits_retain(Self::NIGHTCORE_BIT | Self::DoubleTime.bits());
     pub const Flashlight: Self = Self::from_bits_retain(1 << 10);
     pub const Autoplay: Self = Self::from_bits_retain(1 << 11);
     pub const SpunOut: Self = Self::from_bits_retain(1 << 12);
     pub const Autopilot: Self = Self::from_bits_retain(1 << 13);
-    pub const Perfect: Self = Self::from_bits_retain((1 << 14) | Self::SuddenDeath.bits());
+    pub const Perfect: Self = Self::from_bits_retain(Self::PERFECT_BIT | Self::SuddenDeath.bits());
     pub const Key4: Self = Self::from_bits_retain(1 << 15);
     pub const Key5: Self = Self::from_bits_retain(1 << 16);
     pub const Key6: Self = Self::from_bits_retain(1 << 17);
@@ -81,6 +84,13 @@ impl GameModsLegacy {
     pub const Key2: Self = Self::from_bits_retain(1 << 28);
     pub const ScoreV2: Self = Self::from_bits_retain(1 << 29);
     pub const Mirror: Self = Self::from_bits_retain(1 << 30);
+
+    /// The bit of [`Nightcore`](Self::Nightcore) without the one of
+    /// [`DoubleTime`](Self::DoubleTime).
+    const NIGHTCORE_BIT: u32 = 1 << 9;
+    /// The bit of [`Perfect`](Self::Perfect) without the one of
+    /// [`SuddenDeath`](Self::SuddenDeath).
+    const PERFECT_BIT: u32 = 1 << 14;
 }
 
 impl GameModsLegacy {
@@ -96,6 +106,101 @@ impl GameModsLegacy {
         }
     }
 
+    /// Returns the score multiplier of the mods in osu!stable for the given
+    /// [`GameMode`] i.e. the product of all contained mods' multipliers.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_mods::{GameModsLegacy, GameMode};
+    ///
+    /// let hdhr = GameModsLegacy::Hidden | GameModsLegacy::HardRock;
+    /// assert_eq!(hdhr.score_multiplier(GameMode::Osu), 1.06 * 1.06);
+    /// assert_eq!(hdhr.score_multiplier(GameMode::Catch), 1.06 * 1.12);
+    /// assert_eq!(hdhr.score_multiplier(GameMode::Mania), 1.0);
+    /// ```
+    pub const fn score_multiplier(self, mode: GameMode) -> f64 {
+        const KEYS: GameModsLegacy = GameModsLegacy::Key1
+            .union(GameModsLegacy::Key2)
+            .union(GameModsLegacy::Key3)
+            .union(GameModsLegacy::Key4)
+            .union(GameModsLegacy::Key5)
+            .union(GameModsLegacy::Key6)
+            .union(GameModsLegacy::Key7)
+            .union(GameModsLegacy::Key8)
+            .union(GameModsLegacy::Key9);
+
+        let mut multiplier = 1.0;
+
+        if self.contains(Self::NoFail) {
+            multiplier *= 0.5;
+        }
+
+        if self.contains(Self::Easy) {
+            multiplier *= 0.5;
+        }
+
+        match mode {
+            GameMode::Osu | GameMode::Taiko => {
+                if self.contains(Self::HalfTime) {
+                    multiplier *= 0.3;
+                }
+
+                if self.contains(Self::Hidden) {
+                    multiplier *= 1.06;
+                }
+
+                if self.contains(Self::HardRock) {
+                    multiplier *= 1.06;
+                }
+
+                if self.contains(Self::DoubleTime) {
+                    multiplier *= 1.12;
+                }
+
+                if self.contains(Self::Flashlight) {
+                    multiplier *= 1.12;
+                }
+
+                if self.contains(Self::SpunOut) {
+                    multiplier *= 0.9;
+                }
+            }
+            GameMode::Catch => {
+                if self.contains(Self::HalfTime) {
+                    multiplier *= 0.3;
+                }
+
+                if self.contains(Self::Hidden) {
+                    multiplier *= 1.06;
+                }
+
+                if self.contains(Self::HardRock) {
+                    multiplier *= 1.12;
+                }
+
+                if self.contains(Self::DoubleTime) {
+                    multiplier *= 1.06;
+                }
+
+                if self.contains(Self::Flashlight) {
+                    multiplier *= 1.12;
+                }
+            }
+            GameMode::Mania => {
+                // Difficulty increasing mods don't affect the score in mania
+                if self.contains(Self::HalfTime) {
+                    multiplier *= 0.5;
+                }
+
+                if self.intersects(KEYS) {
+                    multiplier *= 0.9;
+                }
+            }
+        }
+
+        multiplier
+    }
+
     /// Returns the amount of contained mods.
     ///
     /// # Example
@@ -137,13 +242,230 @@ impl GameModsLegacy {
     pub fn to_intermode(self) -> GameModsIntermode {
         GameModsIntermode::from_bits(self.bits())
     }
+
+    /// The key count of an osu!mania beatmap with the given amount of keys
+    /// after applying the mods.
+    ///
+    /// The bits `Key1` through `Key9` replace the key count and `KeyCoop`
+    /// doubles it. These mods only take effect on converted beatmaps.
+    /// If multiple key bits are set, the largest key count applies.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameModsLegacy;
+    ///
+    /// let mods = GameModsLegacy::Key5 | GameModsLegacy::KeyCoop;
+    ///
+    /// assert_eq!(mods.mania_key_count(4, true), 10);
+    /// assert_eq!(mods.mania_key_count(4, false), 4);
+    /// ```
+    pub fn mania_key_count(self, original_keys: u8, is_convert: bool) -> u8 {
+        mod_manual::mania_key_count(self.to_intermode(), original_keys, is_convert)
+    }
+
+    /// Convert [`GameModsLegacy`] to [`GameMods`] the same way osu!lazer
+    /// imports osu!stable scores.
+    ///
+    /// Mirrors the rulesets' `ConvertFromLegacyMods`:
+    /// - Mods that the mode's ruleset does not convert are ignored.
+    /// - `NC` and `PF` replace `DT` and `SD`, even if only their own bit is set.
+    /// - `CN` replaces `AT`.
+    /// - `KeyCoop` becomes `DS` and the key bits become `1K` through `9K`.
+    ///
+    /// Additionally, `CL` is added as is done for all imported scores.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameModsLegacy, GameMode};
+    ///
+    /// let mods = GameModsLegacy::Nightcore | GameModsLegacy::Hidden | GameModsLegacy::Key4;
+    ///
+    /// assert_eq!(mods.to_lazer_mods(GameMode::Osu).to_string(), "HDNCCL");
+    /// assert_eq!(mods.to_lazer_mods(GameMode::Mania).to_string(), "HDNC4KCL");
+    /// ```
+    pub fn to_lazer_mods(self, mode: GameMode) -> GameMods {
+        const COMMON: GameModsLegacy = GameModsLegacy::NoFail
+            .union(GameModsLegacy::Easy)
+            .union(GameModsLegacy::Hidden)
+            .union(GameModsLegacy::HardRock)
+            .union(GameModsLegacy::Perfect)
+            .union(GameModsLegacy::Nightcore)
+            .union(GameModsLegacy::HalfTime)
+            .union(GameModsLegacy::Flashlight)
+            .union(GameModsLegacy::Autoplay)
+            .union(GameModsLegacy::Cinema)
+            .union(GameModsLegacy::ScoreV2);
+
+        const OSU: GameModsLegacy = COMMON
+            .union(GameModsLegacy::TouchDevice)
+            .union(GameModsLegacy::Relax)
+            .union(GameModsLegacy::SpunOut)
+            .union(GameModsLegacy::Autopilot)
+            .union(GameModsLegacy::Target);
+
+        const TAIKO: GameModsLegacy = COMMON
+            .union(GameModsLegacy::Relax)
+            .union(GameModsLegacy::Random);
+
+        const CATCH: GameModsLegacy = COMMON.union(GameModsLegacy::Relax);
+
+        const MANIA: GameModsLegacy = COMMON
+            .union(GameModsLegacy::Key1)
+            .union(GameModsLegacy::Key2)
+            .union(GameModsLegacy::Key3)
+            .union(GameModsLegacy::Key4)
+            .union(GameModsLegacy::Key5)
+            .union(GameModsLegacy::Key6)
+            .union(GameModsLegacy::Key7)
+            .union(GameModsLegacy::Key8)
+            .union(GameModsLegacy::Key9)
+            .union(GameModsLegacy::KeyCoop)
+            .union(GameModsLegacy::FadeIn)
+            .union(GameModsLegacy::Random)
+            .union(GameModsLegacy::Mirror);
+
+        let mut mods = self;
+
+        // osu!lazer only checks the bit of NC and PF, not the one of DT and SD
+        if mods.bits() & Self::NIGHTCORE_BIT > 0 {
+            mods |= Self::Nightcore;
+        }
+
+        if mods.bits() & Self::PERFECT_BIT > 0 {
+            mods |= Self::Perfect;
+        }
+
+        if mods.contains(Self::Cinema) {
+            mods.remove(Self::Autoplay);
+        }
+
+        let supported = match mode {
+            GameMode::Osu => OSU,
+            GameMode::Taiko => TAIKO,
+            GameMode::Catch => CATCH,
+            GameMode::Mania => MANIA,
+        };
+
+        let mut lazer_mods = mods.intersection(supported).to_intermode().with_mode(mode);
+        lazer_mods.insert(GameMod::new("CL", mode));
+
+        lazer_mods
+    }
+
+    /// Parse a combination of mod acronyms into [`GameModsLegacy`] while
+    /// accepting common notations.
+    ///
+    /// - Acronyms may be separated by whitespace, `,`, `;`, `|`, `/`, or `+`.
+    /// - Acronyms after a `-` are removed from the result until the next `+`.
+    /// - `NM`, `None`, and `No Mod` denote no mods.
+    /// - Two- and three-letter acronyms such as `SV2` can be mixed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameModsLegacy;
+    ///
+    /// let parse = |s| GameModsLegacy::parse_lenient(s).unwrap();
+    ///
+    /// assert_eq!(parse("HD DT"), GameModsLegacy::Hidden | GameModsLegacy::DoubleTime);
+    /// assert_eq!(parse("+HDDT -NF"), GameModsLegacy::Hidden | GameModsLegacy::DoubleTime);
+    /// assert_eq!(parse("SV2HD"), GameModsLegacy::ScoreV2 | GameModsLegacy::Hidden);
+    /// assert_eq!(parse("None"), GameModsLegacy::NoMod);
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, AcronymsParseError> {
+        let mut included = Self::NoMod;
+        let mut excluded = Self::NoMod;
+
+        let suggest = |substring: &str| {
+            util::closest_match(substring, Self::ACRONYMS).and_then(|acronym| acronym.parse().ok())
+        };
+
+        for group in util::lenient_groups(s) {
+            let mods = util::split_acronyms(group.acronyms, Self::from_acronym, suggest).map_err(
+                |mut err| {
+                    err.offset += group.offset;
+
+                    err
+                },
+            )?;
+
+            let mods = mods.into_iter().fold(Self::NoMod, |mods, m| mods | m);
+
+            if group.excluded {
+                excluded |= mods;
+            } else {
+                included |= mods;
+            }
+        }
+
+        Ok(included - excluded)
+    }
+
+    /// Returns a [`GameModsFormatter`] to configure how [`GameModsLegacy`]
+    /// are displayed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameModsLegacy;
+    ///
+    /// let mods = GameModsLegacy::Hidden | GameModsLegacy::DoubleTime;
+    ///
+    /// assert_eq!(mods.format().prefix("+").to_string(), "+HDDT");
+    /// assert_eq!(mods.format().separator(" ").to_string(), "HD DT");
+    /// ```
+    pub const fn format(self) -> GameModsFormatter<'static> {
+        GameModsFormatter::legacy(self)
+    }
 }
 
 impl GameModsLegacy {
+    /// The acronyms of all legacy mods.
+    const ACRONYMS: [&'static str; 29] = [
+        "NM", "NF", "EZ", "TD", "HD", "HR", "SD", "DT", "RX", "HT", "NC", "FL", "SO", "AP", "PF",
+        "FI", "RD", "TP", "V2", "MR", "1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K",
+    ];
+
     const fn all() -> Self {
         Self::from_bits_retain(u32::MAX >> 2)
     }
 
+    /// Parse an uppercase acronym.
+    fn from_acronym(acronym: &str) -> Option<Self> {
+        let m = match acronym {
+            "NM" => Self::NoMod,
+            "NF" => Self::NoFail,
+            "EZ" => Self::Easy,
+            "TD" => Self::TouchDevice,
+            "HD" => Self::Hidden,
+            "HR" => Self::HardRock,
+            "SD" => Self::SuddenDeath,
+            "DT" => Self::DoubleTime,
+            "RX" | "RL" => Self::Relax,
+            "HT" => Self::HalfTime,
+            "NC" => Self::Nightcore,
+            "FL" => Self::Flashlight,
+            "SO" => Self::SpunOut,
+            "AP" => Self::Autopilot,
+            "PF" => Self::Perfect,
+            "FI" => Self::FadeIn,
+            "RD" => Self::Random,
+            "TP" => Self::Target,
+            "V2" | "SV2" => Self::ScoreV2,
+            "MR" => Self::Mirror,
+            "1K" | "K1" => Self::Key1,
+            "2K" | "K2" => Self::Key2,
+            "3K" | "K3" => Self::Key3,
+            "4K" | "K4" => Self::Key4,
+            "5K" | "K5" => Self::Key5,
+            "6K" | "K6" => Self::Key6,
+            "7K" | "K7" => Self::Key7,
+            "8K" | "K8" => Self::Key8,
+            "9K" | "K9" => Self::Key9,
+            _ => return None,
+        };
+
+        Some(m)
+    }
+
     /// Get the underlying bits value.
     ///
     /// The returned value is exactly the bits set in this flags value.
@@ -372,47 +694,17 @@ impl FromStr for GameModsLegacy {
     type Err = GameModsLegacyParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut res = Self::default();
-        let upper = util::to_uppercase(s);
-
-        for m in util::cut(&upper, 2) {
-            let m = match m {
-                "NM" => Self::NoMod,
-                "NF" => Self::NoFail,
-                "EZ" => Self::Easy,
-                "TD" => Self::TouchDevice,
-                "HD" => Self::Hidden,
-                "HR" => Self::HardRock,
-                "SD" => Self::SuddenDeath,
-                "DT" => Self::DoubleTime,
-                "RX" | "RL" => Self::Relax,
-                "HT" => Self::HalfTime,
-                "NC" => Self::Nightcore,
-                "FL" => Self::Flashlight,
-                "SO" => Self::SpunOut,
-                "AP" => Self::Autopilot,
-                "PF" => Self::Perfect,
-                "FI" => Self::FadeIn,
-                "RD" => Self::Random,
-                "TP" => Self::Target,
-                "V2" => Self::ScoreV2,
-                "MR" => Self::Mirror,
-                "1K" | "K1" => Self::Key1,
-                "2K" | "K2" => Self::Key2,
-                "3K" | "K3" => Self::Key3,
-                "4K" | "K4" => Self::Key4,
-                "5K" | "K5" => Self::Key5,
-                "6K" | "K6" => Self::Key6,
-                "7K" | "K7" => Self::Key7,
-                "8K" | "K8" => Self::Key8,
-                "9K" | "K9" => Self::Key9,
-                _ => return Err(GameModsLegacyParseError { mods: Box::from(s) }),
-            };
-
-            res.insert(m);
+        let suggest = |substring: &str| {
+            util::closest_match(substring, Self::ACRONYMS).and_then(|acronym| acronym.parse().ok())
+        };
+
+        match util::split_acronyms(s, Self::from_acronym, suggest) {
+            Ok(mods) => Ok(mods.into_iter().fold(Self::NoMod, |mods, m| mods | m)),
+            Err(cause) => Err(GameModsLegacyParseError {
+                mods: Box::from(s),
+                cause,
+            }),
         }
-
-        Ok(res)
     }
 }
 
@@ -543,6 +835,8 @@ const _: () = {
 
 #[cfg(test)]
 mod tests {
+    use crate::error::AcronymsParseErrorKind;
+
     use super::*;
 
     #[test]
@@ -561,6 +855,53 @@ mod tests {
         assert!(GameModsLegacy::from_str("HHDR").is_err());
     }
 
+    #[test]
+    fn try_from_str_error() {
+        let err = GameModsLegacy::from_str("HDrhDT").unwrap_err();
+
+        assert_eq!(err.cause.offset, 2);
+        assert_eq!(err.cause.substring.as_ref(), "rh");
+        assert_eq!(
+            err.cause.suggestion.map(|acronym| acronym.to_string()),
+            Some("HR".to_owned())
+        );
+
+        let err = GameModsLegacy::from_str("HDD").unwrap_err();
+
+        assert_eq!(err.cause.offset, 2);
+        assert_eq!(err.cause.substring.as_ref(), "D");
+
+        let err = GameModsLegacy::from_str("HRD").unwrap_err();
+
+        assert_eq!(err.cause.offset, 0);
+        assert_eq!(err.cause.kind, AcronymsParseErrorKind::AmbiguousSplit);
+    }
+
+    #[test]
+    fn try_from_str_three_letters() {
+        assert_eq!(
+            "SV2HD".parse::<GameModsLegacy>().unwrap(),
+            GameModsLegacy::ScoreV2 | GameModsLegacy::Hidden
+        );
+        assert_eq!(
+            "SV2".parse::<GameModsLegacy>().unwrap(),
+            GameModsLegacy::ScoreV2
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let mods = GameModsLegacy::parse_lenient("hd+dt | 4k").unwrap();
+        assert_eq!(
+            mods,
+            GameModsLegacy::Hidden | GameModsLegacy::DoubleTime | GameModsLegacy::Key4
+        );
+
+        let err = GameModsLegacy::parse_lenient("+HD -WU").unwrap_err();
+        assert_eq!(err.offset, 5);
+        assert_eq!(err.substring.as_ref(), "WU");
+    }
+
     #[test]
     fn iter() {
         let mut iter = GameModsLegacy::default().iter();
@@ -573,6 +914,58 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn score_multiplier() {
+        let mods = GameModsLegacy::Hidden | GameModsLegacy::Nightcore | GameModsLegacy::Flashlight;
+
+        let osu = mods.score_multiplier(GameMode::Osu);
+        assert!((osu - 1.06 * 1.12 * 1.12).abs() < f64::EPSILON);
+
+        let catch = mods.score_multiplier(GameMode::Catch);
+        assert!((catch - 1.06 * 1.06 * 1.12).abs() < f64::EPSILON);
+
+        let mania = (mods | GameModsLegacy::Key4 | GameModsLegacy::NoFail)
+            .score_multiplier(GameMode::Mania);
+        assert!((mania - 0.5 * 0.9).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn to_lazer_mods() {
+        let mods = GameModsLegacy::Perfect
+            | GameModsLegacy::Cinema
+            | GameModsLegacy::Autoplay
+            | GameModsLegacy::SpunOut
+            | GameModsLegacy::KeyCoop
+            | GameModsLegacy::Key7;
+
+        assert_eq!(mods.to_lazer_mods(GameMode::Osu).to_string(), "PFCLCNSO");
+        assert_eq!(mods.to_lazer_mods(GameMode::Taiko).to_string(), "PFCLCN");
+        assert_eq!(
+            mods.to_lazer_mods(GameMode::Mania).to_string(),
+            "PF7KCLDSCN"
+        );
+
+        // Only the NC bit without the DT bit
+        let nc = GameModsLegacy::from_bits_retain(GameModsLegacy::NIGHTCORE_BIT);
+        assert_eq!(nc.to_lazer_mods(GameMode::Catch).to_string(), "NCCL");
+
+        let sv2 = GameModsLegacy::ScoreV2 | GameModsLegacy::Mirror;
+        assert_eq!(sv2.to_lazer_mods(GameMode::Catch).to_string(), "CLSV2");
+        assert_eq!(sv2.to_lazer_mods(GameMode::Mania).to_string(), "CLMRSV2");
+    }
+
+    #[test]
+    fn mania_key_count() {
+        let mods = GameModsLegacy::Key1 | GameModsLegacy::Hidden;
+        assert_eq!(mods.mania_key_count(7, true), 1);
+        assert_eq!(GameModsLegacy::KeyCoop.mania_key_count(9, true), 18);
+        assert_eq!(GameModsLegacy::NoMod.mania_key_count(6, true), 6);
+
+        let multiple = GameModsLegacy::Key4 | GameModsLegacy::Key7;
+        assert_eq!(multiple.mania_key_count(5, true), 7);
+        assert_eq!(multiple.mania_key_count(5, false), 5);
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
@@ -161,6 +161,10 @@ extern crate pastey;
 pub mod macros;
 
 mod acronym;
+mod conflict;
+mod convert;
+mod difficulty;
+mod format;
 mod intermode;
 mod kind;
 mod legacy;
@@ -168,7 +172,9 @@ mod mod_manual;
 mod mode;
 mod mods;
 mod order;
+mod setting;
 mod simple;
+mod tournament;
 mod util;
 
 /// Error types
@@ -182,6 +188,9 @@ pub mod intersection;
 /// Iterator types for mods.
 pub mod iter;
 
+/// Validation of mods in multiplayer rooms as done by osu!lazer.
+pub mod multiplayer;
+
 #[cfg(feature = "rkyv")]
 #[doc(inline)]
 pub use generated_mods::rkyv;
@@ -195,11 +204,17 @@ pub use self::{mode::GameMode, mods::GameMods};
 #[doc(inline)]
 pub use self::{
     acronym::Acronym,
+    conflict::{ModConflict, SanitizeStrategy},
+    convert::{DroppedSetting, LegacyConversion, ModeConversion},
+    difficulty::{BeatmapDifficulty, HitWindows},
+    format::{FormatOrder, GameModsFormatter},
     generated_mods::{GameMod, GameModIntermode},
     intermode::GameModsIntermode,
     kind::GameModKind,
     legacy::GameModsLegacy,
+    setting::{SettingDefault, SettingKind, SettingRange, SettingSchema},
     simple::{GameModSimple, SettingSimple},
+    tournament::{Mappool, PoolSlot, SlotRules},
 };
 
 #[cfg(feature = "serde")]
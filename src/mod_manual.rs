@@ -1,35 +1,52 @@
-use super::GameMod;
+use super::{
+    difficulty::{BeatmapDifficulty, DifficultyOverrides},
+    error::{InvalidSetting, InvalidSettingReason, SettingsValidationError},
+    generated_mods::{FlashlightCatch, FlashlightOsu, FlashlightTaiko},
+    GameMod, GameModIntermode, GameMode, SettingRange, SettingSimple,
+};
 
 impl GameMod {
+    /// Default instances of all mods that are available in the given mode, in
+    /// the same order as osu!lazer lists them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameModKind, GameMode};
+    ///
+    /// let fun: Vec<_> = GameMod::all_for_mode(GameMode::Mania)
+    ///     .filter(|gamemod| gamemod.kind() == GameModKind::Fun)
+    ///     .map(|gamemod| gamemod.acronym().to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(fun, ["WU", "WD", "MU", "AS"]);
+    /// ```
+    pub fn all_for_mode(mode: GameMode) -> impl Iterator<Item = Self> {
+        GameModIntermode::all_for_mode(mode)
+            .iter()
+            .map(move |gamemod| Self::new(gamemod.acronym().as_str(), mode))
+    }
+
     /// The clock rate of the [`GameMod`].
     ///
     /// Returns `None` if there is no single clock rate.
     pub const fn clock_rate(&self) -> Option<f64> {
-        // TODO: replace with `Option::unwrap_or` when it's const stable
-        const fn unwrap_or(opt: Option<f64>, default: f64) -> f64 {
-            match opt {
-                Some(n) => n,
-                None => default,
-            }
-        }
-
         match self {
-            Self::DoubleTimeOsu(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::DoubleTimeTaiko(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::DoubleTimeCatch(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::DoubleTimeMania(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::NightcoreOsu(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::NightcoreTaiko(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::NightcoreCatch(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::NightcoreMania(m) => Some(unwrap_or(m.speed_change, 1.5)),
-            Self::HalfTimeOsu(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::HalfTimeTaiko(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::HalfTimeCatch(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::HalfTimeMania(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::DaycoreOsu(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::DaycoreTaiko(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::DaycoreCatch(m) => Some(unwrap_or(m.speed_change, 0.75)),
-            Self::DaycoreMania(m) => Some(unwrap_or(m.speed_change, 0.75)),
+            Self::DoubleTimeOsu(m) => Some(m.effective_speed_change()),
+            Self::DoubleTimeTaiko(m) => Some(m.effective_speed_change()),
+            Self::DoubleTimeCatch(m) => Some(m.effective_speed_change()),
+            Self::DoubleTimeMania(m) => Some(m.effective_speed_change()),
+            Self::NightcoreOsu(m) => Some(m.effective_speed_change()),
+            Self::NightcoreTaiko(m) => Some(m.effective_speed_change()),
+            Self::NightcoreCatch(m) => Some(m.effective_speed_change()),
+            Self::NightcoreMania(m) => Some(m.effective_speed_change()),
+            Self::HalfTimeOsu(m) => Some(m.effective_speed_change()),
+            Self::HalfTimeTaiko(m) => Some(m.effective_speed_change()),
+            Self::HalfTimeCatch(m) => Some(m.effective_speed_change()),
+            Self::HalfTimeMania(m) => Some(m.effective_speed_change()),
+            Self::DaycoreOsu(m) => Some(m.effective_speed_change()),
+            Self::DaycoreTaiko(m) => Some(m.effective_speed_change()),
+            Self::DaycoreCatch(m) => Some(m.effective_speed_change()),
+            Self::DaycoreMania(m) => Some(m.effective_speed_change()),
             Self::WindUpOsu(_) => None,
             Self::WindUpTaiko(_) => None,
             Self::WindUpCatch(_) => None,
@@ -44,4 +61,455 @@ impl GameMod {
             _ => Some(1.0),
         }
     }
+
+    /// The initial and final rate of mods that change the rate over time.
+    ///
+    /// The rate of `AdaptiveSpeed` depends on the player's performance so
+    /// only its initial rate is considered.
+    pub(crate) const fn rate_ramp(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::WindUpOsu(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpTaiko(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpCatch(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpMania(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindDownOsu(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindDownTaiko(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindDownCatch(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindDownMania(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::AdaptiveSpeedOsu(m) => {
+                Some((m.effective_initial_rate(), m.effective_initial_rate()))
+            }
+            Self::AdaptiveSpeedTaiko(m) => {
+                Some((m.effective_initial_rate(), m.effective_initial_rate()))
+            }
+            Self::AdaptiveSpeedMania(m) => {
+                Some((m.effective_initial_rate(), m.effective_initial_rate()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The score multiplier of the [`GameMod`] as used by osu!lazer.
+    ///
+    /// Multipliers of some mods depend on their settings e.g. the rate of
+    /// `DoubleTime` or whether `Flashlight` uses its default configuration.
+    pub fn score_multiplier(&self) -> f64 {
+        match self {
+            Self::EasyOsu(_) => 0.5,
+            Self::EasyTaiko(_) => 0.5,
+            Self::EasyCatch(_) => 0.5,
+            Self::EasyMania(_) => 0.5,
+            Self::NoFailOsu(_) => 0.5,
+            Self::NoFailTaiko(_) => 0.5,
+            Self::NoFailCatch(_) => 0.5,
+            Self::NoFailMania(_) => 0.5,
+            Self::HalfTimeOsu(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::HalfTimeTaiko(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::HalfTimeCatch(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::HalfTimeMania(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::DaycoreOsu(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::DaycoreTaiko(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::DaycoreCatch(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::DaycoreMania(m) => rate_decrease_multiplier(m.effective_speed_change()),
+            Self::DoubleTimeOsu(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::DoubleTimeTaiko(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::DoubleTimeCatch(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::DoubleTimeMania(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::NightcoreOsu(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::NightcoreTaiko(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::NightcoreCatch(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::NightcoreMania(m) => rate_increase_multiplier(m.effective_speed_change()),
+            Self::HardRockOsu(_) => 1.06,
+            Self::HardRockTaiko(_) => 1.06,
+            Self::HardRockCatch(_) => 1.12,
+            Self::HiddenOsu(_) => 1.06,
+            Self::HiddenTaiko(_) => 1.06,
+            Self::HiddenCatch(_) => 1.06,
+            Self::BlindsOsu(_) => 1.12,
+            Self::FlashlightOsu(m)
+                if m.clone().with_defaults_filled()
+                    == FlashlightOsu::default().with_defaults_filled() =>
+            {
+                1.12
+            }
+            Self::FlashlightTaiko(m)
+                if m.clone().with_defaults_filled()
+                    == FlashlightTaiko::default().with_defaults_filled() =>
+            {
+                1.12
+            }
+            Self::FlashlightCatch(m)
+                if m.clone().with_defaults_filled()
+                    == FlashlightCatch::default().with_defaults_filled() =>
+            {
+                1.12
+            }
+            Self::DifficultyAdjustOsu(_) => 0.5,
+            Self::DifficultyAdjustTaiko(_) => 0.5,
+            Self::DifficultyAdjustCatch(_) => 0.5,
+            Self::DifficultyAdjustMania(_) => 0.5,
+            Self::ClassicOsu(_) => 0.96,
+            Self::ClassicTaiko(_) => 0.96,
+            Self::ClassicCatch(_) => 0.96,
+            Self::ClassicMania(_) => 0.96,
+            Self::RelaxOsu(_) => 0.1,
+            Self::RelaxTaiko(_) => 0.1,
+            Self::RelaxCatch(_) => 0.1,
+            Self::AutopilotOsu(_) => 0.1,
+            Self::TargetPracticeOsu(_) => 0.1,
+            Self::SpunOutOsu(_) => 0.9,
+            Self::MagnetisedOsu(_) => 0.5,
+            Self::SynesthesiaOsu(_) => 0.8,
+            Self::SimplifiedRhythmTaiko(_) => 0.6,
+            Self::ConstantSpeedTaiko(_) => 0.9,
+            Self::WindUpOsu(_) => 0.5,
+            Self::WindUpTaiko(_) => 0.5,
+            Self::WindUpCatch(_) => 0.5,
+            Self::WindUpMania(_) => 0.5,
+            Self::WindDownOsu(_) => 0.5,
+            Self::WindDownTaiko(_) => 0.5,
+            Self::WindDownCatch(_) => 0.5,
+            Self::WindDownMania(_) => 0.5,
+            Self::AdaptiveSpeedOsu(_) => 0.5,
+            Self::AdaptiveSpeedTaiko(_) => 0.5,
+            Self::AdaptiveSpeedMania(_) => 0.5,
+            Self::InvertMania(_) => 0.4,
+            Self::NoReleaseMania(_) => 0.9,
+            Self::ConstantSpeedMania(_) => 0.9,
+            Self::HoldOffMania(_) => 0.9,
+            Self::OneKeyMania(_) => 0.9,
+            Self::TwoKeysMania(_) => 0.9,
+            Self::ThreeKeysMania(_) => 0.9,
+            Self::FourKeysMania(_) => 0.9,
+            Self::FiveKeysMania(_) => 0.9,
+            Self::SixKeysMania(_) => 0.9,
+            Self::SevenKeysMania(_) => 0.9,
+            Self::EightKeysMania(_) => 0.9,
+            Self::NineKeysMania(_) => 0.9,
+            Self::TenKeysMania(_) => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Apply the adjustments of the [`GameMod`] to the given
+    /// [`BeatmapDifficulty`].
+    ///
+    /// Only `HardRock`, `Easy`, and `DifficultyAdjust` affect the difficulty.
+    /// Just like in osu!lazer, osu!mania's `HardRock` and `Easy` are an
+    /// exception; they only scale the hit windows, see
+    /// [`GameMods::hit_windows`].
+    ///
+    /// [`GameMods::hit_windows`]: crate::GameMods::hit_windows
+    pub fn apply_to_difficulty(&self, difficulty: &mut BeatmapDifficulty) {
+        match self {
+            Self::HardRockOsu(_) | Self::HardRockTaiko(_) | Self::HardRockCatch(_) => {
+                difficulty.apply_hard_rock();
+            }
+            Self::EasyOsu(_) | Self::EasyTaiko(_) | Self::EasyCatch(_) => difficulty.apply_easy(),
+            Self::DifficultyAdjustOsu(m) => difficulty.apply_overrides(DifficultyOverrides {
+                cs: m.circle_size,
+                ar: m.approach_rate,
+                od: m.overall_difficulty,
+                hp: m.drain_rate,
+                extended_limits: m.effective_extended_limits(),
+            }),
+            Self::DifficultyAdjustTaiko(m) => difficulty.apply_overrides(DifficultyOverrides {
+                od: m.overall_difficulty,
+                hp: m.drain_rate,
+                extended_limits: m.effective_extended_limits(),
+                ..Default::default()
+            }),
+            Self::DifficultyAdjustCatch(m) => difficulty.apply_overrides(DifficultyOverrides {
+                cs: m.circle_size,
+                ar: m.approach_rate,
+                od: m.overall_difficulty,
+                hp: m.drain_rate,
+                extended_limits: m.effective_extended_limits(),
+            }),
+            Self::DifficultyAdjustMania(m) => difficulty.apply_overrides(DifficultyOverrides {
+                od: m.overall_difficulty,
+                hp: m.drain_rate,
+                extended_limits: m.effective_extended_limits(),
+                ..Default::default()
+            }),
+            _ => {}
+        }
+    }
+
+    /// Check whether all settings of the [`GameMod`] lie within the bounds
+    /// and precision that osu!lazer allows and whether they contradict each
+    /// other.
+    ///
+    /// Unset settings are always valid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{
+    ///     error::InvalidSettingReason,
+    ///     generated_mods::{DoubleTimeOsu, WindUpTaiko},
+    ///     GameMod,
+    /// };
+    ///
+    /// let dt = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(17.0),
+    ///     adjust_pitch: None,
+    /// });
+    ///
+    /// let err = dt.validate_settings().unwrap_err();
+    /// assert_eq!(err.invalid.len(), 1);
+    /// assert_eq!(err.invalid[0].setting, "speed_change");
+    ///
+    /// let wu = GameMod::WindUpTaiko(WindUpTaiko {
+    ///     initial_rate: Some(1.2),
+    ///     final_rate: Some(1.1),
+    ///     adjust_pitch: None,
+    /// });
+    ///
+    /// let err = wu.validate_settings().unwrap_err();
+    /// assert_eq!(
+    ///     err.invalid[0].reason,
+    ///     InvalidSettingReason::Contradiction { other: "initial_rate" },
+    /// );
+    /// ```
+    pub fn validate_settings(&self) -> Result<(), SettingsValidationError> {
+        let mut invalid = Vec::new();
+        self.collect_invalid_settings(&mut invalid);
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(SettingsValidationError { invalid })
+        }
+    }
+
+    pub(crate) fn collect_invalid_settings(&self, invalid: &mut Vec<InvalidSetting>) {
+        let acronym = self.acronym();
+
+        let extended_limits = match self {
+            Self::DifficultyAdjustOsu(m) => m.effective_extended_limits(),
+            Self::DifficultyAdjustTaiko(m) => m.effective_extended_limits(),
+            Self::DifficultyAdjustCatch(m) => m.effective_extended_limits(),
+            Self::DifficultyAdjustMania(m) => m.effective_extended_limits(),
+            _ => false,
+        };
+
+        for schema in self.settings_schema() {
+            let (Some(mut range), Some(SettingSimple::Number(value))) =
+                (schema.range, self.setting(schema.name))
+            else {
+                continue;
+            };
+
+            // <https://github.com/ppy/osu/blob/master/osu.Game/Rulesets/Mods/ModDifficultyAdjust.cs>
+            if extended_limits && schema.name != "scroll_speed" {
+                range.max = 11.0;
+
+                if schema.name == "approach_rate" {
+                    range.min = -10.0;
+                }
+            }
+
+            let reason = if !range.contains(value) {
+                InvalidSettingReason::OutOfRange {
+                    value,
+                    min: range.min,
+                    max: range.max,
+                }
+            } else if !range.is_on_step(value) {
+                InvalidSettingReason::OffStep {
+                    value,
+                    precision: range.precision,
+                }
+            } else {
+                continue;
+            };
+
+            invalid.push(InvalidSetting {
+                acronym,
+                setting: schema.name,
+                reason,
+            });
+        }
+
+        let ramp = match self {
+            Self::WindUpOsu(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpTaiko(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpCatch(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            Self::WindUpMania(m) => Some((m.effective_initial_rate(), m.effective_final_rate())),
+            // Swapping the rates lets both ramps be checked the same way
+            Self::WindDownOsu(m) => Some((m.effective_final_rate(), m.effective_initial_rate())),
+            Self::WindDownTaiko(m) => Some((m.effective_final_rate(), m.effective_initial_rate())),
+            Self::WindDownCatch(m) => Some((m.effective_final_rate(), m.effective_initial_rate())),
+            Self::WindDownMania(m) => Some((m.effective_final_rate(), m.effective_initial_rate())),
+            _ => None,
+        };
+
+        if let Some((slower, faster)) = ramp {
+            if slower >= faster {
+                invalid.push(InvalidSetting {
+                    acronym,
+                    setting: "final_rate",
+                    reason: InvalidSettingReason::Contradiction {
+                        other: "initial_rate",
+                    },
+                });
+            }
+        }
+    }
+
+    /// Unset all settings whose value is the default value at the
+    /// setting's precision.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod};
+    ///
+    /// let mut dt = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.5),
+    ///     adjust_pitch: Some(true),
+    /// });
+    ///
+    /// dt.normalize();
+    ///
+    /// let expected = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: None,
+    ///     adjust_pitch: Some(true),
+    /// });
+    ///
+    /// assert_eq!(dt, expected);
+    /// ```
+    pub fn normalize(&mut self) {
+        for schema in self.settings_schema() {
+            let Some(default) = schema.default else {
+                continue;
+            };
+
+            let is_default = self.setting(schema.name).is_some_and(|value| {
+                setting_eq(&value, &SettingSimple::from(default), schema.range)
+            });
+
+            if is_default {
+                self.set_setting(schema.name, None);
+            }
+        }
+    }
+
+    /// Check whether two [`GameMod`]s are the same when treating unset
+    /// settings as their default value and comparing numbers at each
+    /// setting's precision.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod};
+    ///
+    /// let a = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: None,
+    ///     adjust_pitch: None,
+    /// });
+    ///
+    /// let b = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.5),
+    ///     adjust_pitch: Some(false),
+    /// });
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        if self.acronym() != other.acronym() || self.mode() != other.mode() {
+            return false;
+        }
+
+        self.settings_schema().iter().all(|schema| {
+            let default = schema.default.map(SettingSimple::from);
+
+            match (
+                self.setting(schema.name).or_else(|| default.clone()),
+                other.setting(schema.name).or(default),
+            ) {
+                (Some(a), Some(b)) => setting_eq(&a, &b, schema.range),
+                (None, None) => true,
+                _ => false,
+            }
+        })
+    }
+}
+
+impl GameModIntermode {
+    /// The amount of keys that a key mod converts beatmaps to.
+    pub(crate) const fn key_count(self) -> Option<u8> {
+        match self {
+            Self::OneKey => Some(1),
+            Self::TwoKeys => Some(2),
+            Self::ThreeKeys => Some(3),
+            Self::FourKeys => Some(4),
+            Self::FiveKeys => Some(5),
+            Self::SixKeys => Some(6),
+            Self::SevenKeys => Some(7),
+            Self::EightKeys => Some(8),
+            Self::NineKeys => Some(9),
+            Self::TenKeys => Some(10),
+            _ => None,
+        }
+    }
+}
+
+/// The key count of an osu!mania beatmap after applying the mods.
+///
+/// Key mods and `DualStages` only affect converted beatmaps. If multiple key
+/// mods are present, the largest key count applies.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Mania/Mods/ManiaKeyMod.cs>
+pub(crate) fn mania_key_count<I>(mods: I, original_keys: u8, is_convert: bool) -> u8
+where
+    I: IntoIterator<Item = GameModIntermode>,
+{
+    if !is_convert {
+        return original_keys;
+    }
+
+    let mut key_mod_count = None;
+    let mut dual_stages = false;
+
+    for gamemod in mods {
+        if let Some(key_count) = gamemod.key_count() {
+            key_mod_count = key_mod_count.max(Some(key_count));
+        } else if gamemod == GameModIntermode::DualStages {
+            dual_stages = true;
+        }
+    }
+
+    let keys = key_mod_count.unwrap_or(original_keys);
+
+    if dual_stages {
+        keys.saturating_mul(2)
+    } else {
+        keys
+    }
+}
+
+// <https://github.com/ppy/osu/blob/master/osu.Game/Rulesets/Mods/ModDoubleTime.cs>
+fn rate_increase_multiplier(speed_change: f64) -> f64 {
+    // Round down to the nearest multiple of 0.1 and add 0.02 for
+    // each 0.1 above 1.0 i.e. 1.5x rate => 1.1x multiplier
+    let value = (speed_change * 10.0).trunc() / 10.0;
+
+    1.0 + (value - 1.0) / 5.0
+}
+
+// <https://github.com/ppy/osu/blob/master/osu.Game/Rulesets/Mods/ModHalfTime.cs>
+fn rate_decrease_multiplier(speed_change: f64) -> f64 {
+    // Round down to the nearest multiple of 0.1 and subtract 0.1 for
+    // each 0.1 below 1.0 i.e. 0.75x rate => 0.7x multiplier
+    (speed_change * 10.0).trunc() / 10.0
+}
+
+fn setting_eq(a: &SettingSimple, b: &SettingSimple, range: Option<SettingRange>) -> bool {
+    match (a, b, range) {
+        (SettingSimple::Number(a), SettingSimple::Number(b), Some(range)) => {
+            range.eq_at_precision(*a, *b)
+        }
+        (SettingSimple::Number(a), SettingSimple::Number(b), None) => (a - b).abs() < f64::EPSILON,
+        _ => a == b,
+    }
 }
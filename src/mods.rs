@@ -7,13 +7,29 @@ use std::{
 };
 
 use crate::{
+    difficulty::HitWindows,
+    error::{GameModsModeError, GameModsParseError, SettingsValidationError},
     generated_mods::{GameMod, GameModIntermode},
+    intermode::split_acronyms,
     intersection::{GameModsIntersection, IntersectionInner},
     iter::{GameModsIter, GameModsIterMut, IntoGameModsIter},
+    mod_manual,
     order::GameModOrder,
-    Acronym, GameMode, GameModsIntermode, GameModsLegacy,
+    setting, Acronym, BeatmapDifficulty, GameMode, GameModsFormatter, GameModsIntermode,
+    GameModsLegacy, LegacyConversion, ModConflict, ModeConversion, SanitizeStrategy,
 };
 
+/// The factor by which `HardRock` and `Easy` scale osu!mania's hit windows.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game.Rulesets.Mania/Mods/ManiaModHardRock.cs>
+const MANIA_HIT_WINDOW_MULTIPLIER: f64 = 1.4;
+
+/// The progress through a map at which mods like `WindUp` reach their final
+/// rate.
+///
+/// <https://github.com/ppy/osu/blob/master/osu.Game/Rulesets/Mods/ModTimeRamp.cs>
+const FINAL_RATE_PROGRESS: f64 = 0.75;
+
 /// Combination of [`GameMod`]s.
 #[derive(Clone, Default, PartialEq)]
 pub struct GameMods {
@@ -471,6 +487,328 @@ impl GameMods {
             .try_fold(1.0, |clock_rate, next| next.map(|next| clock_rate * next))
     }
 
+    /// The clock rate at the given time in milliseconds.
+    ///
+    /// Unlike [`GameMods::clock_rate`], this considers mods that change the
+    /// rate over time. As in osu!lazer's `ModTimeRamp`, the rate changes
+    /// linearly from `initial_rate` at `map_start` to `final_rate` at 75% of
+    /// the time between `map_start` and `map_end`, usually the start time of
+    /// the first and end time of the last hit object.
+    ///
+    /// The rate of `AdaptiveSpeed` depends on the player's performance so
+    /// only its initial rate is considered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameMods};
+    ///
+    /// // Ramps from 1.0 to 1.5 by default
+    /// let wu = GameMods::from(GameMod::WindUpOsu(Default::default()));
+    ///
+    /// assert_eq!(wu.rate_at(1000.0, 1000.0, 5000.0), 1.0);
+    /// assert_eq!(wu.rate_at(2500.0, 1000.0, 5000.0), 1.25);
+    /// assert_eq!(wu.rate_at(4500.0, 1000.0, 5000.0), 1.5);
+    /// ```
+    pub fn rate_at(&self, time_ms: f64, map_start: f64, map_end: f64) -> f64 {
+        let (initial_rate, final_rate) = self.rate_ramp();
+        let ramp_duration = FINAL_RATE_PROGRESS * (map_end - map_start);
+        let amount = (time_ms - map_start) / ramp_duration.max(1.0);
+
+        initial_rate + (final_rate - initial_rate) * amount.clamp(0.0, 1.0)
+    }
+
+    /// The duration in milliseconds that a map with the given drain time
+    /// takes to play, considering rates that change over time.
+    ///
+    /// See [`GameMods::rate_at`] for how the rate changes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameMods};
+    ///
+    /// let dt = GameMods::from(GameMod::DoubleTimeOsu(Default::default()));
+    /// assert_eq!(dt.effective_length(90_000.0), 60_000.0);
+    ///
+    /// let wu = GameMods::from(GameMod::WindUpOsu(Default::default()));
+    /// assert!((wu.effective_length(90_000.0) - 69_737.8).abs() < 0.1);
+    /// ```
+    pub fn effective_length(&self, drain_ms: f64) -> f64 {
+        if drain_ms <= 0.0 {
+            return 0.0;
+        }
+
+        let (initial_rate, final_rate) = self.rate_ramp();
+        let ramp_duration = (FINAL_RATE_PROGRESS * drain_ms).max(1.0);
+        let ramp_end = ramp_duration.min(drain_ms);
+
+        // Integral of `1 / rate` over the linear part of the ramp
+        let slope = (final_rate - initial_rate) / ramp_duration;
+
+        let ramp_length = if slope.abs() < f64::EPSILON {
+            ramp_end / initial_rate
+        } else {
+            ((initial_rate + slope * ramp_end) / initial_rate).ln() / slope
+        };
+
+        ramp_length + (drain_ms - ramp_duration).max(0.0) / final_rate
+    }
+
+    /// The average clock rate over a map with the given drain time, i.e. the
+    /// factor by which the map's duration shrinks and its BPM grows.
+    ///
+    /// See [`GameMods::rate_at`] for how the rate changes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameMods};
+    ///
+    /// let wd = GameMods::from(GameMod::WindDownOsu(Default::default()));
+    /// let bpm = 180.0 * wd.average_rate(90_000.0);
+    ///
+    /// assert!((bpm - 150.5).abs() < 0.1);
+    /// ```
+    pub fn average_rate(&self, drain_ms: f64) -> f64 {
+        if drain_ms <= 0.0 {
+            return self.rate_ramp().0;
+        }
+
+        drain_ms / self.effective_length(drain_ms)
+    }
+
+    /// The combined initial and final rate of all mods.
+    fn rate_ramp(&self) -> (f64, f64) {
+        self.inner
+            .values()
+            .map(|gamemod| {
+                gamemod.rate_ramp().unwrap_or_else(|| {
+                    let clock_rate = gamemod.clock_rate().unwrap_or(1.0);
+
+                    (clock_rate, clock_rate)
+                })
+            })
+            .fold((1.0, 1.0), |(initial_rate, final_rate), (initial, last)| {
+                (initial_rate * initial, final_rate * last)
+            })
+    }
+
+    /// Apply the contained mods to the given [`BeatmapDifficulty`].
+    ///
+    /// Overrides of `DifficultyAdjust` are applied before the scaling of
+    /// `HardRock` and `Easy`.
+    ///
+    /// The returned difficulty does not consider the clock rate, see
+    /// [`BeatmapDifficulty::with_clock_rate`] for that.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{BeatmapDifficulty, GameMod};
+    ///
+    /// # let hrdt: rosu_mods::GameMods = [
+    /// #   GameMod::HardRockOsu(Default::default()),
+    /// #   GameMod::DoubleTimeOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let hrdt = mods!(Osu: HR DT);
+    /// # */
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 7.5, 5.0);
+    /// let adjusted = hrdt.apply_to_difficulty(difficulty);
+    /// assert_eq!(adjusted.ar, 10.0);
+    /// assert_eq!(adjusted.od, 10.0);
+    ///
+    /// let clock_rate = hrdt.clock_rate().unwrap();
+    /// assert_eq!(adjusted.approach_rate_ms(clock_rate), 300.0);
+    /// assert_eq!(adjusted.overall_difficulty_ms(clock_rate), 20.0 / 1.5);
+    /// ```
+    pub fn apply_to_difficulty(&self, difficulty: BeatmapDifficulty) -> BeatmapDifficulty {
+        let mut difficulty = difficulty;
+
+        let is_overriding =
+            |gamemod: &&GameMod| gamemod.intermode() == GameModIntermode::DifficultyAdjust;
+
+        let overrides = self.inner.values().filter(is_overriding);
+        let scaling = self
+            .inner
+            .values()
+            .filter(|gamemod| !is_overriding(gamemod));
+
+        for gamemod in overrides.chain(scaling) {
+            gamemod.apply_to_difficulty(&mut difficulty);
+        }
+
+        difficulty
+    }
+
+    /// The hit windows in milliseconds for the given [`GameMode`] after
+    /// applying the mods to the difficulty and adjusting for the clock rate.
+    ///
+    /// Returns `None` for osu!catch which has no hit windows. Mods of other
+    /// modes are ignored.
+    ///
+    /// osu!mania deviates from the other modes:
+    /// - `HardRock` and `Easy` don't change the OD but shrink the windows by
+    ///   a factor of 1.4 or widen them by the same factor, respectively.
+    /// - The windows don't depend on the clock rate.
+    /// - With `Classic`, osu!stable's windows are used which differ for
+    ///   converted beatmaps, hence `is_convert`. It is ignored for other modes.
+    ///
+    /// Rates that change over time are not considered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{BeatmapDifficulty, GameMod, GameMode, GameMods};
+    ///
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 8.0, 5.0);
+    ///
+    /// let dt = GameMods::from(GameMod::DoubleTimeOsu(Default::default()));
+    /// let windows = dt.hit_windows(difficulty, GameMode::Osu, false).unwrap();
+    /// assert_eq!(windows.great, 32.0 / 1.5);
+    /// assert_eq!(windows.meh, Some(120.0 / 1.5));
+    ///
+    /// let hr = GameMods::from(GameMod::HardRockMania(Default::default()));
+    /// let windows = hr.hit_windows(difficulty, GameMode::Mania, false).unwrap();
+    /// assert_eq!(windows.great, 40.0 / 1.4);
+    ///
+    /// assert!(GameMods::new().hit_windows(difficulty, GameMode::Catch, false).is_none());
+    /// ```
+    pub fn hit_windows(
+        &self,
+        difficulty: BeatmapDifficulty,
+        mode: GameMode,
+        is_convert: bool,
+    ) -> Option<HitWindows> {
+        let mods = self.of_mode(mode);
+        let clock_rate = mods.clock_rate().unwrap_or(1.0);
+
+        match mode {
+            GameMode::Osu => Some(HitWindows::osu(
+                mods.apply_to_difficulty(difficulty).od,
+                clock_rate,
+            )),
+            GameMode::Taiko => Some(HitWindows::taiko(
+                mods.apply_to_difficulty(difficulty).od,
+                clock_rate,
+            )),
+            GameMode::Catch => None,
+            GameMode::Mania => {
+                let difficulty = mods.apply_to_difficulty(difficulty);
+
+                let multiplier = if mods.contains_intermode(GameModIntermode::HardRock) {
+                    1.0 / MANIA_HIT_WINDOW_MULTIPLIER
+                } else if mods.contains_intermode(GameModIntermode::Easy) {
+                    MANIA_HIT_WINDOW_MULTIPLIER
+                } else {
+                    1.0
+                };
+
+                if mods.contains_intermode(GameModIntermode::Classic) {
+                    Some(HitWindows::mania_classic(
+                        difficulty.od,
+                        multiplier,
+                        is_convert,
+                    ))
+                } else {
+                    Some(HitWindows::mania(difficulty.od, multiplier))
+                }
+            }
+        }
+    }
+
+    /// The time in milliseconds that hit objects are visible before they
+    /// need to be hit for the given [`GameMode`] after applying the mods to
+    /// the difficulty and adjusting for the clock rate.
+    ///
+    /// Returns `None` for osu!taiko and osu!mania which don't use AR. Mods of
+    /// other modes are ignored.
+    ///
+    /// Rates that change over time are not considered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{BeatmapDifficulty, GameMod, GameMode, GameMods};
+    ///
+    /// let difficulty = BeatmapDifficulty::new(4.0, 9.0, 8.0, 5.0);
+    ///
+    /// let hrdt: GameMods = [
+    ///     GameMod::HardRockOsu(Default::default()),
+    ///     GameMod::DoubleTimeOsu(Default::default()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(hrdt.approach_preempt(difficulty, GameMode::Osu), Some(300.0));
+    /// assert_eq!(hrdt.approach_preempt(difficulty, GameMode::Mania), None);
+    /// ```
+    pub fn approach_preempt(&self, difficulty: BeatmapDifficulty, mode: GameMode) -> Option<f64> {
+        match mode {
+            GameMode::Osu | GameMode::Catch => {
+                let mods = self.of_mode(mode);
+                let clock_rate = mods.clock_rate().unwrap_or(1.0);
+
+                Some(
+                    mods.apply_to_difficulty(difficulty)
+                        .approach_rate_ms(clock_rate),
+                )
+            }
+            GameMode::Taiko | GameMode::Mania => None,
+        }
+    }
+
+    /// The key count of an osu!mania beatmap with the given amount of keys
+    /// after applying the mods.
+    ///
+    /// Key mods such as `4K` replace the key count and `DualStages` doubles
+    /// it. Same as in osu!lazer, these mods only take effect on converted
+    /// beatmaps and only osu!mania mods are considered.
+    /// If multiple key mods are present, the largest key count applies.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameMods};
+    ///
+    /// let mods: GameMods = [
+    ///     GameMod::FourKeysMania(Default::default()),
+    ///     GameMod::DualStagesMania(Default::default()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(mods.mania_key_count(7, true), 8);
+    /// assert_eq!(mods.mania_key_count(7, false), 7);
+    /// ```
+    pub fn mania_key_count(&self, original_keys: u8, is_convert: bool) -> u8 {
+        let mods = self
+            .inner
+            .values()
+            .filter(|gamemod| gamemod.mode() == GameMode::Mania)
+            .map(GameMod::intermode);
+
+        mod_manual::mania_key_count(mods, original_keys, is_convert)
+    }
+
+    /// The score multiplier of the [`GameMods`] as used by osu!lazer i.e. the
+    /// product of all contained mods' multipliers.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod};
+    ///
+    /// # let hd: rosu_mods::GameMods = [GameMod::HiddenOsu(Default::default())].into_iter().collect();
+    /// # /*
+    /// let hd = mods!(Osu: HD);
+    /// # */
+    /// assert_eq!(hd.score_multiplier(), 1.06);
+    ///
+    /// let mut hddt = hd;
+    /// hddt.insert(GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.2),
+    ///     adjust_pitch: None,
+    /// }));
+    /// assert_eq!(hddt.score_multiplier(), 1.06 * 1.04);
+    /// ```
+    pub fn score_multiplier(&self) -> f64 {
+        self.inner.values().map(GameMod::score_multiplier).product()
+    }
+
     /// Tries to create [`GameMods`] from a [`GameModsIntermode`].
     ///
     /// Returns `None` if any contained [`GameModIntermode`] is unknown for the
@@ -509,6 +847,122 @@ impl GameMods {
         mods.with_mode(mode)
     }
 
+    /// Convert all mods to the given [`GameMode`].
+    ///
+    /// Unlike [`GameMods::from_intermode`], settings are carried over if the
+    /// target mode's mod has a setting of the same name. Mods and settings
+    /// that don't exist in the target mode are reported in the returned
+    /// [`ModeConversion`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::{DoubleTimeMania, DoubleTimeOsu}, GameMod, GameMode, GameMods};
+    ///
+    /// let mut mods = GameMods::new();
+    /// mods.insert(GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.3),
+    ///     adjust_pitch: None,
+    /// }));
+    /// mods.insert(GameMod::TraceableOsu(Default::default()));
+    ///
+    /// let conversion = mods.convert_to_mode(GameMode::Mania);
+    ///
+    /// let dt = GameMod::DoubleTimeMania(DoubleTimeMania {
+    ///     speed_change: Some(1.3),
+    ///     adjust_pitch: None,
+    /// });
+    /// assert_eq!(conversion.mods, GameMods::from(dt));
+    /// assert_eq!(conversion.dropped_mods, [GameMod::TraceableOsu(Default::default())]);
+    /// ```
+    pub fn convert_to_mode(&self, mode: GameMode) -> ModeConversion {
+        ModeConversion::new(self, mode)
+    }
+
+    /// The [`GameMode`] of all contained mods.
+    ///
+    /// Returns an error if the mods are empty or belong to multiple modes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{error::GameModsModeError, GameMod, GameMode, GameMods};
+    ///
+    /// let mut mods = GameMods::from(GameMod::HiddenTaiko(Default::default()));
+    /// assert_eq!(mods.mode(), Ok(GameMode::Taiko));
+    ///
+    /// mods.insert(GameMod::HiddenMania(Default::default()));
+    /// assert_eq!(
+    ///     mods.mode(),
+    ///     Err(GameModsModeError::Mixed { modes: vec![GameMode::Taiko, GameMode::Mania] }),
+    /// );
+    ///
+    /// assert_eq!(GameMods::new().mode(), Err(GameModsModeError::Empty));
+    /// ```
+    pub fn mode(&self) -> Result<GameMode, GameModsModeError> {
+        // Mods are sorted by mode first so checking the outer ones suffices
+        let (Some((_, first)), Some((_, last))) =
+            (self.inner.first_key_value(), self.inner.last_key_value())
+        else {
+            return Err(GameModsModeError::Empty);
+        };
+
+        if first.mode() == last.mode() {
+            return Ok(first.mode());
+        }
+
+        let mut modes: Vec<_> = self.inner.values().map(GameMod::mode).collect();
+        modes.dedup();
+
+        Err(GameModsModeError::Mixed { modes })
+    }
+
+    /// Split the mods into one [`GameMods`] per [`GameMode`].
+    ///
+    /// The returned array is indexed by the mode i.e. `GameMode::Osu as usize`
+    /// through `GameMode::Mania as usize`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameMode, GameMods};
+    ///
+    /// let mods: GameMods = [
+    ///     GameMod::HardRockOsu(Default::default()),
+    ///     GameMod::HiddenOsu(Default::default()),
+    ///     GameMod::HiddenCatch(Default::default()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let [osu, taiko, catch, mania] = mods.split_by_mode();
+    ///
+    /// assert_eq!(osu.to_string(), "HDHR");
+    /// assert!(taiko.is_empty());
+    /// assert_eq!(catch.mode(), Ok(GameMode::Catch));
+    /// assert!(mania.is_empty());
+    /// ```
+    pub fn split_by_mode(&self) -> [Self; 4] {
+        let mut split: [Self; 4] = Default::default();
+
+        for (key, gamemod) in self.inner.iter() {
+            split[gamemod.mode() as usize]
+                .inner
+                .insert(*key, gamemod.clone());
+        }
+
+        split
+    }
+
+    /// The contained mods of the given [`GameMode`].
+    fn of_mode(&self, mode: GameMode) -> Self {
+        let inner = self
+            .inner
+            .iter()
+            .filter(|(_, gamemod)| gamemod.mode() == mode)
+            .map(|(key, gamemod)| (*key, gamemod.clone()))
+            .collect();
+
+        Self { inner }
+    }
+
     /// Returns an iterator over all contained mods.
     ///
     /// Note that the iterator will immediately yield `None` in case of "`NoMod`".
@@ -552,6 +1006,64 @@ impl GameMods {
         true
     }
 
+    /// Returns all pairs of contained mods that exclude each other.
+    ///
+    /// Each pair is listed once. If only one of the two mods lists the other
+    /// as incompatible, that mod is [`ModConflict::gamemod`]. Otherwise the
+    /// conflict is [`ModConflict::mutual`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameMod;
+    ///
+    /// # let mut mods: rosu_mods::GameMods = [
+    /// #   GameMod::HiddenOsu(Default::default()),
+    /// #   GameMod::DoubleTimeOsu(Default::default()),
+    /// #   GameMod::HalfTimeOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let mods = mods!(Osu: HD DT HT);
+    /// # */
+    /// let conflicts = mods.conflicts();
+    ///
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert!(conflicts[0].mutual);
+    /// assert_eq!(conflicts[0].to_string(), "HT is incompatible with DT");
+    /// ```
+    ///
+    /// [`ModConflict::gamemod`]: crate::ModConflict::gamemod
+    /// [`ModConflict::mutual`]: crate::ModConflict::mutual
+    pub fn conflicts(&self) -> Vec<ModConflict<'_>> {
+        let excludes = |gamemod: &GameMod, other: &GameMod| {
+            gamemod.incompatible_mods().contains(&other.acronym())
+        };
+
+        let mods: Vec<_> = self.inner.values().collect();
+        let mut conflicts = Vec::new();
+
+        for (i, &a) in mods.iter().enumerate() {
+            for &b in &mods[i + 1..] {
+                let conflict = match (excludes(a, b), excludes(b, a)) {
+                    (true, mutual) => ModConflict {
+                        gamemod: a,
+                        excluded: b,
+                        mutual,
+                    },
+                    (false, true) => ModConflict {
+                        gamemod: b,
+                        excluded: a,
+                        mutual: false,
+                    },
+                    (false, false) => continue,
+                };
+
+                conflicts.push(conflict);
+            }
+        }
+
+        conflicts
+    }
+
     /// Remove all mods that are excluded by other contained mods.
     ///
     /// # Example
@@ -588,59 +1100,484 @@ impl GameMods {
         }
     }
 
-    /// Turns [`GameMods`] into [`GameModsLegacy`].
-    pub fn as_legacy(&self) -> GameModsLegacy {
-        GameModsLegacy::from_bits(self.bits())
-    }
-
-    /// Attempts to turns [`GameMods`] into [`GameModsLegacy`].
+    /// Remove mods that are excluded by other contained mods while letting
+    /// the [`SanitizeStrategy`] decide which of two conflicting mods is kept.
     ///
-    /// Returns `None` if any contained [`GameMod`] does not have a bit value.
-    pub fn try_as_legacy(&self) -> Option<GameModsLegacy> {
-        self.checked_bits().map(GameModsLegacy::from_bits)
-    }
-}
-
-impl Debug for GameMods {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_list().entries(self.inner.values()).finish()
-    }
-}
+    /// Returns the removed mods.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{GameMod, GameModIntermode, GameModKind, SanitizeStrategy};
+    ///
+    /// # let mods: rosu_mods::GameMods = [
+    /// #   GameMod::EasyOsu(Default::default()),
+    /// #   GameMod::HardRockOsu(Default::default()),
+    /// #   GameMod::HiddenOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let mods = mods!(Osu: EZ HR HD);
+    /// # */
+    /// // The player selected HR before EZ
+    /// let selected = [GameModIntermode::HardRock, GameModIntermode::Easy];
+    ///
+    /// let mut earliest = mods.clone();
+    /// let removed = earliest.sanitize_with(SanitizeStrategy::KeepEarliest(&selected));
+    /// assert_eq!(earliest.to_string(), "HDHR");
+    /// assert_eq!(removed, [GameMod::EasyOsu(Default::default())]);
+    ///
+    /// let mut latest = mods.clone();
+    /// latest.sanitize_with(SanitizeStrategy::KeepLatest(&selected));
+    /// assert_eq!(latest.to_string(), "EZHD");
+    ///
+    /// let mut increase = mods.clone();
+    /// increase.sanitize_with(SanitizeStrategy::PreferKind(&[GameModKind::DifficultyIncrease]));
+    /// assert_eq!(increase.to_string(), "HDHR");
+    /// ```
+    pub fn sanitize_with(&mut self, strategy: SanitizeStrategy<'_>) -> Vec<GameMod> {
+        // Rank each mod by how many others are preferred over it. Sorting by
+        // that key can't panic even if a custom closure is no total order.
+        let mut mods: Vec<_> = self
+            .inner
+            .values()
+            .map(|gamemod| {
+                let rank = self
+                    .inner
+                    .values()
+                    .filter(|other| strategy.compare(other, gamemod) == Ordering::Less)
+                    .count();
+
+                (rank, gamemod)
+            })
+            .collect();
 
-impl Display for GameMods {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if self.is_empty() {
-            f.write_str("NM")
-        } else {
-            for gamemod in self.iter() {
-                f.write_str(gamemod.acronym().as_str())?;
-            }
+        // Stable sort so that ties keep `GameModOrder`
+        mods.sort_by_key(|(rank, _)| *rank);
 
-            Ok(())
-        }
-    }
-}
+        let excludes = |gamemod: &GameMod, other: &GameMod| {
+            gamemod.incompatible_mods().contains(&other.acronym())
+        };
 
-impl From<GameMod> for GameMods {
-    fn from(gamemod: GameMod) -> Self {
-        let mut mods = Self::new();
-        mods.insert(gamemod);
+        let mut kept: Vec<&GameMod> = Vec::with_capacity(mods.len());
+        let mut removed_keys = Vec::new();
 
-        mods
-    }
-}
+        for (_, gamemod) in mods {
+            let conflicts = kept
+                .iter()
+                .any(|kept| excludes(kept, gamemod) || excludes(gamemod, kept));
 
-impl IntoIterator for GameMods {
-    type Item = GameMod;
-    type IntoIter = IntoGameModsIter;
+            if conflicts {
+                removed_keys.push(GameModOrder::from(gamemod));
+            } else {
+                kept.push(gamemod);
+            }
+        }
 
-    /// Turns [`GameMods`] into an iterator over all contained mods.
-    ///
-    /// Note that the iterator will immediately yield `None` in case of "`NoMod`".
-    fn into_iter(self) -> Self::IntoIter {
-        IntoGameModsIter::new(self.inner.into_values())
+        removed_keys
+            .iter()
+            .filter_map(|key| self.inner.remove(key))
+            .collect()
     }
-}
+
+    /// Checks whether all contained mods are playable by a user.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameMod;
+    ///
+    /// # let mut mods: rosu_mods::GameMods = [
+    /// #   GameMod::HiddenOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let mut mods = mods!(Osu: HD);
+    /// # */
+    /// assert!(mods.is_user_playable());
+    ///
+    /// mods.insert(GameMod::AutoplayOsu(Default::default()));
+    /// assert!(!mods.is_user_playable());
+    /// ```
+    pub fn is_user_playable(&self) -> bool {
+        self.inner.values().all(GameMod::is_user_playable)
+    }
+
+    /// Checks whether all contained mods can be selected in multiplayer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameMod;
+    ///
+    /// # let mut mods: rosu_mods::GameMods = [
+    /// #   GameMod::DoubleTimeOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let mut mods = mods!(Osu: DT);
+    /// # */
+    /// assert!(mods.is_valid_for_multiplayer());
+    ///
+    /// mods.insert(GameMod::TouchDeviceOsu(Default::default()));
+    /// assert!(!mods.is_valid_for_multiplayer());
+    /// ```
+    pub fn is_valid_for_multiplayer(&self) -> bool {
+        self.inner.values().all(GameMod::is_valid_for_multiplayer)
+    }
+
+    /// Checks whether all contained mods can be selected as free mods in
+    /// multiplayer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::GameMod;
+    ///
+    /// # let mut mods: rosu_mods::GameMods = [
+    /// #   GameMod::HiddenOsu(Default::default()),
+    /// # ].into_iter().collect();
+    /// # /*
+    /// let mut mods = mods!(Osu: HD);
+    /// # */
+    /// assert!(mods.is_valid_for_multiplayer_as_free_mod());
+    ///
+    /// mods.insert(GameMod::DoubleTimeOsu(Default::default()));
+    /// assert!(!mods.is_valid_for_multiplayer_as_free_mod());
+    /// ```
+    pub fn is_valid_for_multiplayer_as_free_mod(&self) -> bool {
+        self.inner
+            .values()
+            .all(GameMod::is_valid_for_multiplayer_as_free_mod)
+    }
+
+    /// Checks whether any contained mod needs to be configured before it can
+    /// be used.
+    pub fn requires_configuration(&self) -> bool {
+        self.inner.values().any(GameMod::requires_configuration)
+    }
+
+    /// Check whether the settings of all contained mods are valid.
+    ///
+    /// See [`GameMod::validate_settings`] for more information.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DifficultyAdjustOsu, GameMod, GameMods};
+    ///
+    /// let mut mods = GameMods::new();
+    /// mods.insert(GameMod::HiddenOsu(Default::default()));
+    /// assert!(mods.validate().is_ok());
+    ///
+    /// mods.insert(GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+    ///     approach_rate: Some(10.5),
+    ///     overall_difficulty: Some(8.25),
+    ///     ..Default::default()
+    /// }));
+    ///
+    /// let err = mods.validate().unwrap_err();
+    /// assert_eq!(err.invalid.len(), 2);
+    /// ```
+    pub fn validate(&self) -> Result<(), SettingsValidationError> {
+        let mut invalid = Vec::new();
+
+        for gamemod in self.inner.values() {
+            gamemod.collect_invalid_settings(&mut invalid);
+        }
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(SettingsValidationError { invalid })
+        }
+    }
+
+    /// Unset all settings of contained mods whose value is the default value.
+    ///
+    /// See [`GameMod::normalize`] for more information.
+    pub fn normalize(&mut self) {
+        for gamemod in self.inner.values_mut() {
+            gamemod.normalize();
+        }
+    }
+
+    /// Check whether both [`GameMods`] contain the same mods when treating
+    /// unset settings as their default value.
+    ///
+    /// See [`GameMod::semantically_eq`] for more information.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::HalfTimeCatch, GameMod, GameMods};
+    ///
+    /// let a: GameMods = [
+    ///     GameMod::HiddenCatch(Default::default()),
+    ///     GameMod::HalfTimeCatch(HalfTimeCatch {
+    ///         speed_change: Some(0.75),
+    ///         adjust_pitch: None,
+    ///     }),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let b: GameMods = [
+    ///     GameMod::HiddenCatch(Default::default()),
+    ///     GameMod::HalfTimeCatch(Default::default()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.inner.len() == other.inner.len()
+            && self
+                .inner
+                .values()
+                .zip(other.inner.values())
+                .all(|(a, b)| a.semantically_eq(b))
+    }
+
+    /// Parse mod acronyms with optional settings in parentheses into
+    /// [`GameMods`] of the given mode.
+    ///
+    /// Settings are separated by commas and given as `name=value` where the
+    /// name is either the setting's field name or a short alias such as `ar`
+    /// for `approach_rate`. A single value without name, e.g. `DT(1.3x)`, sets
+    /// the `speed_change` of rate mods and is rejected for other mods. This is
+    /// the inverse of [`GameModsFormatter::settings`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{
+    ///     generated_mods::{DifficultyAdjustOsu, DoubleTimeOsu},
+    ///     GameMod, GameMode, GameMods,
+    /// };
+    ///
+    /// let mods = GameMods::parse_with_settings("HDDT(1.3x)DA(ar=9.5,od=8)", GameMode::Osu).unwrap();
+    ///
+    /// assert_eq!(mods.len(), 3);
+    /// assert!(mods.contains(&GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.3),
+    ///     adjust_pitch: None,
+    /// })));
+    /// assert!(mods.contains(&GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+    ///     approach_rate: Some(9.5),
+    ///     overall_difficulty: Some(8.0),
+    ///     ..Default::default()
+    /// })));
+    ///
+    /// assert!(GameMods::parse_with_settings("DT(foo=1)", GameMode::Osu).is_err());
+    /// ```
+    ///
+    /// [`GameModsFormatter::settings`]: crate::GameModsFormatter::settings
+    pub fn parse_with_settings(s: &str, mode: GameMode) -> Result<Self, GameModsParseError> {
+        let s = s.trim_end();
+        let trimmed = s.trim_start();
+        let mut remaining = trimmed.strip_prefix('+').unwrap_or(trimmed);
+        let mut mods = Self::new();
+
+        if remaining.eq_ignore_ascii_case("NM") {
+            return Ok(mods);
+        }
+
+        while !remaining.is_empty() {
+            let end = remaining.find(['(', ')']).unwrap_or(remaining.len());
+            let (run, rest) = remaining.split_at(end);
+
+            let offset = s.len() - remaining.len();
+
+            let intermodes = split_acronyms(run).map_err(|mut err| {
+                err.offset += offset;
+
+                GameModsParseError::Acronyms(err)
+            })?;
+
+            let settings = if let Some(rest) = rest.strip_prefix('(') {
+                let close = rest
+                    .find(')')
+                    .ok_or(GameModsParseError::UnclosedParenthesis)?;
+                remaining = &rest[close + 1..];
+
+                Some(&rest[..close])
+            } else if rest.starts_with(')') {
+                return Err(GameModsParseError::UnexpectedParenthesis);
+            } else {
+                remaining = rest;
+
+                None
+            };
+
+            if settings.is_some() && intermodes.is_empty() {
+                return Err(GameModsParseError::UnexpectedParenthesis);
+            }
+
+            let last = intermodes.len().saturating_sub(1);
+
+            for (i, intermode) in intermodes.into_iter().enumerate() {
+                let acronym = intermode.acronym();
+                let mut gamemod = GameMod::new(acronym.as_str(), mode);
+
+                if let GameModIntermode::Unknown(_) = gamemod.intermode() {
+                    return Err(GameModsParseError::InvalidForMode { acronym, mode });
+                }
+
+                if let Some(settings) = settings.filter(|_| i == last) {
+                    parse_settings(&mut gamemod, settings)?;
+                }
+
+                mods.insert(gamemod);
+            }
+        }
+
+        Ok(mods)
+    }
+
+    /// Turns [`GameMods`] into [`GameModsLegacy`].
+    pub fn as_legacy(&self) -> GameModsLegacy {
+        GameModsLegacy::from_bits(self.bits())
+    }
+
+    /// Attempts to turns [`GameMods`] into [`GameModsLegacy`].
+    ///
+    /// Returns `None` if any contained [`GameMod`] does not have a bit value.
+    pub fn try_as_legacy(&self) -> Option<GameModsLegacy> {
+        self.checked_bits().map(GameModsLegacy::from_bits)
+    }
+
+    /// Turns [`GameMods`] into [`GameModsLegacy`] while reporting everything
+    /// that can't be represented by legacy mods.
+    ///
+    /// Unlike [`GameMods::as_legacy`], the returned [`LegacyConversion`]
+    /// contains all mods without a bit value as well as all settings that
+    /// differ from their default value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod, GameMods, GameModsLegacy};
+    ///
+    /// let mut mods = GameMods::new();
+    /// mods.insert(GameMod::HiddenOsu(Default::default()));
+    /// mods.insert(GameMod::DoubleTimeOsu(DoubleTimeOsu {
+    ///     speed_change: Some(1.3),
+    ///     adjust_pitch: None,
+    /// }));
+    ///
+    /// let conversion = mods.to_legacy_report();
+    ///
+    /// assert_eq!(conversion.legacy, GameModsLegacy::Hidden | GameModsLegacy::DoubleTime);
+    /// assert_eq!(conversion.dropped_settings[0].setting, "speed_change");
+    /// assert!(!conversion.is_lossless());
+    /// ```
+    pub fn to_legacy_report(&self) -> LegacyConversion {
+        LegacyConversion::new(self)
+    }
+
+    /// Returns a [`GameModsFormatter`] to configure how [`GameMods`] are
+    /// displayed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosu_mods::{generated_mods::DoubleTimeCatch, GameMod, GameMods};
+    ///
+    /// let mut mods = GameMods::new();
+    /// mods.insert(GameMod::HardRockCatch(Default::default()));
+    /// mods.insert(GameMod::DoubleTimeCatch(DoubleTimeCatch {
+    ///     speed_change: Some(1.3),
+    ///     adjust_pitch: None,
+    /// }));
+    ///
+    /// assert_eq!(mods.format().separator(", ").settings(true).to_string(), "DT(1.3x), HR");
+    /// ```
+    pub const fn format(&self) -> GameModsFormatter<'_> {
+        GameModsFormatter::mods(self)
+    }
+}
+
+/// Parse comma-separated settings into the [`GameMod`].
+fn parse_settings(gamemod: &mut GameMod, settings: &str) -> Result<(), GameModsParseError> {
+    const POSITIONAL: &str = "speed_change";
+
+    let acronym = gamemod.acronym();
+    let mut has_positional = false;
+
+    for item in settings.split(',').map(str::trim) {
+        if item.is_empty() {
+            continue;
+        }
+
+        let (name, value) = match item.split_once('=') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None if has_positional
+                || setting::find_setting(gamemod.settings_schema(), POSITIONAL).is_none() =>
+            {
+                return Err(GameModsParseError::UnexpectedValue {
+                    acronym,
+                    value: Box::from(item),
+                });
+            }
+            None => {
+                has_positional = true;
+
+                (POSITIONAL, item)
+            }
+        };
+
+        let schema = setting::find_setting(gamemod.settings_schema(), name).ok_or_else(|| {
+            GameModsParseError::UnknownSetting {
+                acronym,
+                setting: Box::from(name),
+            }
+        })?;
+
+        let value = setting::parse_setting_value(schema.kind, value).ok_or_else(|| {
+            GameModsParseError::InvalidValue {
+                acronym,
+                setting: schema.name,
+                value: Box::from(value),
+            }
+        })?;
+
+        gamemod.set_setting(schema.name, Some(value));
+    }
+
+    Ok(())
+}
+
+impl Debug for GameMods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.inner.values()).finish()
+    }
+}
+
+impl Display for GameMods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_empty() {
+            f.write_str("NM")
+        } else {
+            for gamemod in self.iter() {
+                f.write_str(gamemod.acronym().as_str())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+impl From<GameMod> for GameMods {
+    fn from(gamemod: GameMod) -> Self {
+        let mut mods = Self::new();
+        mods.insert(gamemod);
+
+        mods
+    }
+}
+
+impl IntoIterator for GameMods {
+    type Item = GameMod;
+    type IntoIter = IntoGameModsIter;
+
+    /// Turns [`GameMods`] into an iterator over all contained mods.
+    ///
+    /// Note that the iterator will immediately yield `None` in case of "`NoMod`".
+    fn into_iter(self) -> Self::IntoIter {
+        IntoGameModsIter::new(self.inner.into_values())
+    }
+}
 
 impl<'a> IntoIterator for &'a GameMods {
     type Item = <GameModsIter<'a> as Iterator>::Item;
@@ -733,7 +1670,13 @@ const _: () = {
 
 #[cfg(test)]
 mod tests {
-    use crate::generated_mods::DoubleTimeOsu;
+    use crate::{
+        error::{AcronymsParseError, InvalidSettingReason},
+        generated_mods::{
+            DaycoreMania, DifficultyAdjustCatch, DoubleTimeCatch, DoubleTimeOsu, DoubleTimeTaiko,
+            FlashlightOsu, HalfTimeMania, HalfTimeTaiko, WindDownMania, WindDownTaiko,
+        },
+    };
 
     use super::*;
 
@@ -870,4 +1813,441 @@ mod tests {
 
         assert_eq!(mods.to_string(), "BLHD");
     }
+
+    #[test]
+    fn eligibility() {
+        let mods: GameMods = [
+            GameMod::DifficultyAdjustTaiko(Default::default()),
+            GameMod::HiddenTaiko(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(mods.is_user_playable());
+        assert!(mods.is_valid_for_multiplayer());
+        assert!(mods.is_valid_for_multiplayer_as_free_mod());
+        assert!(mods.requires_configuration());
+        assert!(GameModIntermode::TouchDevice.is_always_valid_for_submission());
+        assert!(!GameModIntermode::Cinema.is_valid_for_multiplayer());
+    }
+
+    #[test]
+    fn score_multiplier() {
+        let mods: GameMods = [
+            GameMod::HiddenCatch(Default::default()),
+            GameMod::HardRockCatch(Default::default()),
+            GameMod::NightcoreCatch(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!((mods.score_multiplier() - 1.06 * 1.12 * 1.1).abs() < f64::EPSILON);
+        assert!((GameMods::new().score_multiplier() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_multiplier_rate_decrease() {
+        let default = GameMod::HalfTimeOsu(Default::default());
+        assert!((default.score_multiplier() - 0.7).abs() < f64::EPSILON);
+
+        let slower = GameMod::DaycoreMania(DaycoreMania {
+            speed_change: Some(0.55),
+        });
+        assert!((slower.score_multiplier() - 0.5).abs() < f64::EPSILON);
+
+        let faster = GameMod::HalfTimeTaiko(HalfTimeTaiko {
+            speed_change: Some(0.99),
+            adjust_pitch: None,
+        });
+        assert!((faster.score_multiplier() - 0.9).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_multiplier_flashlight() {
+        let default = GameMod::FlashlightOsu(Default::default());
+        assert!((default.score_multiplier() - 1.12).abs() < f64::EPSILON);
+
+        let explicit_default = GameMod::FlashlightOsu(FlashlightOsu {
+            follow_delay: Some(120.0),
+            size_multiplier: None,
+            combo_based_size: Some(true),
+        });
+        assert!((explicit_default.score_multiplier() - 1.12).abs() < f64::EPSILON);
+
+        let custom = GameMod::FlashlightOsu(FlashlightOsu {
+            size_multiplier: Some(1.5),
+            ..Default::default()
+        });
+        assert!((custom.score_multiplier() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_multiplier_rate() {
+        let dt = |speed_change| {
+            GameMod::DoubleTimeTaiko(DoubleTimeTaiko {
+                speed_change: Some(speed_change),
+                adjust_pitch: None,
+            })
+        };
+
+        assert!((dt(1.01).score_multiplier() - 1.0).abs() < f64::EPSILON);
+        assert!((dt(1.19).score_multiplier() - 1.02).abs() < f64::EPSILON);
+        assert!((dt(2.0).score_multiplier() - 1.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn validate_extended_limits() {
+        let da = |extended_limits| {
+            GameMods::from(GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+                circle_size: Some(10.5),
+                extended_limits: Some(extended_limits),
+                ..Default::default()
+            }))
+        };
+
+        assert!(da(true).validate().is_ok());
+
+        let err = da(false).validate().unwrap_err();
+        assert_eq!(
+            err.invalid[0].reason,
+            InvalidSettingReason::OutOfRange {
+                value: 10.5,
+                min: 0.0,
+                max: 10.0
+            }
+        );
+    }
+
+    #[test]
+    fn validate_off_step_and_contradiction() {
+        let mods: GameMods = [
+            GameMod::HalfTimeMania(HalfTimeMania {
+                speed_change: Some(0.755),
+                adjust_pitch: None,
+            }),
+            GameMod::WindDownMania(WindDownMania {
+                initial_rate: Some(0.7),
+                final_rate: None,
+                adjust_pitch: None,
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let err = mods.validate().unwrap_err();
+        let reasons: Vec<_> = err.invalid.iter().map(|invalid| invalid.reason).collect();
+
+        assert_eq!(
+            reasons,
+            [
+                InvalidSettingReason::OffStep {
+                    value: 0.755,
+                    precision: 0.01
+                },
+                InvalidSettingReason::Contradiction {
+                    other: "initial_rate"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_at_precision() {
+        let mut mods: GameMods = [
+            GameMod::DoubleTimeOsu(DoubleTimeOsu {
+                speed_change: Some(1.500_000_1),
+                adjust_pitch: None,
+            }),
+            GameMod::FlashlightOsu(FlashlightOsu {
+                follow_delay: Some(240.0),
+                size_multiplier: Some(1.0),
+                combo_based_size: Some(true),
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        mods.normalize();
+
+        let expected: GameMods = [
+            GameMod::DoubleTimeOsu(Default::default()),
+            GameMod::FlashlightOsu(FlashlightOsu {
+                follow_delay: Some(240.0),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(mods, expected);
+    }
+
+    #[test]
+    fn semantically_eq_differs() {
+        let a = GameMods::from(GameMod::DoubleTimeTaiko(DoubleTimeTaiko {
+            speed_change: Some(1.3),
+            adjust_pitch: None,
+        }));
+        let b = GameMods::from(GameMod::DoubleTimeTaiko(Default::default()));
+        let c = GameMods::from(GameMod::DoubleTimeOsu(Default::default()));
+
+        assert!(!a.semantically_eq(&b));
+        assert!(!b.semantically_eq(&c));
+        assert!(!b.semantically_eq(&GameMods::new()));
+    }
+
+    #[test]
+    fn parse_with_settings() {
+        let mods = GameMods::parse_with_settings("+dt(1.25)", GameMode::Catch).unwrap();
+        let expected = GameMods::from(GameMod::DoubleTimeCatch(DoubleTimeCatch {
+            speed_change: Some(1.25),
+            adjust_pitch: None,
+        }));
+        assert_eq!(mods, expected);
+
+        let mods = GameMods::parse_with_settings(
+            "DA(CS=3, approach_rate=9.5, extended_limits=true)HD",
+            GameMode::Catch,
+        )
+        .unwrap();
+        let expected: GameMods = [
+            GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+                circle_size: Some(3.0),
+                approach_rate: Some(9.5),
+                extended_limits: Some(true),
+                ..Default::default()
+            }),
+            GameMod::HiddenCatch(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(mods, expected);
+
+        assert_eq!(
+            GameMods::parse_with_settings("NM", GameMode::Osu),
+            Ok(GameMods::new())
+        );
+    }
+
+    #[test]
+    fn parse_with_settings_roundtrip() {
+        let mods: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::DoubleTimeOsu(DoubleTimeOsu {
+                speed_change: Some(1.3),
+                adjust_pitch: Some(true),
+            }),
+            GameMod::FlashlightOsu(FlashlightOsu {
+                follow_delay: Some(240.0),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let formatted = mods.format().settings(true).to_string();
+        let parsed = GameMods::parse_with_settings(&formatted, GameMode::Osu).unwrap();
+
+        assert_eq!(parsed, mods);
+    }
+
+    #[test]
+    fn parse_with_settings_errors() {
+        let parse = |s| GameMods::parse_with_settings(s, GameMode::Osu).unwrap_err();
+
+        assert!(matches!(
+            parse("HDQQQ"),
+            GameModsParseError::Acronyms(AcronymsParseError { offset: 2, .. })
+        ));
+        assert!(matches!(
+            parse("HDCS"),
+            GameModsParseError::InvalidForMode { .. }
+        ));
+        assert_eq!(parse("DT(1.3"), GameModsParseError::UnclosedParenthesis);
+        assert_eq!(parse("(1.3)DT"), GameModsParseError::UnexpectedParenthesis);
+        assert!(matches!(
+            parse("HR(foo=1.3)"),
+            GameModsParseError::UnknownSetting { .. }
+        ));
+
+        for s in ["HR(1.3)", "BR(0.5)", "WU(0.8)"] {
+            assert!(matches!(
+                parse(s),
+                GameModsParseError::UnexpectedValue { .. }
+            ));
+        }
+
+        assert_eq!(
+            parse("DT(1.3,1.4)"),
+            GameModsParseError::UnexpectedValue {
+                acronym: GameModIntermode::DoubleTime.acronym(),
+                value: Box::from("1.4"),
+            }
+        );
+        assert!(matches!(
+            parse("DA(ar=fast)"),
+            GameModsParseError::InvalidValue {
+                setting: "approach_rate",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn conflicts() {
+        let mods: GameMods = [
+            GameMod::EasyOsu(Default::default()),
+            GameMod::HardRockOsu(Default::default()),
+            GameMod::DoubleTimeOsu(Default::default()),
+            GameMod::NightcoreOsu(Default::default()),
+            GameMod::HiddenOsu(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        let pairs: Vec<_> = mods
+            .conflicts()
+            .iter()
+            .map(|conflict| {
+                (
+                    conflict.gamemod.acronym().as_str().to_owned(),
+                    conflict.excluded.acronym().as_str().to_owned(),
+                    conflict.mutual,
+                )
+            })
+            .collect();
+
+        let expected = [
+            ("EZ".to_owned(), "HR".to_owned(), true),
+            ("DT".to_owned(), "NC".to_owned(), true),
+        ];
+
+        assert_eq!(pairs, expected);
+        assert!(!mods.is_valid());
+
+        for gamemod in GameMod::all_for_mode(GameMode::Taiko) {
+            let mods: GameMods = [gamemod, GameMod::HiddenTaiko(Default::default())]
+                .into_iter()
+                .collect();
+
+            assert_eq!(mods.conflicts().is_empty(), mods.is_valid());
+        }
+    }
+
+    #[test]
+    fn sanitize_with_custom() {
+        let mods: GameMods = [
+            GameMod::HalfTimeTaiko(Default::default()),
+            GameMod::DaycoreTaiko(Default::default()),
+            GameMod::DoubleTimeTaiko(Default::default()),
+            GameMod::HiddenTaiko(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        // Prefer mods with a higher clock rate
+        let faster = |a: &GameMod, b: &GameMod| {
+            let rate = |gamemod: &GameMod| gamemod.clock_rate().unwrap_or(1.0);
+
+            rate(b).total_cmp(&rate(a))
+        };
+
+        let mut custom = mods.clone();
+        let removed = custom.sanitize_with(SanitizeStrategy::Custom(&faster));
+
+        assert_eq!(custom.to_string(), "DTHD");
+        assert_eq!(removed.len(), 2);
+
+        // Without preference, the result matches `sanitize`
+        let mut fallback = mods.clone();
+        fallback.sanitize_with(SanitizeStrategy::PreferKind(&[]));
+
+        let mut sanitized = mods.clone();
+        sanitized.sanitize();
+
+        assert_eq!(fallback, sanitized);
+
+        // Not a total order but must not panic
+        let mut inconsistent = mods;
+        inconsistent.sanitize_with(SanitizeStrategy::Custom(&|_, _| Ordering::Less));
+        assert_eq!(inconsistent.len(), 2);
+    }
+
+    #[test]
+    fn split_by_mode() {
+        let mods: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::HiddenMania(Default::default()),
+            GameMod::DoubleTimeMania(Default::default()),
+            GameMod::FlashlightOsu(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        let split = mods.split_by_mode();
+
+        assert_eq!(split[GameMode::Osu as usize].len(), 2);
+        assert!(split[GameMode::Taiko as usize].is_empty());
+        assert!(split[GameMode::Catch as usize].is_empty());
+        assert_eq!(split[GameMode::Mania as usize].mode(), Ok(GameMode::Mania));
+
+        let merged: GameMods = split.into_iter().flatten().collect();
+        assert_eq!(merged, mods);
+    }
+
+    #[test]
+    fn time_ramp() {
+        let mods: GameMods = [
+            GameMod::WindDownTaiko(WindDownTaiko {
+                initial_rate: Some(1.2),
+                final_rate: Some(0.8),
+                adjust_pitch: None,
+            }),
+            GameMod::HiddenTaiko(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!((mods.rate_at(-500.0, 0.0, 4000.0) - 1.2).abs() < f64::EPSILON);
+        assert!((mods.rate_at(1500.0, 0.0, 4000.0) - 1.0).abs() < f64::EPSILON);
+        assert!((mods.rate_at(3500.0, 0.0, 4000.0) - 0.8).abs() < f64::EPSILON);
+
+        // Linear ramp over 3s and 1s at the final rate
+        let expected = 3000.0 * (1.2_f64 / 0.8).ln() / 0.4 + 1000.0 / 0.8;
+        assert!((mods.effective_length(4000.0) - expected).abs() < 1e-6);
+        assert!((mods.average_rate(4000.0) - 4000.0 / expected).abs() < 1e-9);
+
+        let nm = GameMods::new();
+        assert!((nm.effective_length(4000.0) - 4000.0).abs() < f64::EPSILON);
+        assert!((nm.average_rate(0.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn mania_key_count() {
+        let mods: GameMods = [
+            GameMod::TenKeysMania(Default::default()),
+            GameMod::HiddenMania(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(mods.mania_key_count(4, true), 10);
+        assert_eq!(mods.mania_key_count(4, false), 4);
+
+        let ds = GameMods::from(GameMod::DualStagesMania(Default::default()));
+        assert_eq!(ds.mania_key_count(5, true), 10);
+
+        // Only osu!mania mods are considered
+        let mut mixed = ds;
+        mixed.insert(GameMod::new("4K", GameMode::Osu));
+        assert_eq!(mixed.mania_key_count(5, true), 10);
+
+        let multiple: GameMods = [
+            GameMod::SevenKeysMania(Default::default()),
+            GameMod::FourKeysMania(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(multiple.mania_key_count(5, true), 7);
+    }
 }
@@ -0,0 +1,312 @@
+// <https://github.com/ppy/osu/blob/master/osu.Game/Utils/ModUtils.cs>
+// <https://github.com/ppy/osu-server-spectator/blob/master/osu.Server.Spectator/Hubs/Multiplayer/MultiplayerHub.cs>
+
+use crate::{
+    error::MultiplayerModsError, GameMod, GameModKind, GameMode, GameMods, GameModsIntermode,
+};
+
+/// Checks that no mod excludes another.
+///
+/// Equivalent to `ModUtils.CheckCompatibleSet`.
+///
+/// # Example
+/// ```rust
+/// use rosu_mods::{error::MultiplayerModsError, multiplayer, GameMod};
+///
+/// # let mods: rosu_mods::GameMods = [
+/// #   GameMod::HiddenOsu(Default::default()),
+/// #   GameMod::DoubleTimeOsu(Default::default()),
+/// #   GameMod::HalfTimeOsu(Default::default()),
+/// # ].into_iter().collect();
+/// # /*
+/// let mods = mods!(Osu: HD DT HT);
+/// # */
+/// let Err(MultiplayerModsError::IncompatibleMods { invalid }) =
+///     multiplayer::check_compatible_set(&mods)
+/// else {
+///     panic!("expected incompatible mods");
+/// };
+///
+/// assert_eq!(invalid.len(), 2);
+/// ```
+pub fn check_compatible_set(mods: &GameMods) -> Result<(), MultiplayerModsError> {
+    let mods: Vec<_> = mods.iter().collect();
+
+    check_compatible(&mods).map_err(|invalid| MultiplayerModsError::IncompatibleMods { invalid })
+}
+
+/// Checks that the mods are compatible and valid for multiplayer so that
+/// they can be used as a room's required mods.
+///
+/// Mods of kind [`GameModKind::System`] are never valid.
+///
+/// Equivalent to `ModUtils.CheckValidRequiredModsForMultiplayer`.
+pub fn check_valid_required_mods(mods: &GameMods) -> Result<(), MultiplayerModsError> {
+    check_compatible_set(mods)?;
+
+    check_valid(mods, GameMod::is_valid_for_multiplayer)
+        .map_err(|invalid| MultiplayerModsError::InvalidRequiredMods { invalid })
+}
+
+/// Checks that the mods can be used as a room's allowed free mods.
+///
+/// Mods of kind [`GameModKind::System`] are never valid.
+///
+/// Equivalent to `ModUtils.CheckValidFreeModsForMultiplayer`.
+pub fn check_valid_free_mods(mods: &GameMods) -> Result<(), MultiplayerModsError> {
+    check_valid(mods, GameMod::is_valid_for_multiplayer_as_free_mod)
+        .map_err(|invalid| MultiplayerModsError::InvalidFreeMods { invalid })
+}
+
+/// Validates the required and allowed mods of a playlist item for the given
+/// mode.
+///
+/// Besides the individual checks of the required and allowed mods, each
+/// allowed mod must be compatible with all required mods. Allowed mods carry
+/// no settings and are checked as mods of the given mode.
+///
+/// # Example
+/// ```rust
+/// use rosu_mods::{error::MultiplayerModsError, multiplayer, GameMod, GameModIntermode, GameMode, GameMods, GameModsIntermode};
+///
+/// let required = GameMods::from(GameMod::DoubleTimeOsu(Default::default()));
+/// let mut allowed: GameModsIntermode = [GameModIntermode::Hidden].into_iter().collect();
+///
+/// assert!(multiplayer::validate_playlist_item(GameMode::Osu, &required, &allowed).is_ok());
+///
+/// allowed.insert(GameModIntermode::HalfTime);
+/// let err = multiplayer::validate_playlist_item(GameMode::Osu, &required, &allowed).unwrap_err();
+///
+/// assert!(matches!(err, MultiplayerModsError::InvalidFreeMods { .. }));
+/// ```
+pub fn validate_playlist_item(
+    mode: GameMode,
+    required: &GameMods,
+    allowed: &GameModsIntermode,
+) -> Result<(), MultiplayerModsError> {
+    let allowed = allowed.with_mode(mode);
+
+    check_mode(mode, required)?;
+    check_valid_required_mods(required)?;
+    check_valid_free_mods(&allowed)?;
+
+    for allowed_mod in allowed.iter() {
+        let combined: Vec<_> = required.iter().chain([allowed_mod]).collect();
+
+        check_compatible(&combined)
+            .map_err(|invalid| MultiplayerModsError::IncompatibleWithRequiredMods { invalid })?;
+    }
+
+    Ok(())
+}
+
+/// Validates the free mods chosen by a user for a playlist item.
+///
+/// The mods must be of the given mode, contained in the allowed mods, and
+/// compatible both with each other and with the required mods.
+///
+/// # Example
+/// ```rust
+/// use rosu_mods::{error::MultiplayerModsError, multiplayer, GameMod, GameModIntermode, GameMode, GameMods};
+///
+/// let required = GameMods::from(GameMod::DoubleTimeOsu(Default::default()));
+/// let allowed = [GameModIntermode::Hidden, GameModIntermode::HardRock].into_iter().collect();
+///
+/// let user = GameMods::from(GameMod::HiddenOsu(Default::default()));
+/// assert!(multiplayer::validate_user_mods(GameMode::Osu, &required, &allowed, &user).is_ok());
+///
+/// let user = GameMods::from(GameMod::FlashlightOsu(Default::default()));
+/// let err = multiplayer::validate_user_mods(GameMode::Osu, &required, &allowed, &user).unwrap_err();
+/// assert!(matches!(err, MultiplayerModsError::NotAllowed { .. }));
+/// ```
+pub fn validate_user_mods(
+    mode: GameMode,
+    required: &GameMods,
+    allowed: &GameModsIntermode,
+    user: &GameMods,
+) -> Result<(), MultiplayerModsError> {
+    check_mode(mode, user)?;
+
+    let not_allowed: Vec<_> = user
+        .iter()
+        .filter(|gamemod| !allowed.contains(gamemod.intermode()))
+        .cloned()
+        .collect();
+
+    if !not_allowed.is_empty() {
+        return Err(MultiplayerModsError::NotAllowed {
+            invalid: not_allowed,
+        });
+    }
+
+    check_compatible_set(user)?;
+
+    let combined: Vec<_> = required.iter().chain(user.iter()).collect();
+
+    check_compatible(&combined)
+        .map_err(|invalid| MultiplayerModsError::IncompatibleWithRequiredMods { invalid })
+}
+
+fn check_mode(mode: GameMode, mods: &GameMods) -> Result<(), MultiplayerModsError> {
+    let invalid: Vec<_> = mods
+        .iter()
+        .filter(|gamemod| gamemod.mode() != mode)
+        .cloned()
+        .collect();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(MultiplayerModsError::WrongMode { invalid, mode })
+    }
+}
+
+/// Returns all mods that are excluded by another mod or, if any mod
+/// appears multiple times, all mods.
+fn check_compatible(mods: &[&GameMod]) -> Result<(), Vec<GameMod>> {
+    let has_duplicates = mods
+        .iter()
+        .enumerate()
+        .any(|(i, a)| mods[i + 1..].iter().any(|b| a.intermode() == b.intermode()));
+
+    if has_duplicates {
+        return Err(mods.iter().map(|&gamemod| gamemod.clone()).collect());
+    }
+
+    let mut invalid: Vec<GameMod> = Vec::new();
+
+    for gamemod in mods {
+        let incompatible = gamemod.incompatible_mods();
+
+        for &other in mods {
+            if other.intermode() != gamemod.intermode()
+                && incompatible.contains(&other.acronym())
+                && !invalid.contains(other)
+            {
+                invalid.push(other.clone());
+            }
+        }
+    }
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+fn check_valid(mods: &GameMods, valid: fn(&GameMod) -> bool) -> Result<(), Vec<GameMod>> {
+    let invalid: Vec<_> = mods
+        .iter()
+        .filter(|gamemod| gamemod.kind() == GameModKind::System || !valid(gamemod))
+        .cloned()
+        .collect();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GameModIntermode;
+
+    use super::*;
+
+    fn mods<const N: usize>(mods: [GameMod; N]) -> GameMods {
+        mods.into_iter().collect()
+    }
+
+    #[test]
+    fn required_mods() {
+        let required = mods([
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::DoubleTimeOsu(Default::default()),
+        ]);
+        assert_eq!(check_valid_required_mods(&required), Ok(()));
+
+        let required = mods([GameMod::AutoplayOsu(Default::default())]);
+        assert!(matches!(
+            check_valid_required_mods(&required),
+            Err(MultiplayerModsError::InvalidRequiredMods { .. })
+        ));
+
+        let required = mods([GameMod::ScoreV2Osu(Default::default())]);
+        assert_eq!(
+            check_valid_required_mods(&required),
+            Err(MultiplayerModsError::InvalidRequiredMods {
+                invalid: vec![GameMod::ScoreV2Osu(Default::default())],
+            })
+        );
+    }
+
+    #[test]
+    fn free_mods() {
+        let allowed = mods([
+            GameMod::HiddenTaiko(Default::default()),
+            GameMod::HardRockTaiko(Default::default()),
+        ]);
+        assert_eq!(check_valid_free_mods(&allowed), Ok(()));
+
+        let allowed = mods([GameMod::DoubleTimeTaiko(Default::default())]);
+        assert!(matches!(
+            check_valid_free_mods(&allowed),
+            Err(MultiplayerModsError::InvalidFreeMods { .. })
+        ));
+
+        let allowed = mods([GameMod::ScoreV2Taiko(Default::default())]);
+        assert_eq!(
+            check_valid_free_mods(&allowed),
+            Err(MultiplayerModsError::InvalidFreeMods {
+                invalid: vec![GameMod::ScoreV2Taiko(Default::default())],
+            })
+        );
+    }
+
+    #[test]
+    fn playlist_item() {
+        let required = mods([GameMod::HardRockCatch(Default::default())]);
+
+        let allowed: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::Easy]
+            .into_iter()
+            .collect();
+        let err = validate_playlist_item(GameMode::Catch, &required, &allowed).unwrap_err();
+
+        let MultiplayerModsError::IncompatibleWithRequiredMods { invalid } = err else {
+            panic!("unexpected error: {err:?}");
+        };
+
+        assert_eq!(invalid.len(), 2);
+
+        let allowed = GameModsIntermode::from(GameModIntermode::Hidden);
+        assert_eq!(
+            validate_playlist_item(GameMode::Osu, &required, &allowed),
+            Err(MultiplayerModsError::WrongMode {
+                invalid: vec![GameMod::HardRockCatch(Default::default())],
+                mode: GameMode::Osu,
+            })
+        );
+    }
+
+    #[test]
+    fn user_mods() {
+        let required = mods([GameMod::HardRockOsu(Default::default())]);
+        let allowed: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::Easy]
+            .into_iter()
+            .collect();
+
+        let user = mods([GameMod::EasyOsu(Default::default())]);
+        assert!(matches!(
+            validate_user_mods(GameMode::Osu, &required, &allowed, &user),
+            Err(MultiplayerModsError::IncompatibleWithRequiredMods { .. })
+        ));
+
+        let user = mods([GameMod::HiddenOsu(Default::default())]);
+        assert_eq!(
+            validate_user_mods(GameMode::Osu, &required, &allowed, &user),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use crate::SettingSimple;
+
+/// Metadata of a single setting of a [`GameMod`].
+///
+/// [`GameMod`]: crate::GameMod
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SettingSchema {
+    /// The name of the setting i.e. the field name of the gamemod struct.
+    pub name: &'static str,
+    /// The type of the setting's value.
+    pub kind: SettingKind,
+    /// The description of the setting as provided by osu!lazer.
+    pub description: &'static str,
+    /// The value that osu!lazer uses if the setting is unset.
+    ///
+    /// `None` if there is no fixed default e.g. because it depends on the
+    /// beatmap like the overrides of `DifficultyAdjust`.
+    pub default: Option<SettingDefault>,
+    /// The bounds and precision of a [`SettingKind::Number`] setting.
+    ///
+    /// `None` for non-numeric settings or numbers without bounds such as seeds.
+    pub range: Option<SettingRange>,
+}
+
+/// The type of a [`SettingSchema`]'s value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SettingKind {
+    /// The setting is stored as `Option<bool>`.
+    Bool,
+    /// The setting is stored as `Option<f64>`.
+    Number,
+    /// The setting is stored as `Option<String>`.
+    String,
+}
+
+/// The default value of a [`SettingSchema`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum SettingDefault {
+    /// Default of a [`SettingKind::Bool`] setting.
+    Bool(bool),
+    /// Default of a [`SettingKind::Number`] setting.
+    Number(f64),
+    /// Default of a [`SettingKind::String`] setting.
+    String(&'static str),
+}
+
+/// The valid values of a numeric [`SettingSchema`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SettingRange {
+    /// The smallest valid value.
+    pub min: f64,
+    /// The largest valid value.
+    pub max: f64,
+    /// Valid values are multiples of the precision.
+    pub precision: f64,
+}
+
+/// Short names for settings that are commonly abbreviated.
+///
+/// Pairs of `(alias, setting name)`.
+pub(crate) const SETTING_ALIASES: &[(&str, &str)] = &[
+    ("cs", "circle_size"),
+    ("ar", "approach_rate"),
+    ("od", "overall_difficulty"),
+    ("hp", "drain_rate"),
+];
+
+/// The alias of a setting if there is one, otherwise the setting name itself.
+pub(crate) fn setting_alias(name: &'static str) -> &'static str {
+    SETTING_ALIASES
+        .iter()
+        .find_map(|(alias, setting)| (*setting == name).then_some(*alias))
+        .unwrap_or(name)
+}
+
+/// Find the setting with the given name or alias, ignoring ASCII case.
+pub(crate) fn find_setting<'s>(
+    schemas: &'s [SettingSchema],
+    name: &str,
+) -> Option<&'s SettingSchema> {
+    let name = SETTING_ALIASES
+        .iter()
+        .find_map(|(alias, setting)| alias.eq_ignore_ascii_case(name).then_some(*setting))
+        .unwrap_or(name);
+
+    schemas
+        .iter()
+        .find(|schema| schema.name.eq_ignore_ascii_case(name))
+}
+
+/// Parse a textual setting value of the given kind.
+///
+/// Numbers may carry a trailing `x` as is common for rates.
+pub(crate) fn parse_setting_value(kind: SettingKind, value: &str) -> Option<SettingSimple> {
+    match kind {
+        SettingKind::Bool => match value.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Some(SettingSimple::Bool(true)),
+            "false" | "no" | "off" | "0" => Some(SettingSimple::Bool(false)),
+            _ => None,
+        },
+        SettingKind::Number => value
+            .strip_suffix(['x', 'X'])
+            .unwrap_or(value)
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(SettingSimple::Number),
+        SettingKind::String if value.is_empty() => None,
+        SettingKind::String => Some(SettingSimple::String(value.to_owned())),
+    }
+}
+
+impl From<SettingDefault> for SettingSimple {
+    fn from(default: SettingDefault) -> Self {
+        match default {
+            SettingDefault::Bool(value) => Self::Bool(value),
+            SettingDefault::Number(value) => Self::Number(value),
+            SettingDefault::String(value) => Self::String(value.to_owned()),
+        }
+    }
+}
+
+impl SettingRange {
+    /// Whether the value lies within `min` and `max`.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Whether the value is a multiple of the precision.
+    pub fn is_on_step(&self, value: f64) -> bool {
+        let steps = value / self.precision;
+
+        (steps - steps.round()).abs() < 1e-6
+    }
+
+    /// Whether both values round to the same multiple of the precision.
+    pub fn eq_at_precision(&self, a: f64, b: f64) -> bool {
+        let a = (a / self.precision).round();
+        let b = (b / self.precision).round();
+
+        (a - b).abs() < f64::EPSILON
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generated_mods::{BarrelRollOsu, DoubleTimeMania, FlashlightCatch},
+        GameMod, GameModIntermode, GameMode, SettingSimple,
+    };
+
+    use super::*;
+
+    #[test]
+    fn struct_schema() {
+        let schema = DoubleTimeMania::settings_schema();
+
+        assert_eq!(schema.len(), 2);
+        assert_eq!(schema[0].name, "speed_change");
+        assert_eq!(schema[0].kind, SettingKind::Number);
+        assert_eq!(schema[0].default, Some(SettingDefault::Number(1.5)));
+        assert_eq!(
+            schema[0].range,
+            Some(SettingRange {
+                min: 1.01,
+                max: 2.0,
+                precision: 0.01
+            })
+        );
+        assert_eq!(schema[1].name, "adjust_pitch");
+        assert_eq!(schema[1].kind, SettingKind::Bool);
+    }
+
+    #[test]
+    fn range_precision() {
+        let range = SettingRange {
+            min: 0.5,
+            max: 2.0,
+            precision: 0.1,
+        };
+
+        assert!(range.eq_at_precision(1.0, 1.04));
+        assert!(range.eq_at_precision(1.04, 0.96));
+        assert!(!range.eq_at_precision(1.04, 1.06));
+        assert!(!range.eq_at_precision(1.0, 1.06));
+    }
+
+    #[test]
+    fn effective_settings() {
+        let fl = FlashlightCatch {
+            size_multiplier: Some(1.3),
+            combo_based_size: None,
+        };
+
+        assert!((fl.effective_size_multiplier() - 1.3).abs() < f64::EPSILON);
+        assert!(fl.effective_combo_based_size());
+
+        let br = BarrelRollOsu::default();
+        assert_eq!(br.effective_direction(), "Clockwise");
+    }
+
+    #[test]
+    fn setting_value() {
+        let br = GameMod::BarrelRollOsu(BarrelRollOsu {
+            spin_speed: Some(0.7),
+            direction: Some(String::from("Counterclockwise")),
+        });
+
+        assert_eq!(br.setting("spin_speed"), Some(SettingSimple::Number(0.7)));
+        assert_eq!(
+            br.setting("direction"),
+            Some(SettingSimple::String(String::from("Counterclockwise")))
+        );
+        assert_eq!(br.setting("unknown"), None);
+    }
+
+    #[test]
+    fn defaults_filled() {
+        let dt = GameMod::DoubleTimeMania(DoubleTimeMania {
+            speed_change: Some(1.2),
+            adjust_pitch: None,
+        });
+
+        let expected = GameMod::DoubleTimeMania(DoubleTimeMania {
+            speed_change: Some(1.2),
+            adjust_pitch: Some(false),
+        });
+
+        assert_eq!(dt.with_defaults_filled(), expected);
+
+        let da = GameMod::DifficultyAdjustOsu(Default::default()).with_defaults_filled();
+        let GameMod::DifficultyAdjustOsu(da) = da else {
+            unreachable!()
+        };
+
+        assert_eq!(da.approach_rate, None);
+        assert_eq!(da.extended_limits, Some(false));
+    }
+
+    #[test]
+    fn gamemod_schema() {
+        let gamemod = GameMod::AccuracyChallengeOsu(Default::default());
+        let names: Vec<_> = gamemod
+            .settings_schema()
+            .iter()
+            .map(|setting| setting.name)
+            .collect();
+
+        assert_eq!(
+            names,
+            ["minimum_accuracy", "accuracy_judge_mode", "restart"]
+        );
+        assert!(GameMod::HardRockOsu(Default::default())
+            .settings_schema()
+            .is_empty());
+    }
+
+    #[test]
+    fn intermode_schema() {
+        let osu = GameModIntermode::DifficultyAdjust.settings_schema(GameMode::Osu);
+        let taiko = GameModIntermode::DifficultyAdjust.settings_schema(GameMode::Taiko);
+
+        assert!(osu.is_some_and(|schema| schema.iter().any(|s| s.name == "approach_rate")));
+        assert!(taiko.is_some_and(|schema| schema.iter().any(|s| s.name == "scroll_speed")));
+        assert!(GameModIntermode::FadeIn
+            .settings_schema(GameMode::Osu)
+            .is_none());
+    }
+}
@@ -0,0 +1,442 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{error::SlotValidationError, GameModIntermode, GameMods, GameModsIntermode};
+
+/// The slot of a beatmap within a tournament [`Mappool`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PoolSlot {
+    /// `NM`
+    NoMod,
+    /// `HD`
+    Hidden,
+    /// `HR`
+    HardRock,
+    /// `DT`
+    DoubleTime,
+    /// `FM`
+    FreeMod,
+    /// `TB`
+    Tiebreaker,
+    /// `EZ`
+    Easy,
+}
+
+impl PoolSlot {
+    /// All slots in the order they commonly appear in a mappool.
+    pub const ALL: [Self; 7] = [
+        Self::NoMod,
+        Self::Hidden,
+        Self::HardRock,
+        Self::DoubleTime,
+        Self::FreeMod,
+        Self::Tiebreaker,
+        Self::Easy,
+    ];
+
+    /// The short name of the slot e.g. `"HD"` or `"FM"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::NoMod => "NM",
+            Self::Hidden => "HD",
+            Self::HardRock => "HR",
+            Self::DoubleTime => "DT",
+            Self::FreeMod => "FM",
+            Self::Tiebreaker => "TB",
+            Self::Easy => "EZ",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for PoolSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The mods that may be played on a [`PoolSlot`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlotRules {
+    /// Mods that every play must contain.
+    pub required: GameModsIntermode,
+    /// Mods that a play may contain in addition to the required mods.
+    pub allowed: GameModsIntermode,
+    /// If not empty, a play must contain at least one of these mods.
+    pub require_one_of: GameModsIntermode,
+    /// The clock rate that a play must have, if any.
+    pub clock_rate: Option<f64>,
+}
+
+impl SlotRules {
+    /// The commonly used rules of the given slot.
+    ///
+    /// - `NM`: no mods
+    /// - `HD`, `HR`, `EZ`: the respective mod is required
+    /// - `DT`: `DT` is required at a clock rate of `1.5`
+    /// - `FM`: any of `HD`, `HR`, `EZ`, and `FL` but at least one of `HD`,
+    ///   `HR`, or `EZ`
+    /// - `TB`: any of `HD`, `HR`, `EZ`, and `FL`
+    pub fn standard(slot: PoolSlot) -> Self {
+        let free_mods = || {
+            [
+                GameModIntermode::Hidden,
+                GameModIntermode::HardRock,
+                GameModIntermode::Easy,
+                GameModIntermode::Flashlight,
+            ]
+            .into_iter()
+            .collect()
+        };
+
+        let required = |gamemod: GameModIntermode| Self {
+            required: gamemod.into(),
+            ..Self::default()
+        };
+
+        match slot {
+            PoolSlot::NoMod => Self::default(),
+            PoolSlot::Hidden => required(GameModIntermode::Hidden),
+            PoolSlot::HardRock => required(GameModIntermode::HardRock),
+            PoolSlot::Easy => required(GameModIntermode::Easy),
+            PoolSlot::DoubleTime => Self {
+                clock_rate: Some(1.5),
+                ..required(GameModIntermode::DoubleTime)
+            },
+            PoolSlot::FreeMod => Self {
+                allowed: free_mods(),
+                require_one_of: [
+                    GameModIntermode::Hidden,
+                    GameModIntermode::HardRock,
+                    GameModIntermode::Easy,
+                ]
+                .into_iter()
+                .collect(),
+                ..Self::default()
+            },
+            PoolSlot::Tiebreaker => Self {
+                allowed: free_mods(),
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// Mod rules of a tournament mappool.
+///
+/// Each [`PoolSlot`] has its own [`SlotRules`]. Additionally, some mods are
+/// allowed on every slot and some mods count as other mods e.g. `NC` as `DT`.
+///
+/// # Example
+/// ```
+/// use rosu_mods::{
+///     error::SlotValidationError, GameMod, GameMods, Mappool, PoolSlot,
+/// };
+///
+/// let pool = Mappool::new();
+///
+/// let hdnc: GameMods = [
+///     GameMod::HiddenOsu(Default::default()),
+///     GameMod::NightcoreOsu(Default::default()),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert!(pool.validate_play(PoolSlot::DoubleTime, &GameMods::from(
+///     GameMod::NightcoreOsu(Default::default())
+/// )).is_ok());
+///
+/// assert!(matches!(
+///     pool.validate_play(PoolSlot::DoubleTime, &hdnc),
+///     Err(SlotValidationError::NotAllowed { .. }),
+/// ));
+///
+/// assert!(matches!(
+///     pool.validate_play(PoolSlot::FreeMod, &GameMods::new()),
+///     Err(SlotValidationError::MissingOneOf { .. }),
+/// ));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mappool {
+    slots: [SlotRules; 7],
+    always_allowed: GameModsIntermode,
+    equivalents: Vec<(GameModIntermode, GameModIntermode)>,
+}
+
+impl Mappool {
+    /// Create a new [`Mappool`] with the [standard rules] for each slot.
+    ///
+    /// `NF`, `CL`, and `SV2` are allowed on every slot and `NC` counts as `DT`.
+    ///
+    /// [standard rules]: SlotRules::standard
+    pub fn new() -> Self {
+        Self {
+            slots: PoolSlot::ALL.map(SlotRules::standard),
+            always_allowed: [
+                GameModIntermode::NoFail,
+                GameModIntermode::Classic,
+                GameModIntermode::ScoreV2,
+            ]
+            .into_iter()
+            .collect(),
+            equivalents: vec![(GameModIntermode::Nightcore, GameModIntermode::DoubleTime)],
+        }
+    }
+
+    /// Replace the rules of a slot.
+    #[must_use]
+    pub fn with_rules(mut self, slot: PoolSlot, rules: SlotRules) -> Self {
+        self.slots[slot.index()] = rules;
+
+        self
+    }
+
+    /// Replace the mods that are allowed on every slot.
+    #[must_use]
+    pub fn with_always_allowed(mut self, mods: GameModsIntermode) -> Self {
+        self.always_allowed = mods;
+
+        self
+    }
+
+    /// Count `gamemod` as `counts_as` when validating plays e.g. `DC` as `HT`.
+    #[must_use]
+    pub fn with_equivalent(
+        mut self,
+        gamemod: GameModIntermode,
+        counts_as: GameModIntermode,
+    ) -> Self {
+        self.equivalents.push((gamemod, counts_as));
+
+        self
+    }
+
+    /// The rules of a slot.
+    pub const fn rules(&self, slot: PoolSlot) -> &SlotRules {
+        &self.slots[slot.index()]
+    }
+
+    /// The mods that are allowed on every slot.
+    pub const fn always_allowed(&self) -> &GameModsIntermode {
+        &self.always_allowed
+    }
+
+    /// Check whether the mods of a play are valid for the given slot.
+    ///
+    /// The checks are done in the following order:
+    /// 1. All mods must be required, allowed, or always allowed
+    /// 2. All required mods must be present
+    /// 3. At least one of the slot's [`SlotRules::require_one_of`] must be present
+    /// 4. The mods must not exclude each other
+    /// 5. The clock rate must match [`SlotRules::clock_rate`]
+    pub fn validate_play(
+        &self,
+        slot: PoolSlot,
+        mods: &GameMods,
+    ) -> Result<(), SlotValidationError> {
+        let rules = self.rules(slot);
+
+        let played: GameModsIntermode = mods
+            .iter()
+            .map(|gamemod| self.counted_as(gamemod.intermode()))
+            .collect();
+
+        let not_allowed: GameModsIntermode = played
+            .iter()
+            .filter(|&gamemod| {
+                !(rules.required.contains(gamemod)
+                    || rules.allowed.contains(gamemod)
+                    || self.always_allowed.contains(gamemod))
+            })
+            .collect();
+
+        if !not_allowed.is_empty() {
+            return Err(SlotValidationError::NotAllowed {
+                slot,
+                mods: not_allowed,
+            });
+        }
+
+        let missing: GameModsIntermode = rules
+            .required
+            .iter()
+            .filter(|&gamemod| !played.contains(gamemod))
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(SlotValidationError::MissingRequired {
+                slot,
+                mods: missing,
+            });
+        }
+
+        if !(rules.require_one_of.is_empty() || rules.require_one_of.intersects(&played)) {
+            return Err(SlotValidationError::MissingOneOf {
+                slot,
+                mods: rules.require_one_of.clone(),
+            });
+        }
+
+        if !mods.is_valid() {
+            return Err(SlotValidationError::Incompatible { slot });
+        }
+
+        if let Some(expected) = rules.clock_rate {
+            let actual = mods.clock_rate();
+
+            if !actual.is_some_and(|actual| (actual - expected).abs() < 1e-6) {
+                return Err(SlotValidationError::ClockRate {
+                    slot,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn counted_as(&self, gamemod: GameModIntermode) -> GameModIntermode {
+        self.equivalents
+            .iter()
+            .find_map(|&(from, to)| (from == gamemod).then_some(to))
+            .unwrap_or(gamemod)
+    }
+}
+
+impl Default for Mappool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generated_mods::{DoubleTimeOsu, NightcoreOsu},
+        GameMod,
+    };
+
+    use super::*;
+
+    fn mods<const N: usize>(mods: [GameMod; N]) -> GameMods {
+        mods.into_iter().collect()
+    }
+
+    #[test]
+    fn slot_order() {
+        let pool = Mappool::new();
+
+        for slot in PoolSlot::ALL {
+            assert_eq!(pool.rules(slot), &SlotRules::standard(slot), "{slot}");
+        }
+
+        let names: Vec<_> = PoolSlot::ALL.iter().map(|slot| slot.as_str()).collect();
+        assert_eq!(names, ["NM", "HD", "HR", "DT", "FM", "TB", "EZ"]);
+        assert!(PoolSlot::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn required_slots() {
+        let pool = Mappool::new();
+
+        let hd = mods([GameMod::HiddenOsu(Default::default())]);
+        assert_eq!(pool.validate_play(PoolSlot::Hidden, &hd), Ok(()));
+        assert_eq!(
+            pool.validate_play(PoolSlot::NoMod, &hd),
+            Err(SlotValidationError::NotAllowed {
+                slot: PoolSlot::NoMod,
+                mods: GameModIntermode::Hidden.into(),
+            })
+        );
+
+        let nf = mods([GameMod::NoFailOsu(Default::default())]);
+        assert_eq!(pool.validate_play(PoolSlot::NoMod, &nf), Ok(()));
+        assert_eq!(
+            pool.validate_play(PoolSlot::HardRock, &nf),
+            Err(SlotValidationError::MissingRequired {
+                slot: PoolSlot::HardRock,
+                mods: GameModIntermode::HardRock.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn double_time() {
+        let pool = Mappool::new();
+
+        let nc = mods([GameMod::NightcoreOsu(Default::default())]);
+        assert_eq!(pool.validate_play(PoolSlot::DoubleTime, &nc), Ok(()));
+
+        let nc = mods([GameMod::NightcoreOsu(NightcoreOsu {
+            speed_change: Some(1.3),
+        })]);
+        assert_eq!(
+            pool.validate_play(PoolSlot::DoubleTime, &nc),
+            Err(SlotValidationError::ClockRate {
+                slot: PoolSlot::DoubleTime,
+                expected: 1.5,
+                actual: Some(1.3),
+            })
+        );
+
+        let dt = mods([GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.5),
+            adjust_pitch: Some(true),
+        })]);
+        assert_eq!(pool.validate_play(PoolSlot::DoubleTime, &dt), Ok(()));
+    }
+
+    #[test]
+    fn free_mod() {
+        let pool = Mappool::new();
+
+        let fl = mods([GameMod::FlashlightOsu(Default::default())]);
+        assert!(matches!(
+            pool.validate_play(PoolSlot::FreeMod, &fl),
+            Err(SlotValidationError::MissingOneOf { .. })
+        ));
+        assert_eq!(pool.validate_play(PoolSlot::Tiebreaker, &fl), Ok(()));
+
+        let hrfl = mods([
+            GameMod::HardRockOsu(Default::default()),
+            GameMod::FlashlightOsu(Default::default()),
+        ]);
+        assert_eq!(pool.validate_play(PoolSlot::FreeMod, &hrfl), Ok(()));
+
+        let ezhr = mods([
+            GameMod::EasyOsu(Default::default()),
+            GameMod::HardRockOsu(Default::default()),
+        ]);
+        assert_eq!(
+            pool.validate_play(PoolSlot::FreeMod, &ezhr),
+            Err(SlotValidationError::Incompatible {
+                slot: PoolSlot::FreeMod
+            })
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        let pool = Mappool::new()
+            .with_always_allowed(GameModsIntermode::new())
+            .with_equivalent(GameModIntermode::Daycore, GameModIntermode::HalfTime)
+            .with_rules(
+                PoolSlot::Easy,
+                SlotRules {
+                    required: GameModIntermode::HalfTime.into(),
+                    clock_rate: Some(0.75),
+                    ..Default::default()
+                },
+            );
+
+        let dc = mods([GameMod::DaycoreOsu(Default::default())]);
+        assert_eq!(pool.validate_play(PoolSlot::Easy, &dc), Ok(()));
+
+        let nf = mods([GameMod::NoFailOsu(Default::default())]);
+        assert!(pool.validate_play(PoolSlot::NoMod, &nf).is_err());
+    }
+}
@@ -1,24 +1,9 @@
 use std::borrow::Cow;
 
-/// Provide an iterator over substrings of the given length on the given
-/// source string
-pub(crate) fn cut(mut source: &str, n: usize) -> impl Iterator<Item = &str> {
-    std::iter::from_fn(move || {
-        if source.is_empty() {
-            None
-        } else {
-            let end_idx = source
-                .char_indices()
-                .nth(n - 1)
-                .map_or_else(|| source.len(), |(idx, c)| idx + c.len_utf8());
-
-            let (sub_str, rest) = source.split_at(end_idx);
-            source = rest;
-
-            Some(sub_str)
-        }
-    })
-}
+use crate::{
+    error::{AcronymsParseError, AcronymsParseErrorKind},
+    Acronym,
+};
 
 /// Put a `&str` into ASCII uppercase.
 ///
@@ -47,20 +32,244 @@ pub(crate) fn split_prefix<const N: usize>(s: &str) -> (&str, &str) {
     s.split_at(end_idx)
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn cut() {
-        let mut iter = super::cut("hDHrdTv2n", 2);
-
-        assert_eq!(iter.next(), Some("hD"));
-        assert_eq!(iter.next(), Some("Hr"));
-        assert_eq!(iter.next(), Some("dT"));
-        assert_eq!(iter.next(), Some("v2"));
-        assert_eq!(iter.next(), Some("n"));
-        assert_eq!(iter.next(), None);
+/// Splits a string of acronyms into mods while keeping their order.
+///
+/// `lookup` receives capitalized ASCII candidates of length 2 or 3. On
+/// failure, `suggest` receives the unknown substring.
+///
+/// Two-character acronyms are preferred but three-character acronyms are
+/// used whenever that is the only way to parse the whole string.
+pub(crate) fn split_acronyms<T, L, S>(
+    s: &str,
+    lookup: L,
+    suggest: S,
+) -> Result<Vec<T>, AcronymsParseError>
+where
+    L: Fn(&str) -> Option<T>,
+    S: FnOnce(&str) -> Option<Acronym>,
+{
+    let uppercased = to_uppercase(s);
+    let len = uppercased.len();
+
+    let lookup = |start: usize, n: usize| {
+        uppercased
+            .get(start..start + n)
+            .filter(|candidate| candidate.bytes().all(|byte| byte.is_ascii_alphanumeric()))
+            .and_then(&lookup)
+    };
+
+    // `parsable[i]` denotes whether `uppercased[i..]` can be fully split
+    let mut parsable = vec![false; len + 1];
+    parsable[len] = true;
+
+    for i in (0..len).rev() {
+        parsable[i] = [2, 3]
+            .into_iter()
+            .any(|n| i + n <= len && parsable[i + n] && lookup(i, n).is_some());
+    }
+
+    if parsable[0] {
+        let mut mods = Vec::new();
+        let mut i = 0;
+
+        while i < len {
+            let (n, gamemod) = [2, 3]
+                .into_iter()
+                .filter(|n| i + n <= len && parsable[i + n])
+                .find_map(|n| lookup(i, n).map(|gamemod| (n, gamemod)))
+                .expect("parsable position must have a parsable acronym");
+
+            mods.push(gamemod);
+            i += n;
+        }
+
+        return Ok(mods);
+    }
+
+    // `reachable[i]` denotes whether `uppercased[..i]` can be fully split
+    let mut reachable = vec![false; len + 1];
+    reachable[0] = true;
+
+    for i in 0..len {
+        if reachable[i] {
+            for n in [2, 3] {
+                if i + n <= len && lookup(i, n).is_some() {
+                    reachable[i + n] = true;
+                }
+            }
+        }
     }
 
+    // Parsing failed at the last reachable position
+    let pos = (0..len).rev().find(|&i| reachable[i]).unwrap_or(0);
+
+    // If a known acronym starts right before the failing position, a
+    // different split would have been possible
+    let overlapping = pos
+        .checked_sub(1)
+        .and_then(|start| [2, 3].into_iter().find(|&n| lookup(start, n).is_some()))
+        .map(|n| pos - 1 + n);
+
+    let prev_start = [2, 3]
+        .into_iter()
+        .filter_map(|n| pos.checked_sub(n))
+        .find(|&start| reachable[start] && lookup(start, pos - start).is_some());
+
+    if let (Some(offset), Some(end)) = (prev_start, overlapping) {
+        return Err(AcronymsParseError {
+            offset,
+            substring: Box::from(&s[offset..end]),
+            kind: AcronymsParseErrorKind::AmbiguousSplit,
+            suggestion: None,
+        });
+    }
+
+    let end = if len - pos <= 3 {
+        len
+    } else {
+        pos + split_prefix::<2>(&s[pos..]).0.len()
+    };
+
+    let substring = &s[pos..end];
+
+    let err = AcronymsParseError {
+        offset: pos,
+        substring: Box::from(substring),
+        kind: AcronymsParseErrorKind::UnknownAcronym,
+        suggestion: suggest(substring),
+    };
+
+    Err(err)
+}
+
+/// A group of acronyms within a leniently written string of mods.
+pub(crate) struct LenientGroup<'a> {
+    /// The byte offset of the group within the full string.
+    pub offset: usize,
+    /// The acronyms of the group.
+    pub acronyms: &'a str,
+    /// Whether the group was preceded by a minus.
+    pub excluded: bool,
+}
+
+/// Splits a leniently written string of mods into groups of acronyms.
+///
+/// Groups are separated by whitespace, `,`, `;`, `|`, `/`, `+`, or `-`. A
+/// minus marks all following groups as excluded until the next plus. Any
+/// spelling of "no mod" such as `NM`, `None`, or `No Mod` is skipped.
+pub(crate) fn lenient_groups(s: &str) -> Vec<LenientGroup<'_>> {
+    let is_no_mod = |s: &str| {
+        let normalized: String = s
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        matches!(normalized.as_str(), "nm" | "none" | "nomod" | "nomods")
+    };
+
+    if is_no_mod(s) {
+        return Vec::new();
+    }
+
+    let mut groups = Vec::new();
+    let mut excluded = false;
+    let mut start = None;
+
+    for (i, c) in s.char_indices().chain([(s.len(), ' ')]) {
+        let is_separator = c.is_whitespace() || matches!(c, ',' | ';' | '|' | '/' | '+' | '-');
+
+        match (start, is_separator) {
+            (None, false) => start = Some(i),
+            (Some(offset), true) => {
+                let acronyms = &s[offset..i];
+
+                if !is_no_mod(acronyms) {
+                    groups.push(LenientGroup {
+                        offset,
+                        acronyms,
+                        excluded,
+                    });
+                }
+
+                start = None;
+            }
+            _ => {}
+        }
+
+        match c {
+            '+' => excluded = false,
+            '-' => excluded = true,
+            _ => {}
+        }
+    }
+
+    groups
+}
+
+/// Returns the candidate closest to `s` if it is at most one edit away.
+///
+/// Comparison ignores ASCII case. An edit is an insertion, deletion,
+/// substitution, or transposition of adjacent characters. Ties are resolved
+/// in favor of transpositions and then of the candidate that comes first.
+pub(crate) fn closest_match<'c, I>(s: &str, candidates: I) -> Option<&'c str>
+where
+    I: IntoIterator<Item = &'c str>,
+{
+    let s = to_uppercase(s);
+
+    let mut sorted = s.as_bytes().to_vec();
+    sorted.sort_unstable();
+
+    let is_anagram = |candidate: &str| {
+        let mut bytes = candidate.as_bytes().to_vec();
+        bytes.sort_unstable();
+
+        bytes == sorted
+    };
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(s.as_bytes(), candidate.as_bytes()), candidate))
+        .filter(|(distance, _)| *distance <= 1)
+        .min_by_key(|(distance, candidate)| (*distance, !is_anagram(candidate)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Optimal string alignment distance between two short byte strings.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    // Acronyms are short so the quadratic table is negligible
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(table[i - 2][j - 2] + 1);
+            }
+
+            table[i][j] = distance;
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
     #[test]
     fn to_uppercase() {
         let upper = super::to_uppercase("MANAmE JeF");
@@ -75,4 +284,36 @@ mod tests {
         assert_eq!(super::split_prefix::<1>("abc"), ("a", "bc"));
         assert_eq!(super::split_prefix::<4>("abc"), ("abc", ""));
     }
+
+    #[test]
+    fn closest_match() {
+        let candidates = ["DT", "HD", "HR", "SV2"];
+
+        assert_eq!(super::closest_match("dh", candidates), Some("HD"));
+        assert_eq!(super::closest_match("HX", candidates), Some("HD"));
+        assert_eq!(super::closest_match("SV", candidates), Some("SV2"));
+        assert_eq!(super::closest_match("QQ", candidates), None);
+    }
+
+    #[test]
+    fn lenient_groups() {
+        let groups: Vec<_> = super::lenient_groups("+hd,DT -NF  hr+SV2")
+            .into_iter()
+            .map(|group| (group.offset, group.acronyms, group.excluded))
+            .collect();
+
+        assert_eq!(
+            groups,
+            [
+                (1, "hd", false),
+                (4, "DT", false),
+                (8, "NF", true),
+                (12, "hr", true),
+                (15, "SV2", false)
+            ]
+        );
+
+        assert!(super::lenient_groups("No Mod").is_empty());
+        assert!(super::lenient_groups("+NM").is_empty());
+    }
 }